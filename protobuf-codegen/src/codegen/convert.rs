@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process;
+
+use protobuf::descriptor::FileDescriptorSet;
+use protobuf::reflect::FileDescriptor;
+
+use crate::gen::convert::gen_convert;
+use crate::gen::convert::ConvertSide;
+
+#[derive(Debug, thiserror::Error)]
+enum ConvertCodegenError {
+    #[error("source descriptor set is not specified")]
+    SourceNotSpecified,
+    #[error("target descriptor set is not specified")]
+    TargetNotSpecified,
+    #[error("out_file is not specified")]
+    OutFileNotSpecified,
+    #[error("renamed field `{0}` not found in source descriptor set")]
+    RenamedFieldNotFound(String),
+}
+
+#[derive(Debug)]
+struct Side {
+    file_descriptor_set: FileDescriptorSet,
+    rust_mod: String,
+}
+
+impl Side {
+    fn file_descriptors(&self) -> anyhow::Result<Vec<FileDescriptor>> {
+        Ok(FileDescriptor::new_dynamic_fds(
+            self.file_descriptor_set.file.clone(),
+            &[],
+        )?)
+    }
+}
+
+/// Generate `From` conversions between two versions of the same schema.
+///
+/// Messages are matched by name relative to package, so `v1.Foo`
+/// is converted to `v2.Foo`. Fields are matched by name
+/// unless renamed with [`rename_field`](Self::rename_field).
+///
+/// Fields which are missing or have incompatible types are left
+/// to the generated `ConvertRest` trait, which must be implemented
+/// by the user for such target messages.
+///
+/// Code for both versions must be generated with the same [`Customize`](crate::Customize)
+/// options, and descriptor sets must contain all dependencies.
+#[derive(Debug, Default)]
+pub struct ConvertCodegen {
+    source: Option<Side>,
+    target: Option<Side>,
+    /// Full name of the source field to the name of the target field.
+    field_renames: HashMap<String, String>,
+    out_file: Option<PathBuf>,
+}
+
+impl ConvertCodegen {
+    /// Create new convert codegen object.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Files to convert from, and Rust module path (e. g. `crate::v1`)
+    /// where code for these files is generated.
+    pub fn source(&mut self, file_descriptor_set: FileDescriptorSet, rust_mod: &str) -> &mut Self {
+        self.source = Some(Side {
+            file_descriptor_set,
+            rust_mod: rust_mod.to_owned(),
+        });
+        self
+    }
+
+    /// Files to convert to, and Rust module path (e. g. `crate::v2`)
+    /// where code for these files is generated.
+    pub fn target(&mut self, file_descriptor_set: FileDescriptorSet, rust_mod: &str) -> &mut Self {
+        self.target = Some(Side {
+            file_descriptor_set,
+            rust_mod: rust_mod.to_owned(),
+        });
+        self
+    }
+
+    /// Convert source field (full name like `v1.Foo.old_name`)
+    /// to the target field with given name.
+    pub fn rename_field(&mut self, source_field: &str, target_field: &str) -> &mut Self {
+        self.field_renames
+            .insert(source_field.to_owned(), target_field.to_owned());
+        self
+    }
+
+    /// Output file for generated code.
+    pub fn out_file(&mut self, out_file: impl AsRef<Path>) -> &mut Self {
+        self.out_file = Some(out_file.as_ref().to_owned());
+        self
+    }
+
+    /// Generate conversion code.
+    pub fn gen(&self) -> anyhow::Result<String> {
+        let source = self
+            .source
+            .as_ref()
+            .ok_or(ConvertCodegenError::SourceNotSpecified)?;
+        let target = self
+            .target
+            .as_ref()
+            .ok_or(ConvertCodegenError::TargetNotSpecified)?;
+
+        let source_file_descriptors = source.file_descriptors()?;
+        let target_file_descriptors = target.file_descriptors()?;
+
+        for field in self.field_renames.keys() {
+            let (message, name) = field.rsplit_once('.').unwrap_or(("", field));
+            let found = source_file_descriptors
+                .iter()
+                .filter_map(|f| f.message_by_full_name(&format!(".{}", message)))
+                .any(|m| m.field_by_name(name).is_some());
+            if !found {
+                return Err(ConvertCodegenError::RenamedFieldNotFound(field.clone()).into());
+            }
+        }
+
+        Ok(gen_convert(
+            &ConvertSide {
+                file_descriptors: &source_file_descriptors,
+                rust_mod: &source.rust_mod,
+            },
+            &ConvertSide {
+                file_descriptors: &target_file_descriptors,
+                rust_mod: &target.rust_mod,
+            },
+            &self.field_renames,
+        ))
+    }
+
+    /// Generate conversion code and write it to [`out_file`](Self::out_file).
+    pub fn run(&self) -> anyhow::Result<()> {
+        let out_file = match &self.out_file {
+            Some(out_file) => out_file,
+            None => return Err(ConvertCodegenError::OutFileNotSpecified.into()),
+        };
+        fs::write(out_file, self.gen()?)?;
+        Ok(())
+    }
+
+    /// Similar to `run`, but prints the message to stderr and exits the process on error.
+    pub fn run_from_script(&self) {
+        if let Err(e) = self.run() {
+            eprintln!("convert codegen failed: {:?}", e);
+            process::exit(1);
+        }
+    }
+}
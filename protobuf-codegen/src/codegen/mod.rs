@@ -1,3 +1,5 @@
+pub(crate) mod convert;
+
 use std::env;
 use std::ffi::OsString;
 use std::fs;
@@ -1,6 +1,7 @@
 pub(crate) mod ctx;
 pub(crate) mod rustproto_proto;
 
+use std::collections::BTreeMap;
 use std::fmt;
 use std::ops::Deref;
 use std::rc::Rc;
@@ -12,6 +13,31 @@ use protobuf::reflect::MessageDescriptor;
 use protobuf::reflect::OneofDescriptor;
 
 /// Dynamic callback to customize code generation.
+///
+/// Custom options (extensions of `google.protobuf.*Options`) are preserved
+/// in descriptors' options as unknown fields, so callback can read them
+/// with [`ExtFieldOptional`](protobuf::ext::ExtFieldOptional) and react, e. g.:
+///
+/// ```
+/// # use protobuf::descriptor::field_descriptor_proto::Type;
+/// # use protobuf::descriptor::FieldOptions;
+/// # use protobuf::ext::ExtFieldOptional;
+/// # use protobuf::reflect::FieldDescriptor;
+/// # use protobuf_codegen::Customize;
+/// # use protobuf_codegen::CustomizeCallback;
+/// // extend google.protobuf.FieldOptions { bool use_bytes = 50001; }
+/// const USE_BYTES: ExtFieldOptional<FieldOptions, bool> =
+///     ExtFieldOptional::new(50001, Type::TYPE_BOOL);
+///
+/// struct UseBytes;
+///
+/// impl CustomizeCallback for UseBytes {
+///     fn field(&self, field: &FieldDescriptor) -> Customize {
+///         let use_bytes = USE_BYTES.get(field.proto().options.get_or_default());
+///         Customize::default().tokio_bytes(use_bytes.unwrap_or(false))
+///     }
+/// }
+/// ```
 pub trait CustomizeCallback: 'static {
     fn file(&self, file: &FileDescriptor) -> Customize {
         let _ = file;
@@ -106,9 +132,74 @@ pub struct Customize {
     ///
     /// This option will likely be on by default in rust-protobuf version 3.
     pub(crate) gen_mod_rs: Option<bool>,
+    /// Generate all files into a single `mod.rs` with a nested module per proto file.
+    pub(crate) single_module: Option<bool>,
     /// Used internally to generate protos bundled in protobuf crate
     /// like `descriptor.proto`
     pub(crate) inside_protobuf: Option<bool>,
+    /// Print `<redacted>` instead of field value in generated `Debug` implementation.
+    pub(crate) redact_debug: Option<bool>,
+    /// Generate `FIELD_COUNT` constant for messages.
+    pub(crate) emit_field_count_assert: Option<bool>,
+    /// Derive `Eq` for messages which have no floating point fields.
+    pub(crate) derive_eq: Option<bool>,
+    /// Generate `FIELDS` constant with field metadata.
+    pub(crate) emit_field_meta: Option<bool>,
+    /// Generate type-state builders for messages with required fields.
+    pub(crate) typestate_builder: Option<bool>,
+    /// Implement `Hash` for messages, ignoring special fields.
+    pub(crate) derive_hash: Option<bool>,
+    /// Rust module paths of protobuf packages generated elsewhere.
+    pub(crate) module_paths_for_packages: Option<BTreeMap<String, String>>,
+    /// Generate API resembling `prost` generated code.
+    pub(crate) prost_compat: Option<bool>,
+    /// Implement `TryFrom<&str>` for enums.
+    pub(crate) enum_try_from_str: Option<bool>,
+    /// Generate serde attributes to omit fields with default values.
+    pub(crate) serde_omit_defaults: Option<bool>,
+    /// Use `BTreeMap` instead of `HashMap` for map fields.
+    pub(crate) btreemap_fields: Option<bool>,
+    /// Generate `ALL` constant with all values for enums.
+    pub(crate) emit_enum_all_const: Option<bool>,
+    /// Implement `TryFrom<i32>` and `TryFrom<EnumOrUnknown<E>>` for enums.
+    pub(crate) clean_enum_twin: Option<bool>,
+    /// Add `#[inline]` to generated accessors.
+    pub(crate) inline_accessors: Option<bool>,
+    /// Wrap parse errors with field path and log them with `log::warn!`.
+    pub(crate) log_parse_errors: Option<bool>,
+    /// Generate `FULL_NAME` constant with message full name.
+    pub(crate) emit_full_name_const: Option<bool>,
+    /// Field is part of message identity for generated `PartialEq`, `Eq` and `Hash`.
+    pub(crate) identity_field: Option<bool>,
+    /// Generate `xxx_iter()` accessors returning iterators over repeated fields.
+    pub(crate) iter_accessors: Option<bool>,
+    /// `cfg` predicates for modules generated from proto files.
+    pub(crate) module_cfgs: Option<BTreeMap<String, String>>,
+    /// Implement conversions between messages and tuples of their fields.
+    pub(crate) tuple_conversion: Option<bool>,
+    /// Generate `merge_from_json_str` functions which do not use reflection for most fields.
+    pub(crate) gen_json_methods: Option<bool>,
+    /// Add `#[track_caller]` to generated accessors which unwrap internally,
+    /// and generate `expect_` getters for required fields.
+    pub(crate) track_caller: Option<bool>,
+    /// Serialize repeated fields unpacked even if `packed` option is set.
+    pub(crate) force_unpacked_repeated: Option<bool>,
+    /// Generate `From` conversions from member types to oneof enums.
+    pub(crate) oneof_from_members: Option<bool>,
+    /// Flatten oneof members into the parent object in serde representation.
+    pub(crate) serde_flatten_oneofs: Option<bool>,
+    /// Generate `try_get_` getters returning `Result` for required fields.
+    pub(crate) result_required_getters: Option<bool>,
+    /// Log getter invocations.
+    pub(crate) instrument_getters: Option<bool>,
+    /// Generate `iter()` and `as_str()` functions for enums.
+    pub(crate) enum_iter_and_str: Option<bool>,
+    /// Generate `Copy` twins of messages with only scalar fields.
+    pub(crate) derive_copy_where_possible: Option<bool>,
+    /// Implement `FromStr` for messages parsing text format.
+    pub(crate) fromstr_text_format: Option<bool>,
+    /// Implement `PartialEq` comparing floating point fields by bit pattern.
+    pub(crate) total_float_eq: Option<bool>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -117,6 +208,10 @@ pub(crate) enum CustomizeParseParameterError {
     CannotParseBool(String),
     #[error("Unknown option name: {:?}", .0)]
     UnknownOptionName(String),
+    #[error("Cannot parse module path for package, expecting `package=path`: {:?}", .0)]
+    CannotParseModulePathForPackage(String),
+    #[error("Cannot parse module cfg, expecting `file.proto=predicate`: {:?}", .0)]
+    CannotParseModuleCfg(String),
 }
 
 impl Customize {
@@ -165,12 +260,387 @@ impl Customize {
         self
     }
 
+    /// Generate all inputs into a single `mod.rs` file,
+    /// with a nested `pub mod` block per `.proto` file instead of a separate `.rs` file.
+    ///
+    /// Generated file can be included with `include!` macro,
+    /// which does not support inner attributes in top-level `.rs` files.
+    /// [`gen_mod_rs`](Self::gen_mod_rs) is ignored when this option is set.
+    pub fn single_module(mut self, single_module: bool) -> Self {
+        self.single_module = Some(single_module);
+        self
+    }
+
     /// Generate code bundled in protobuf crate. Regular users don't need this option.
     pub fn inside_protobuf(mut self, inside_protobuf: bool) -> Self {
         self.inside_protobuf = Some(inside_protobuf);
         self
     }
 
+    /// Print `<redacted>` instead of field value in `Debug` output.
+    ///
+    /// Usually set for individual fields with [`CustomizeCallback::field`]
+    /// or `rustproto.redact_debug_field` option. Messages with redacted fields
+    /// get manual `Debug` implementation instead of derived.
+    pub fn redact_debug(mut self, redact_debug: bool) -> Self {
+        self.redact_debug = Some(redact_debug);
+        self
+    }
+
+    /// Generate `FIELD_COUNT` associated constant with the number of message fields.
+    ///
+    /// Dependent code can then pin the schema with a static assertion
+    /// like `const _: () = assert!(Foo::FIELD_COUNT == 3);`,
+    /// which fails to compile when fields are added or removed.
+    pub fn emit_field_count_assert(mut self, emit_field_count_assert: bool) -> Self {
+        self.emit_field_count_assert = Some(emit_field_count_assert);
+        self
+    }
+
+    /// Derive `Eq` (in addition to `PartialEq`) for messages and oneofs
+    /// where all field types permit it.
+    ///
+    /// Messages which contain `float` or `double` fields directly or
+    /// in nested messages, or which contain messages from other files,
+    /// are skipped with a comment in generated code.
+    pub fn derive_eq(mut self, derive_eq: bool) -> Self {
+        self.derive_eq = Some(derive_eq);
+        self
+    }
+
+    /// Generate `pub const FIELDS: &[FieldMeta]` in message impl,
+    /// with name, number and type of each field.
+    ///
+    /// This is a lightweight alternative to reflection
+    /// which does not require building a `MessageDescriptor`.
+    pub fn emit_field_meta(mut self, emit_field_meta: bool) -> Self {
+        self.emit_field_meta = Some(emit_field_meta);
+        self
+    }
+
+    /// Generate `FooBuilder` for proto2 messages with required fields,
+    /// where `build()` is only available after all required fields are set.
+    ///
+    /// Builder setters are generated only for fields with accessors
+    /// (see [`generate_accessors`](Self::generate_accessors)), and builder
+    /// is not generated if some required field has no accessors.
+    pub fn typestate_builder(mut self, typestate_builder: bool) -> Self {
+        self.typestate_builder = Some(typestate_builder);
+        self
+    }
+
+    /// Generate `impl Hash` for messages.
+    ///
+    /// Only message fields are hashed, unknown fields and cached size are ignored,
+    /// so messages equal by `PartialEq` have equal hashes.
+    /// Map entries are hashed in key order.
+    ///
+    /// Messages referenced from other files must be generated with this option too.
+    pub fn derive_hash(mut self, derive_hash: bool) -> Self {
+        self.derive_hash = Some(derive_hash);
+        self
+    }
+
+    /// Reference types of protobuf package `package` (e. g. `my.pkg.v1`)
+    /// by Rust module path `module_path` (e. g. `crate::proto::v1`).
+    ///
+    /// Module path is the module where files of this package are generated
+    /// (i. e. the module which includes `mod.rs` generated for the package),
+    /// so message `Foo` from file `foo.proto` is referenced as
+    /// `crate::proto::v1::foo::Foo`.
+    ///
+    /// Files of this package generated in the same codegen invocation
+    /// are written into subdirectory of the output directory matching the module path
+    /// (`proto/v1/foo.rs` in the example above) with `mod.rs` generated for each directory
+    /// (or nested into modules of [`single_module`](Self::single_module)).
+    /// So the module path must start with `crate`. Generated files reference
+    /// each other by relative paths, so `crate` effectively means the module
+    /// which includes top-level generated `mod.rs` (which is usually the crate root),
+    /// and files of not configured packages are generated into that module.
+    ///
+    /// This option is also useful when packages are generated by separate
+    /// codegen invocations into different modules or crates:
+    /// types of packages not being generated are referenced by configured module path
+    /// (top-level `mod.rs` files of such invocations must be included into the same module
+    /// if that path starts with `crate`).
+    pub fn module_path_for_package(mut self, package: &str, module_path: &str) -> Self {
+        self.module_paths_for_packages
+            .get_or_insert_with(BTreeMap::new)
+            .insert(package.to_owned(), module_path.to_owned());
+        self
+    }
+
+    /// Rust module path for package, if configured with
+    /// [`module_path_for_package`](Customize::module_path_for_package).
+    pub(crate) fn module_path_for_package_get(&self, package: &str) -> Option<&str> {
+        self.module_paths_for_packages
+            .as_ref()
+            .and_then(|m| m.get(package))
+            .map(|p| p.as_str())
+    }
+
+    /// Generate API resembling code generated by `prost`, to ease migration.
+    ///
+    /// Accessors are generated by default (`foo()` getters and `set_foo()` setters
+    /// like in `prost`), and messages get inherent `encoded_len`, `encode_to_vec`
+    /// and `decode` functions.
+    /// Like in `prost`, `encode_to_vec` does not check that required fields are set.
+    /// Fields are public like in any generated message, so messages can be
+    /// constructed with struct literal syntax `Foo { x: 1, ..Default::default() }`.
+    pub fn prost_compat(mut self, prost_compat: bool) -> Self {
+        self.prost_compat = Some(prost_compat);
+        self
+    }
+
+    /// Implement `TryFrom<&str>` for enums.
+    ///
+    /// Conversion matches both short value name (`RED`)
+    /// and value name qualified with enum full name (`my.pkg.Color.RED`),
+    /// without reflection. Unknown names are reported with
+    /// [`EnumFromStrError`](protobuf::EnumFromStrError).
+    pub fn enum_try_from_str(mut self, enum_try_from_str: bool) -> Self {
+        self.enum_try_from_str = Some(enum_try_from_str);
+        self
+    }
+
+    /// Generate `#[serde(default, skip_serializing_if = ...)]` attributes on fields,
+    /// so fields with default values (zero, empty string or collection, unset optional)
+    /// are omitted from serialized output, like in canonical proto3 JSON.
+    ///
+    /// This option does not derive serde traits: `Serialize` and `Deserialize`
+    /// should be derived with [`before`](Customize::before), e. g. from a [`CustomizeCallback`].
+    pub fn serde_omit_defaults(mut self, serde_omit_defaults: bool) -> Self {
+        self.serde_omit_defaults = Some(serde_omit_defaults);
+        self
+    }
+
+    /// Store map fields in `BTreeMap` instead of `HashMap`.
+    ///
+    /// Parsed entries are inserted directly into `BTreeMap`,
+    /// and entries are serialized in key order, so output is deterministic.
+    pub fn btreemap_fields(mut self, btreemap_fields: bool) -> Self {
+        self.btreemap_fields = Some(btreemap_fields);
+        self
+    }
+
+    /// Generate inherent `const ALL: &[Self]` constant for enums
+    /// listing all enum values in declaration order.
+    ///
+    /// Same as [`Enum::VALUES`](protobuf::Enum::VALUES),
+    /// but can be used without importing the trait.
+    pub fn emit_enum_all_const(mut self, emit_enum_all_const: bool) -> Self {
+        self.emit_enum_all_const = Some(emit_enum_all_const);
+        self
+    }
+
+    /// Generate conversions between enums and their wire representation:
+    /// `TryFrom<i32>`, `TryFrom<EnumOrUnknown<E>>` and `From<E> for i32`.
+    ///
+    /// Generated enums have no variant for unknown values,
+    /// (unknown values are stored in [`EnumOrUnknown`](protobuf::EnumOrUnknown) in messages),
+    /// so generated enum is the "clean" twin which is matched exhaustively,
+    /// and unknown values are reported by `TryFrom` as `Err` with the number.
+    pub fn clean_enum_twin(mut self, clean_enum_twin: bool) -> Self {
+        self.clean_enum_twin = Some(clean_enum_twin);
+        self
+    }
+
+    /// Add `#[inline]` attribute to generated field accessors
+    /// (getters, `set_`, `has_`, `clear_`, `mut_` and `take_` functions),
+    /// so they can be inlined into other crates without LTO.
+    pub fn inline_accessors(mut self, inline_accessors: bool) -> Self {
+        self.inline_accessors = Some(inline_accessors);
+        self
+    }
+
+    /// Log parse errors with field path (message name and field number)
+    /// with `log::warn!` in generated `merge_from`.
+    ///
+    /// Requires `with-log` feature of `protobuf` crate,
+    /// without it errors are returned unchanged.
+    pub fn log_parse_errors(mut self, log_parse_errors: bool) -> Self {
+        self.log_parse_errors = Some(log_parse_errors);
+        self
+    }
+
+    /// Generate `pub const FULL_NAME: &str` in messages
+    /// with package-qualified dotted message name.
+    pub fn emit_full_name_const(mut self, emit_full_name_const: bool) -> Self {
+        self.emit_full_name_const = Some(emit_full_name_const);
+        self
+    }
+
+    /// Mark field as identity field.
+    ///
+    /// When any field of a message is marked, `PartialEq`, `Eq` and `Hash`
+    /// implementations of the message compare and hash only identity fields,
+    /// instead of deriving `PartialEq` on all fields.
+    /// Identity fields must not be in oneofs, and must not be
+    /// floating point or message fields (including repeated and map fields).
+    pub fn identity_field(mut self, identity_field: bool) -> Self {
+        self.identity_field = Some(identity_field);
+        self
+    }
+
+    /// Generate `xxx_iter(&self) -> impl Iterator<Item = &T>` accessors
+    /// for repeated fields, hiding the container type.
+    pub fn iter_accessors(mut self, iter_accessors: bool) -> Self {
+        self.iter_accessors = Some(iter_accessors);
+        self
+    }
+
+    /// Place `#[cfg(predicate)]` (e. g. `predicate` is `feature = "foo"`)
+    /// on `mod` statement for module generated from `proto_path`
+    /// in generated `mod.rs`, so the module is compiled only when
+    /// the predicate holds.
+    ///
+    /// `proto_path` is the path of input file relative to include directory
+    /// (e. g. `foo/bar.proto`).
+    /// Only `mod.rs` is affected, so when the module is compiled out,
+    /// it must not be referenced from other modules.
+    pub fn module_cfg(mut self, proto_path: &str, predicate: &str) -> Self {
+        self.module_cfgs
+            .get_or_insert_with(BTreeMap::new)
+            .insert(proto_path.to_owned(), predicate.to_owned());
+        self
+    }
+
+    /// `cfg` predicate for module generated from the file, if configured with
+    /// [`module_cfg`](Customize::module_cfg).
+    pub(crate) fn module_cfg_get(&self, proto_path: &str) -> Option<&str> {
+        self.module_cfgs
+            .as_ref()
+            .and_then(|m| m.get(proto_path))
+            .map(|p| p.as_str())
+    }
+
+    /// Implement `From<Message>` for tuple of message fields in declaration order
+    /// (oneofs are single `Option` members), and `From` the tuple for message,
+    /// for messages with at most 12 fields.
+    ///
+    /// Groups are not included, and unknown fields are dropped on conversion to tuple.
+    pub fn tuple_conversion(mut self, tuple_conversion: bool) -> Self {
+        self.tuple_conversion = Some(tuple_conversion);
+        self
+    }
+
+    /// Generate inherent `merge_from_json_str` and `merge_from_json_str_with_options`
+    /// functions which parse JSON without reflection for scalar, string and bytes fields.
+    ///
+    /// Other fields are parsed with reflection, result is the same as
+    /// `protobuf_json_mapping::merge_from_str`.
+    ///
+    /// Generated code depends on `protobuf-json-mapping` crate.
+    /// Not generated with `lite_runtime`.
+    pub fn gen_json_methods(mut self, gen_json_methods: bool) -> Self {
+        self.gen_json_methods = Some(gen_json_methods);
+        self
+    }
+
+    /// Add `#[track_caller]` attribute to generated accessors which
+    /// internally unwrap (`mut_` and `take_` accessors of singular and oneof fields),
+    /// so a panic inside them is reported at the call site.
+    ///
+    /// Also generate `expect_` getters for required fields,
+    /// which panic if the field is not set.
+    pub fn track_caller(mut self, track_caller: bool) -> Self {
+        self.track_caller = Some(track_caller);
+        self
+    }
+
+    /// Serialize repeated numeric fields unpacked even if they are declared `packed`.
+    ///
+    /// This is a compatibility option for consumers which cannot decode packed fields.
+    /// Parser accepts both packed and unpacked encodings regardless of this option.
+    /// Note dynamic and reflective serialization still follows the descriptor.
+    pub fn force_unpacked_repeated(mut self, force_unpacked_repeated: bool) -> Self {
+        self.force_unpacked_repeated = Some(force_unpacked_repeated);
+        self
+    }
+
+    /// Generate `impl From<T> for Oneof` for each oneof member type `T`
+    /// (e. g. `msg.field = Some(value.into())`).
+    ///
+    /// Conversion is not generated for types shared by several members
+    /// of the same oneof.
+    pub fn oneof_from_members(mut self, oneof_from_members: bool) -> Self {
+        self.oneof_from_members = Some(oneof_from_members);
+        self
+    }
+
+    /// Mark oneof fields with `#[serde(flatten)]` and rename oneof enum variants
+    /// to protobuf field names, so the set oneof member is serialized
+    /// as a field of the message like in protobuf JSON.
+    ///
+    /// This option does not derive serde traits, it is meant to be used
+    /// with `before` option which does.
+    pub fn serde_flatten_oneofs(mut self, serde_flatten_oneofs: bool) -> Self {
+        self.serde_flatten_oneofs = Some(serde_flatten_oneofs);
+        self
+    }
+
+    /// Generate `try_get_xxx()` getters for proto2 required fields.
+    ///
+    /// The getter returns `protobuf::Result` with the field value if the field is set,
+    /// or an error if it is not (regular getter returns default value in that case).
+    pub fn result_required_getters(mut self, result_required_getters: bool) -> Self {
+        self.result_required_getters = Some(result_required_getters);
+        self
+    }
+
+    /// Log each invocation of generated getters with the field name
+    /// with `log::debug!`, to find which fields are read by a code path.
+    ///
+    /// Requires `with-log` feature of `protobuf` crate,
+    /// without it generated getters do not log anything.
+    pub fn instrument_getters(mut self, instrument_getters: bool) -> Self {
+        self.instrument_getters = Some(instrument_getters);
+        self
+    }
+
+    /// Generate `iter()` function returning all enum values in declaration order
+    /// and `as_str()` function returning the value name as written in `.proto`.
+    ///
+    /// These can be used to list and label enum values without reflection.
+    pub fn enum_iter_and_str(mut self, enum_iter_and_str: bool) -> Self {
+        self.enum_iter_and_str = Some(enum_iter_and_str);
+        self
+    }
+
+    /// Generate a `Copy` struct `{Message}Copy` with the same fields
+    /// for messages whose fields are all `Copy` scalars or enums
+    /// (no strings, bytes, messages, repeated, map or oneof fields),
+    /// with conversions to and from the message.
+    ///
+    /// Messages themselves cannot be `Copy`, because they contain special fields
+    /// (unknown fields and cached size). Other messages are skipped,
+    /// and so are messages with a sibling message or enum named `{Message}Copy`.
+    pub fn derive_copy_where_possible(mut self, derive_copy_where_possible: bool) -> Self {
+        self.derive_copy_where_possible = Some(derive_copy_where_possible);
+        self
+    }
+
+    /// Implement `std::str::FromStr` for messages parsing text format,
+    /// so messages can be parsed with `s.parse()`.
+    ///
+    /// Parse error is returned as `protobuf::Error`.
+    /// Not generated with `lite_runtime`.
+    pub fn fromstr_text_format(mut self, fromstr_text_format: bool) -> Self {
+        self.fromstr_text_format = Some(fromstr_text_format);
+        self
+    }
+
+    /// Generate `impl PartialEq` comparing `float` and `double` values by bit pattern
+    /// instead of deriving it, so `NaN` values with the same bits are equal,
+    /// and `0.0` is not equal to `-0.0`.
+    ///
+    /// Messages referenced from other files must be generated with this option too,
+    /// otherwise their fields are compared with `==`.
+    pub fn total_float_eq(mut self, total_float_eq: bool) -> Self {
+        self.total_float_eq = Some(total_float_eq);
+        self
+    }
+
     /// Update fields of self with fields defined in other customize
     pub fn update_with(&mut self, that: &Customize) {
         if let Some(v) = &that.before {
@@ -194,9 +664,109 @@ impl Customize {
         if let Some(v) = that.gen_mod_rs {
             self.gen_mod_rs = Some(v);
         }
+        if let Some(v) = that.single_module {
+            self.single_module = Some(v);
+        }
         if let Some(v) = that.inside_protobuf {
             self.inside_protobuf = Some(v);
         }
+        if let Some(v) = that.redact_debug {
+            self.redact_debug = Some(v);
+        }
+        if let Some(v) = that.emit_field_count_assert {
+            self.emit_field_count_assert = Some(v);
+        }
+        if let Some(v) = that.derive_eq {
+            self.derive_eq = Some(v);
+        }
+        if let Some(v) = that.emit_field_meta {
+            self.emit_field_meta = Some(v);
+        }
+        if let Some(v) = that.typestate_builder {
+            self.typestate_builder = Some(v);
+        }
+        if let Some(v) = that.derive_hash {
+            self.derive_hash = Some(v);
+        }
+        if let Some(v) = &that.module_paths_for_packages {
+            self.module_paths_for_packages
+                .get_or_insert_with(BTreeMap::new)
+                .extend(v.iter().map(|(k, v)| (k.clone(), v.clone())));
+        }
+        if let Some(v) = that.prost_compat {
+            self.prost_compat = Some(v);
+        }
+        if let Some(v) = that.enum_try_from_str {
+            self.enum_try_from_str = Some(v);
+        }
+        if let Some(v) = that.serde_omit_defaults {
+            self.serde_omit_defaults = Some(v);
+        }
+        if let Some(v) = that.btreemap_fields {
+            self.btreemap_fields = Some(v);
+        }
+        if let Some(v) = that.emit_enum_all_const {
+            self.emit_enum_all_const = Some(v);
+        }
+        if let Some(v) = that.clean_enum_twin {
+            self.clean_enum_twin = Some(v);
+        }
+        if let Some(v) = that.inline_accessors {
+            self.inline_accessors = Some(v);
+        }
+        if let Some(v) = that.log_parse_errors {
+            self.log_parse_errors = Some(v);
+        }
+        if let Some(v) = that.emit_full_name_const {
+            self.emit_full_name_const = Some(v);
+        }
+        if let Some(v) = that.identity_field {
+            self.identity_field = Some(v);
+        }
+        if let Some(v) = that.iter_accessors {
+            self.iter_accessors = Some(v);
+        }
+        if let Some(v) = &that.module_cfgs {
+            self.module_cfgs
+                .get_or_insert_with(BTreeMap::new)
+                .extend(v.iter().map(|(k, v)| (k.clone(), v.clone())));
+        }
+        if let Some(v) = that.tuple_conversion {
+            self.tuple_conversion = Some(v);
+        }
+        if let Some(v) = that.gen_json_methods {
+            self.gen_json_methods = Some(v);
+        }
+        if let Some(v) = that.track_caller {
+            self.track_caller = Some(v);
+        }
+        if let Some(v) = that.force_unpacked_repeated {
+            self.force_unpacked_repeated = Some(v);
+        }
+        if let Some(v) = that.oneof_from_members {
+            self.oneof_from_members = Some(v);
+        }
+        if let Some(v) = that.serde_flatten_oneofs {
+            self.serde_flatten_oneofs = Some(v);
+        }
+        if let Some(v) = that.result_required_getters {
+            self.result_required_getters = Some(v);
+        }
+        if let Some(v) = that.instrument_getters {
+            self.instrument_getters = Some(v);
+        }
+        if let Some(v) = that.enum_iter_and_str {
+            self.enum_iter_and_str = Some(v);
+        }
+        if let Some(v) = that.derive_copy_where_possible {
+            self.derive_copy_where_possible = Some(v);
+        }
+        if let Some(v) = that.fromstr_text_format {
+            self.fromstr_text_format = Some(v);
+        }
+        if let Some(v) = that.total_float_eq {
+            self.total_float_eq = Some(v);
+        }
     }
 
     /// Update unset fields of self with fields from other customize
@@ -236,8 +806,78 @@ impl Customize {
                 r.lite_runtime = Some(parse_bool(v)?);
             } else if n == "gen_mod_rs" {
                 r.gen_mod_rs = Some(parse_bool(v)?);
+            } else if n == "single_module" {
+                r.single_module = Some(parse_bool(v)?);
             } else if n == "inside_protobuf" {
                 r.inside_protobuf = Some(parse_bool(v)?);
+            } else if n == "redact_debug" {
+                r.redact_debug = Some(parse_bool(v)?);
+            } else if n == "emit_field_count_assert" {
+                r.emit_field_count_assert = Some(parse_bool(v)?);
+            } else if n == "derive_eq" {
+                r.derive_eq = Some(parse_bool(v)?);
+            } else if n == "emit_field_meta" {
+                r.emit_field_meta = Some(parse_bool(v)?);
+            } else if n == "typestate_builder" {
+                r.typestate_builder = Some(parse_bool(v)?);
+            } else if n == "derive_hash" {
+                r.derive_hash = Some(parse_bool(v)?);
+            } else if n == "module_path_for_package" {
+                let (package, module_path) = v.split_once('=').ok_or_else(|| {
+                    CustomizeParseParameterError::CannotParseModulePathForPackage(v.to_owned())
+                })?;
+                r = r.module_path_for_package(package, module_path);
+            } else if n == "prost_compat" {
+                r.prost_compat = Some(parse_bool(v)?);
+            } else if n == "enum_try_from_str" {
+                r.enum_try_from_str = Some(parse_bool(v)?);
+            } else if n == "serde_omit_defaults" {
+                r.serde_omit_defaults = Some(parse_bool(v)?);
+            } else if n == "btreemap_fields" {
+                r.btreemap_fields = Some(parse_bool(v)?);
+            } else if n == "emit_enum_all_const" {
+                r.emit_enum_all_const = Some(parse_bool(v)?);
+            } else if n == "clean_enum_twin" {
+                r.clean_enum_twin = Some(parse_bool(v)?);
+            } else if n == "inline_accessors" {
+                r.inline_accessors = Some(parse_bool(v)?);
+            } else if n == "log_parse_errors" {
+                r.log_parse_errors = Some(parse_bool(v)?);
+            } else if n == "emit_full_name_const" {
+                r.emit_full_name_const = Some(parse_bool(v)?);
+            } else if n == "identity_field" {
+                r.identity_field = Some(parse_bool(v)?);
+            } else if n == "iter_accessors" {
+                r.iter_accessors = Some(parse_bool(v)?);
+            } else if n == "module_cfg" {
+                let (proto_path, predicate) = v.split_once('=').ok_or_else(|| {
+                    CustomizeParseParameterError::CannotParseModuleCfg(v.to_owned())
+                })?;
+                r = r.module_cfg(proto_path, predicate);
+            } else if n == "tuple_conversion" {
+                r.tuple_conversion = Some(parse_bool(v)?);
+            } else if n == "gen_json_methods" {
+                r.gen_json_methods = Some(parse_bool(v)?);
+            } else if n == "track_caller" {
+                r.track_caller = Some(parse_bool(v)?);
+            } else if n == "force_unpacked_repeated" {
+                r.force_unpacked_repeated = Some(parse_bool(v)?);
+            } else if n == "oneof_from_members" {
+                r.oneof_from_members = Some(parse_bool(v)?);
+            } else if n == "serde_flatten_oneofs" {
+                r.serde_flatten_oneofs = Some(parse_bool(v)?);
+            } else if n == "result_required_getters" {
+                r.result_required_getters = Some(parse_bool(v)?);
+            } else if n == "instrument_getters" {
+                r.instrument_getters = Some(parse_bool(v)?);
+            } else if n == "enum_iter_and_str" {
+                r.enum_iter_and_str = Some(parse_bool(v)?);
+            } else if n == "derive_copy_where_possible" {
+                r.derive_copy_where_possible = Some(parse_bool(v)?);
+            } else if n == "fromstr_text_format" {
+                r.fromstr_text_format = Some(parse_bool(v)?);
+            } else if n == "total_float_eq" {
+                r.total_float_eq = Some(parse_bool(v)?);
             } else if n == "lite" {
                 // Support Java and C++ protoc plugin syntax:
                 // https://github.com/protocolbuffers/protobuf/issues/6489
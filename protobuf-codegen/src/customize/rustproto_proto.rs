@@ -14,7 +14,39 @@ pub(crate) fn customize_from_rustproto_for_message(source: &MessageOptions) -> C
     let tokio_bytes_for_string = rustproto::exts::tokio_bytes_for_string.get(source);
     let lite_runtime = None;
     let gen_mod_rs = None;
+    let single_module = None;
     let inside_protobuf = None;
+    let redact_debug = None;
+    let emit_field_count_assert = rustproto::exts::emit_field_count_assert.get(source);
+    let derive_eq = None;
+    let emit_field_meta = rustproto::exts::emit_field_meta.get(source);
+    let typestate_builder = rustproto::exts::typestate_builder.get(source);
+    let derive_hash = rustproto::exts::derive_hash.get(source);
+    let module_paths_for_packages = None;
+    let module_cfgs = None;
+    let prost_compat = rustproto::exts::prost_compat.get(source);
+    let enum_try_from_str = None;
+    let serde_omit_defaults = rustproto::exts::serde_omit_defaults.get(source);
+    let btreemap_fields = rustproto::exts::btreemap_fields.get(source);
+    let emit_enum_all_const = None;
+    let clean_enum_twin = None;
+    let inline_accessors = rustproto::exts::inline_accessors.get(source);
+    let log_parse_errors = rustproto::exts::log_parse_errors.get(source);
+    let emit_full_name_const = rustproto::exts::emit_full_name_const.get(source);
+    let identity_field = None;
+    let iter_accessors = rustproto::exts::iter_accessors.get(source);
+    let tuple_conversion = rustproto::exts::tuple_conversion.get(source);
+    let gen_json_methods = rustproto::exts::gen_json_methods.get(source);
+    let track_caller = rustproto::exts::track_caller.get(source);
+    let force_unpacked_repeated = rustproto::exts::force_unpacked_repeated.get(source);
+    let oneof_from_members = rustproto::exts::oneof_from_members.get(source);
+    let serde_flatten_oneofs = rustproto::exts::serde_flatten_oneofs.get(source);
+    let result_required_getters = rustproto::exts::result_required_getters.get(source);
+    let instrument_getters = rustproto::exts::instrument_getters.get(source);
+    let enum_iter_and_str = None;
+    let derive_copy_where_possible = rustproto::exts::derive_copy_where_possible.get(source);
+    let fromstr_text_format = rustproto::exts::fromstr_text_format.get(source);
+    let total_float_eq = rustproto::exts::total_float_eq.get(source);
     Customize {
         before,
         generate_accessors,
@@ -23,7 +55,39 @@ pub(crate) fn customize_from_rustproto_for_message(source: &MessageOptions) -> C
         tokio_bytes_for_string,
         lite_runtime,
         gen_mod_rs,
+        single_module,
         inside_protobuf,
+        redact_debug,
+        emit_field_count_assert,
+        derive_eq,
+        emit_field_meta,
+        typestate_builder,
+        derive_hash,
+        module_paths_for_packages,
+        module_cfgs,
+        prost_compat,
+        enum_try_from_str,
+        serde_omit_defaults,
+        btreemap_fields,
+        emit_enum_all_const,
+        clean_enum_twin,
+        inline_accessors,
+        log_parse_errors,
+        emit_full_name_const,
+        identity_field,
+        iter_accessors,
+        tuple_conversion,
+        gen_json_methods,
+        track_caller,
+        force_unpacked_repeated,
+        oneof_from_members,
+        serde_flatten_oneofs,
+        result_required_getters,
+        instrument_getters,
+        enum_iter_and_str,
+        derive_copy_where_possible,
+        fromstr_text_format,
+        total_float_eq,
     }
 }
 
@@ -39,7 +103,39 @@ pub(crate) fn customize_from_rustproto_for_field(source: &FieldOptions) -> Custo
     let tokio_bytes_for_string = rustproto::exts::tokio_bytes_for_string_field.get(source);
     let lite_runtime = None;
     let gen_mod_rs = None;
+    let single_module = None;
     let inside_protobuf = None;
+    let redact_debug = rustproto::exts::redact_debug_field.get(source);
+    let emit_field_count_assert = None;
+    let derive_eq = None;
+    let emit_field_meta = None;
+    let typestate_builder = None;
+    let derive_hash = None;
+    let module_paths_for_packages = None;
+    let module_cfgs = None;
+    let prost_compat = None;
+    let enum_try_from_str = None;
+    let serde_omit_defaults = rustproto::exts::serde_omit_defaults_field.get(source);
+    let btreemap_fields = rustproto::exts::btreemap_fields_field.get(source);
+    let emit_enum_all_const = None;
+    let clean_enum_twin = None;
+    let inline_accessors = rustproto::exts::inline_accessors_field.get(source);
+    let log_parse_errors = None;
+    let emit_full_name_const = None;
+    let identity_field = rustproto::exts::identity_field.get(source);
+    let iter_accessors = rustproto::exts::iter_accessors_field.get(source);
+    let tuple_conversion = None;
+    let gen_json_methods = None;
+    let track_caller = rustproto::exts::track_caller_field.get(source);
+    let force_unpacked_repeated = rustproto::exts::force_unpacked_repeated_field.get(source);
+    let oneof_from_members = None;
+    let serde_flatten_oneofs = None;
+    let result_required_getters = rustproto::exts::result_required_getters_field.get(source);
+    let instrument_getters = rustproto::exts::instrument_getters_field.get(source);
+    let enum_iter_and_str = None;
+    let derive_copy_where_possible = None;
+    let fromstr_text_format = None;
+    let total_float_eq = None;
     Customize {
         before,
         generate_accessors,
@@ -48,7 +144,39 @@ pub(crate) fn customize_from_rustproto_for_field(source: &FieldOptions) -> Custo
         tokio_bytes_for_string,
         lite_runtime,
         gen_mod_rs,
+        single_module,
         inside_protobuf,
+        redact_debug,
+        emit_field_count_assert,
+        derive_eq,
+        emit_field_meta,
+        typestate_builder,
+        derive_hash,
+        module_paths_for_packages,
+        module_cfgs,
+        prost_compat,
+        enum_try_from_str,
+        serde_omit_defaults,
+        btreemap_fields,
+        emit_enum_all_const,
+        clean_enum_twin,
+        inline_accessors,
+        log_parse_errors,
+        emit_full_name_const,
+        identity_field,
+        iter_accessors,
+        tuple_conversion,
+        gen_json_methods,
+        track_caller,
+        force_unpacked_repeated,
+        oneof_from_members,
+        serde_flatten_oneofs,
+        result_required_getters,
+        instrument_getters,
+        enum_iter_and_str,
+        derive_copy_where_possible,
+        fromstr_text_format,
+        total_float_eq,
     }
 }
 
@@ -60,7 +188,39 @@ pub(crate) fn customize_from_rustproto_for_file(source: &FileOptions) -> Customi
     let tokio_bytes_for_string = rustproto::exts::tokio_bytes_for_string_all.get(source);
     let lite_runtime = rustproto::exts::lite_runtime_all.get(source);
     let gen_mod_rs = None;
+    let single_module = None;
     let inside_protobuf = None;
+    let redact_debug = None;
+    let emit_field_count_assert = rustproto::exts::emit_field_count_assert_all.get(source);
+    let derive_eq = rustproto::exts::derive_eq_all.get(source);
+    let emit_field_meta = rustproto::exts::emit_field_meta_all.get(source);
+    let typestate_builder = rustproto::exts::typestate_builder_all.get(source);
+    let derive_hash = rustproto::exts::derive_hash_all.get(source);
+    let module_paths_for_packages = None;
+    let module_cfgs = None;
+    let prost_compat = rustproto::exts::prost_compat_all.get(source);
+    let enum_try_from_str = rustproto::exts::enum_try_from_str_all.get(source);
+    let serde_omit_defaults = rustproto::exts::serde_omit_defaults_all.get(source);
+    let btreemap_fields = rustproto::exts::btreemap_fields_all.get(source);
+    let emit_enum_all_const = rustproto::exts::emit_enum_all_const_all.get(source);
+    let clean_enum_twin = rustproto::exts::clean_enum_twin_all.get(source);
+    let inline_accessors = rustproto::exts::inline_accessors_all.get(source);
+    let log_parse_errors = rustproto::exts::log_parse_errors_all.get(source);
+    let emit_full_name_const = rustproto::exts::emit_full_name_const_all.get(source);
+    let identity_field = None;
+    let iter_accessors = rustproto::exts::iter_accessors_all.get(source);
+    let tuple_conversion = rustproto::exts::tuple_conversion_all.get(source);
+    let gen_json_methods = rustproto::exts::gen_json_methods_all.get(source);
+    let track_caller = rustproto::exts::track_caller_all.get(source);
+    let force_unpacked_repeated = rustproto::exts::force_unpacked_repeated_all.get(source);
+    let oneof_from_members = rustproto::exts::oneof_from_members_all.get(source);
+    let serde_flatten_oneofs = rustproto::exts::serde_flatten_oneofs_all.get(source);
+    let result_required_getters = rustproto::exts::result_required_getters_all.get(source);
+    let instrument_getters = rustproto::exts::instrument_getters_all.get(source);
+    let enum_iter_and_str = rustproto::exts::enum_iter_and_str_all.get(source);
+    let derive_copy_where_possible = rustproto::exts::derive_copy_where_possible_all.get(source);
+    let fromstr_text_format = rustproto::exts::fromstr_text_format_all.get(source);
+    let total_float_eq = rustproto::exts::total_float_eq_all.get(source);
     Customize {
         before,
         generate_accessors,
@@ -70,5 +230,37 @@ pub(crate) fn customize_from_rustproto_for_file(source: &FileOptions) -> Customi
         lite_runtime,
         inside_protobuf,
         gen_mod_rs,
+        single_module,
+        redact_debug,
+        emit_field_count_assert,
+        derive_eq,
+        emit_field_meta,
+        typestate_builder,
+        derive_hash,
+        module_paths_for_packages,
+        module_cfgs,
+        prost_compat,
+        enum_try_from_str,
+        serde_omit_defaults,
+        btreemap_fields,
+        emit_enum_all_const,
+        clean_enum_twin,
+        inline_accessors,
+        log_parse_errors,
+        emit_full_name_const,
+        identity_field,
+        iter_accessors,
+        tuple_conversion,
+        gen_json_methods,
+        track_caller,
+        force_unpacked_repeated,
+        oneof_from_members,
+        serde_flatten_oneofs,
+        result_required_getters,
+        instrument_getters,
+        enum_iter_and_str,
+        derive_copy_where_possible,
+        fromstr_text_format,
+        total_float_eq,
     }
 }
@@ -9,7 +9,10 @@ use crate::compiler_plugin;
 use crate::customize::ctx::CustomizeElemCtx;
 use crate::customize::CustomizeCallback;
 use crate::gen::file::gen_file;
-use crate::gen::mod_rs::gen_mod_rs;
+use crate::gen::mod_rs::gen_mod_rs_tree;
+use crate::gen::mod_rs::gen_single_module;
+use crate::gen::mod_rs::path_in_dir;
+use crate::gen::paths::package_mods;
 use crate::gen::scope::RootScope;
 use crate::gen::well_known_types::gen_well_known_types_mod;
 use crate::Customize;
@@ -41,24 +44,106 @@ pub(crate) fn gen_all(
         callback: customize_callback,
     };
 
+    let single_module = customize.for_elem.single_module.unwrap_or(false);
+    let mut single_module_files = Vec::new();
+
     for file_name in files_to_generate {
         let file = files_map.get(file_name.as_path()).expect(&format!(
             "file not found in file descriptors: {:?}, files: {:?}",
             file_name,
             files_map.keys()
         ));
-        let gen_file_result = gen_file(file, &files_map, &root_scope, &customize, parser)?;
-        results.push(gen_file_result.compiler_plugin_result);
-        mods.push(gen_file_result.mod_name);
+        let mut gen_file_result = gen_file(file, &files_map, &root_scope, &customize, parser)?;
+        let cfg = customize
+            .for_elem
+            .module_cfg_get(file_name.to_str())
+            .map(|p| p.to_owned());
+        let dir = package_mods(file.package(), &customize.for_elem)?.unwrap_or_default();
+        if single_module {
+            let content = String::from_utf8(gen_file_result.compiler_plugin_result.content)?;
+            single_module_files.push(((dir, gen_file_result.mod_name, cfg), content));
+        } else {
+            let name = &mut gen_file_result.compiler_plugin_result.name;
+            *name = path_in_dir(&dir, name);
+            results.push(gen_file_result.compiler_plugin_result);
+            mods.push((dir, gen_file_result.mod_name, cfg));
+        }
     }
 
     if customize.for_elem.inside_protobuf.unwrap_or(false) {
         results.push(gen_well_known_types_mod());
     }
 
-    if customize.for_elem.gen_mod_rs.unwrap_or(true) {
-        results.push(gen_mod_rs(&mods));
+    if single_module {
+        results.push(gen_single_module(&single_module_files));
+    } else if customize.for_elem.gen_mod_rs.unwrap_or(true) {
+        results.extend(gen_mod_rs_tree(&mods));
     }
 
     Ok(results)
 }
+
+#[cfg(test)]
+mod test {
+    use protobuf::descriptor::FileDescriptorProto;
+    use protobuf::reflect::FieldDescriptor;
+    use protobuf_parse::ProtoPathBuf;
+
+    use super::gen_all;
+    use crate::customize::CustomizeCallback;
+    use crate::Customize;
+
+    struct IdentityField(&'static str);
+
+    impl CustomizeCallback for IdentityField {
+        fn field(&self, field: &FieldDescriptor) -> Customize {
+            Customize::default().identity_field(field.name() == self.0)
+        }
+    }
+
+    fn gen_with_identity_field(field: &'static str) -> anyhow::Result<()> {
+        let file: FileDescriptorProto = protobuf::text_format::parse_from_str(
+            r#"
+            name: "a.proto"
+            message_type {
+                name: "A"
+                field { name: "id" number: 1 label: LABEL_OPTIONAL type: TYPE_STRING }
+                field { name: "f" number: 2 label: LABEL_OPTIONAL type: TYPE_DOUBLE }
+                field { name: "m" number: 3 label: LABEL_OPTIONAL type: TYPE_MESSAGE type_name: ".A" }
+                field { name: "r" number: 4 label: LABEL_REPEATED type: TYPE_FLOAT }
+            }
+            "#,
+        )
+        .unwrap();
+        gen_all(
+            &[file],
+            "test",
+            &[ProtoPathBuf::new("a.proto".to_owned()).unwrap()],
+            &Customize::default(),
+            &IdentityField(field),
+        )
+        .map(|_| ())
+    }
+
+    #[test]
+    fn test_identity_field_types() {
+        gen_with_identity_field("id").unwrap();
+        for (field, error) in [
+            (
+                "f",
+                "`identity_field` option is not supported for floating point field `A.f`",
+            ),
+            (
+                "r",
+                "`identity_field` option is not supported for floating point field `A.r`",
+            ),
+            (
+                "m",
+                "`identity_field` option is not supported for message field `A.m`",
+            ),
+        ] {
+            let e = gen_with_identity_field(field).unwrap_err();
+            assert_eq!(error, e.to_string());
+        }
+    }
+}
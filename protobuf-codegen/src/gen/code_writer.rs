@@ -54,7 +54,7 @@ impl<'a> CodeWriter<'a> {
         }
     }
 
-    pub(crate) fn _write_text(&mut self, text: &str) {
+    pub(crate) fn write_text(&mut self, text: &str) {
         for line in text.lines() {
             self.write_line(line);
         }
@@ -0,0 +1,296 @@
+//! Generate `From` conversions between two versions of the same schema.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use protobuf::descriptor::field_descriptor_proto;
+use protobuf::reflect::FieldDescriptor;
+use protobuf::reflect::FileDescriptor;
+use protobuf::reflect::MessageDescriptor;
+use protobuf::reflect::RuntimeFieldType;
+use protobuf::reflect::RuntimeType;
+use protobuf::reflect::Syntax;
+use protobuf_parse::ProtobufAbsPath;
+
+use crate::gen::code_writer::CodeWriter;
+use crate::gen::field::rust_field_name_for_protobuf_field_name;
+use crate::gen::inside::protobuf_crate_path;
+use crate::gen::scope::RootScope;
+use crate::gen::scope::WithScope;
+use crate::Customize;
+
+/// Name of the trait generated for fields which cannot be converted automatically.
+pub(crate) const CONVERT_REST_TRAIT: &str = "ConvertRest";
+
+/// One side of the conversion: parsed files and a Rust module where
+/// the code generated for these files lives.
+pub(crate) struct ConvertSide<'a> {
+    pub file_descriptors: &'a [FileDescriptor],
+    pub rust_mod: &'a str,
+}
+
+impl<'a> ConvertSide<'a> {
+    fn messages(&self) -> Vec<MessageDescriptor> {
+        fn walk(m: MessageDescriptor, r: &mut Vec<MessageDescriptor>) {
+            for n in m.nested_messages() {
+                walk(n, r);
+            }
+            if !m.is_map_entry() {
+                r.push(m);
+            }
+        }
+
+        let mut r = Vec::new();
+        for file in self.file_descriptors {
+            for m in file.messages() {
+                walk(m, &mut r);
+            }
+        }
+        r
+    }
+
+    fn rust_name(&self, message: &MessageDescriptor) -> String {
+        let root_scope = RootScope {
+            file_descriptors: self.file_descriptors,
+        };
+        let message = root_scope.find_message(&ProtobufAbsPath::from_message(message));
+        match self.rust_mod {
+            "" => message.rust_name_with_file().to_string(),
+            m => format!("{}::{}", m, message.rust_name_with_file()),
+        }
+    }
+}
+
+/// Singular field storage as generated by `FieldGen`.
+fn singular_with_flag(field: &FieldDescriptor) -> bool {
+    field.containing_message().file_descriptor().syntax() != Syntax::Proto3
+        || field.proto().type_() == field_descriptor_proto::Type::TYPE_MESSAGE
+        || field.proto().proto3_optional()
+}
+
+struct ConvertGen<'a> {
+    source_side: &'a ConvertSide<'a>,
+    target_side: &'a ConvertSide<'a>,
+    field_renames: &'a HashMap<String, String>,
+    /// Full names of source messages to full names of target messages.
+    message_pairs: HashMap<String, String>,
+    protobuf_crate: String,
+}
+
+enum ElemConversion {
+    Move,
+    Enum,
+    Message,
+}
+
+impl<'a> ConvertGen<'a> {
+    fn messages_compatible(&self, source: &MessageDescriptor, target: &MessageDescriptor) -> bool {
+        match self.message_pairs.get(source.full_name()) {
+            Some(t) => t == target.full_name(),
+            // Message defined outside of both schemas, e. g. well-known type.
+            None => source.full_name() == target.full_name(),
+        }
+    }
+
+    fn elem_conversion(
+        &self,
+        source: &RuntimeType,
+        target: &RuntimeType,
+    ) -> Option<ElemConversion> {
+        match (source, target) {
+            (RuntimeType::Enum(..), RuntimeType::Enum(..)) => Some(ElemConversion::Enum),
+            (RuntimeType::Message(s), RuntimeType::Message(t)) => {
+                if !self.messages_compatible(s, t) {
+                    None
+                } else if self.message_pairs.contains_key(s.full_name()) {
+                    Some(ElemConversion::Message)
+                } else {
+                    Some(ElemConversion::Move)
+                }
+            }
+            (RuntimeType::Enum(..), _) | (_, RuntimeType::Enum(..)) => None,
+            (RuntimeType::Message(..), _) | (_, RuntimeType::Message(..)) => None,
+            (s, t) if s == t => Some(ElemConversion::Move),
+            _ => None,
+        }
+    }
+
+    /// Expression converting `source` field to `target` field,
+    /// or `None` if fields are not compatible.
+    fn field_conversion(
+        &self,
+        source: &FieldDescriptor,
+        target: &FieldDescriptor,
+    ) -> Option<String> {
+        if source.containing_oneof().is_some() || target.containing_oneof().is_some() {
+            return None;
+        }
+
+        let expr = format!(
+            "source.{}",
+            rust_field_name_for_protobuf_field_name(source.name())
+        );
+        let from_i32 = format!("{}::EnumOrUnknown::from_i32", self.protobuf_crate);
+
+        match (source.runtime_field_type(), target.runtime_field_type()) {
+            (RuntimeFieldType::Singular(s), RuntimeFieldType::Singular(t)) => {
+                let with_flag = singular_with_flag(source);
+                if with_flag != singular_with_flag(target) {
+                    return None;
+                }
+                Some(match (self.elem_conversion(&s, &t)?, with_flag) {
+                (ElemConversion::Move, _) => expr,
+                (ElemConversion::Enum, false) => format!("{}({}.value())", from_i32, expr),
+                (ElemConversion::Enum, true) => {
+                    format!("{}.map(|v| {}(v.value()))", expr, from_i32)
+                }
+                (ElemConversion::Message, _) => format!(
+                    "{}::MessageField({}.0.map(|m| ::std::boxed::Box::new(::std::convert::From::from(*m))))",
+                    self.protobuf_crate, expr
+                ),
+            })
+            }
+            (RuntimeFieldType::Repeated(s), RuntimeFieldType::Repeated(t)) => {
+                Some(match self.elem_conversion(&s, &t)? {
+                    ElemConversion::Move => expr,
+                    ElemConversion::Enum => format!(
+                        "{}.into_iter().map(|v| {}(v.value())).collect()",
+                        expr, from_i32
+                    ),
+                    ElemConversion::Message => format!(
+                        "{}.into_iter().map(::std::convert::From::from).collect()",
+                        expr
+                    ),
+                })
+            }
+            (RuntimeFieldType::Map(sk, sv), RuntimeFieldType::Map(tk, tv)) => {
+                match (
+                    self.elem_conversion(&sk, &tk)?,
+                    self.elem_conversion(&sv, &tv)?,
+                ) {
+                    (ElemConversion::Move, ElemConversion::Move) => Some(expr),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn gen_message_conversion(
+        &self,
+        w: &mut CodeWriter,
+        source: &MessageDescriptor,
+        target: &MessageDescriptor,
+    ) {
+        let source_rust_name = self.source_side.rust_name(source);
+        let target_rust_name = self.target_side.rust_name(target);
+
+        let mut assignments = Vec::new();
+        let mut assigned_target_fields = HashSet::new();
+        let mut rest = false;
+        for source_field in source.fields() {
+            let target_field_name = match self.field_renames.get(&source_field.full_name()) {
+                Some(n) => n.as_str(),
+                None => source_field.name(),
+            };
+            let conversion = target
+                .field_by_name(target_field_name)
+                .and_then(|t| Some((self.field_conversion(&source_field, &t)?, t)));
+            match conversion {
+                Some((conversion, target_field)) => {
+                    assignments.push(format!(
+                        "target.{} = {};",
+                        rust_field_name_for_protobuf_field_name(target_field.name()),
+                        conversion
+                    ));
+                    assigned_target_fields.insert(target_field.name().to_owned());
+                }
+                None => rest = true,
+            }
+        }
+        if target
+            .fields()
+            .any(|f| !assigned_target_fields.contains(f.name()))
+        {
+            rest = true;
+        }
+
+        w.write_line("");
+        w.impl_for_block(
+            format!("::std::convert::From<{}>", source_rust_name),
+            &target_rust_name,
+            |w| {
+                w.def_fn(
+                    &format!("from(source: {}) -> Self", source_rust_name),
+                    |w| {
+                        w.write_line(format!("let mut target = {}::new();", target_rust_name));
+                        if rest {
+                            w.write_line(format!(
+                                "{}::convert_rest(&mut target, &source);",
+                                CONVERT_REST_TRAIT
+                            ));
+                        }
+                        for a in &assignments {
+                            w.write_line(a);
+                        }
+                        w.write_line("target");
+                    },
+                );
+            },
+        );
+    }
+}
+
+/// Generate `From` implementations for messages which have the same name
+/// relative to package in both source and target files.
+pub(crate) fn gen_convert(
+    source_side: &ConvertSide,
+    target_side: &ConvertSide,
+    field_renames: &HashMap<String, String>,
+) -> String {
+    let target_messages: HashMap<String, MessageDescriptor> = target_side
+        .messages()
+        .into_iter()
+        .map(|m| (m.name_to_package().to_owned(), m))
+        .collect();
+
+    let mut message_pairs = Vec::new();
+    for source in source_side.messages() {
+        if let Some(target) = target_messages.get(source.name_to_package()) {
+            message_pairs.push((source, target.clone()));
+        }
+    }
+
+    let gen = ConvertGen {
+        source_side,
+        target_side,
+        field_renames,
+        message_pairs: message_pairs
+            .iter()
+            .map(|(s, t)| (s.full_name().to_owned(), t.full_name().to_owned()))
+            .collect(),
+        protobuf_crate: protobuf_crate_path(&Customize::default()).to_string(),
+    };
+
+    CodeWriter::with_no_error(|w| {
+        w.write_line(format!(
+            "// This file is generated by rust-protobuf {}. Do not edit",
+            env!("CARGO_PKG_VERSION"),
+        ));
+        w.comment(&format!("{}generated", "@"));
+        w.write_line("");
+        w.write_line("/// Conversion of fields which cannot be converted automatically.");
+        w.write_line("///");
+        w.write_line("/// Must be implemented for target messages which have fields");
+        w.write_line("/// missing or incompatible in the source message.");
+        w.write_line(format!("pub trait {}<S> {{", CONVERT_REST_TRAIT));
+        w.indented(|w| {
+            w.write_line("fn convert_rest(&mut self, source: &S);");
+        });
+        w.write_line("}");
+
+        for (source, target) in &message_pairs {
+            gen.gen_message_conversion(w, source, target);
+        }
+    })
+}
@@ -138,18 +138,90 @@ impl<'a> EnumGen<'a> {
         }
         w.write_line("");
         self.write_impl_default(w);
+        if self.customize.for_elem.enum_try_from_str.unwrap_or(false) {
+            w.write_line("");
+            self.write_impl_try_from_str(w);
+        }
+        if self.customize.for_elem.clean_enum_twin.unwrap_or(false) {
+            w.write_line("");
+            self.write_impl_clean_enum_twin(w);
+        }
         w.write_line("");
         self.write_impl_self(w);
     }
 
     fn write_impl_self(&self, w: &mut CodeWriter) {
-        if !self.lite_runtime {
+        let emit_all_const = self.customize.for_elem.emit_enum_all_const.unwrap_or(false);
+        let iter_and_str = self.customize.for_elem.enum_iter_and_str.unwrap_or(false);
+        if !self.lite_runtime || emit_all_const || iter_and_str {
             w.impl_self_block(&format!("{}", self.type_name), |w| {
-                self.write_generated_enum_descriptor_data(w);
+                let mut first = true;
+                let mut sep = |w: &mut CodeWriter| {
+                    if !first {
+                        w.write_line("");
+                    }
+                    first = false;
+                };
+                if emit_all_const {
+                    sep(w);
+                    self.write_all_const(w);
+                }
+                if iter_and_str {
+                    sep(w);
+                    self.write_fn_iter(w);
+                    w.write_line("");
+                    self.write_fn_as_str(w);
+                }
+                if !self.lite_runtime {
+                    sep(w);
+                    self.write_generated_enum_descriptor_data(w);
+                }
             });
         }
     }
 
+    fn write_fn_iter(&self, w: &mut CodeWriter) {
+        w.write_line("/// Iterate over all enum values in declaration order.");
+        w.pub_fn(
+            &format!(
+                "iter() -> impl ::std::iter::Iterator<Item = {}>",
+                self.type_name
+            ),
+            |w| {
+                w.write_line(format!(
+                    "<{} as {}::Enum>::VALUES.iter().copied()",
+                    self.type_name,
+                    protobuf_crate_path(&self.customize.for_elem)
+                ));
+            },
+        );
+    }
+
+    fn write_fn_as_str(&self, w: &mut CodeWriter) {
+        w.write_line("/// Name of the value as written in `.proto` file.");
+        w.pub_fn("as_str(&self) -> &'static str", |w| {
+            w.match_expr("*self", |w| {
+                for value in self.values_all() {
+                    w.case_expr(
+                        value.rust_name_outer().to_string(),
+                        format!("\"{}\"", value.value.proto.name()),
+                    );
+                }
+            });
+        });
+    }
+
+    fn write_all_const(&self, w: &mut CodeWriter) {
+        w.write_line("/// All enum values in declaration order.");
+        w.write_line(format!("pub const ALL: &'static [{}] = &[", self.type_name));
+        w.indented(|w| {
+            for value in self.values_all() {
+                w.write_line(format!("{},", value.rust_name_outer()));
+            }
+        });
+        w.write_line("];");
+    }
+
     fn write_enum(&self, w: &mut CodeWriter) {
         w.all_documentation(self.info, self.path);
 
@@ -265,6 +337,90 @@ impl<'a> EnumGen<'a> {
         );
     }
 
+    fn write_impl_try_from_str(&self, w: &mut CodeWriter) {
+        let protobuf_crate = protobuf_crate_path(&self.customize.for_elem);
+        let full_name = self.enum_with_scope.name_absolute().to_string();
+        let full_name = full_name.trim_start_matches('.');
+        w.impl_args_for_block(
+            &["'a"],
+            "::std::convert::TryFrom<&'a str>",
+            &self.type_name.to_string(),
+            |w| {
+                w.write_line(format!(
+                    "type Error = {}::EnumFromStrError;",
+                    protobuf_crate
+                ));
+                w.write_line("");
+                w.def_fn(
+                    "try_from(s: &'a str) -> ::std::result::Result<Self, Self::Error>",
+                    |w| {
+                        w.match_expr("s", |w| {
+                            for value in self.values_all() {
+                                let name = value.value.proto.name();
+                                w.write_line(format!(
+                                    "\"{}\" | \"{}.{}\" => ::std::result::Result::Ok({}),",
+                                    name,
+                                    full_name,
+                                    name,
+                                    value.rust_name_outer()
+                                ));
+                            }
+                            w.write_line(format!(
+                                "_ => ::std::result::Result::Err({}::EnumFromStrError::new(\"{}\", s)),",
+                                protobuf_crate, full_name
+                            ));
+                        });
+                    },
+                );
+            },
+        );
+    }
+
+    fn write_impl_clean_enum_twin(&self, w: &mut CodeWriter) {
+        let protobuf_crate = protobuf_crate_path(&self.customize.for_elem);
+        let type_name = self.type_name.to_string();
+        w.impl_for_block("::std::convert::TryFrom<i32>", &type_name, |w| {
+            w.write_line("type Error = i32;");
+            w.write_line("");
+            w.def_fn(
+                "try_from(value: i32) -> ::std::result::Result<Self, i32>",
+                |w| {
+                    w.write_line(format!(
+                        "<Self as {}::Enum>::from_i32(value).ok_or(value)",
+                        protobuf_crate
+                    ));
+                },
+            );
+        });
+        w.write_line("");
+        w.impl_for_block(
+            format!(
+                "::std::convert::TryFrom<{}::EnumOrUnknown<{}>>",
+                protobuf_crate, type_name
+            ),
+            &type_name,
+            |w| {
+                w.write_line("type Error = i32;");
+                w.write_line("");
+                w.def_fn(
+                    &format!(
+                        "try_from(value: {}::EnumOrUnknown<{}>) -> ::std::result::Result<Self, i32>",
+                        protobuf_crate, type_name
+                    ),
+                    |w| {
+                        w.write_line("value.enum_value()");
+                    },
+                );
+            },
+        );
+        w.write_line("");
+        w.impl_for_block(format!("::std::convert::From<{}>", type_name), "i32", |w| {
+            w.def_fn(&format!("from(value: {}) -> i32", type_name), |w| {
+                w.write_line(format!("{}::Enum::value(&value)", protobuf_crate));
+            });
+        });
+    }
+
     fn write_impl_enum_const_values(&self, w: &mut CodeWriter) {
         w.write_line(&format!("const VALUES: &'static [{}] = &[", self.type_name));
         w.indented(|w| {
@@ -27,6 +27,7 @@ impl<'a> ExtGen<'a> {
             &ProtobufAbsPath::from(self.field.extendee()),
             &FileAndMod {
                 file: self.file.proto().name().to_owned(),
+                package: self.file.package().to_owned(),
                 relative_mod: RustRelativePath::from("exts"),
                 customize: self.customize.clone(),
             },
@@ -50,6 +51,7 @@ impl<'a> ExtGen<'a> {
                 &ProtobufAbsPath::from(self.field.type_name()),
                 &FileAndMod {
                     file: self.file.proto().name().to_owned(),
+                    package: self.file.package().to_owned(),
                     relative_mod: RustRelativePath::from("exts"),
                     customize: self.customize.clone(),
                 },
@@ -63,9 +63,12 @@ impl FieldGen<'_> {
     }
 
     fn accessor_fn_map(&self, map_field: &MapField) -> AccessorFn {
-        let MapField { .. } = map_field;
+        let name = match map_field.btree_map {
+            false => "make_map_simpler_accessor",
+            true => "make_btree_map_simpler_accessor",
+        };
         AccessorFn {
-            name: "make_map_simpler_accessor".to_owned(),
+            name: name.to_owned(),
             type_params: vec![format!("_"), format!("_")],
             callback_params: self.make_accessor_fns_lambda(),
         }
@@ -128,6 +128,24 @@ impl<'a> FieldElem<'a> {
         }
     }
 
+    /// Expression for upper bound of element size including tag,
+    /// used in `estimated_size`.
+    pub(crate) fn estimated_element_size(&self, field_number: u32, var: &RustValueTyped) -> String {
+        let tag_size = tag_size(field_number);
+        match self.proto_type().max_encoded_size() {
+            Some(data_size) => format!("{}", tag_size + data_size as u64),
+            // Length prefix is at most 5 bytes,
+            // because serialized message size is limited by `u32`.
+            None => match self.proto_type() {
+                Type::TYPE_MESSAGE => format!("{tag_size} + 5 + {}.estimated_size()", var.value),
+                Type::TYPE_STRING | Type::TYPE_BYTES => {
+                    format!("{tag_size} + 5 + {}.len()", var.value)
+                }
+                t => panic!("unexpected type: {:?}", t),
+            },
+        }
+    }
+
     pub(crate) fn singular_field_size(
         &self,
         field_number: u32,
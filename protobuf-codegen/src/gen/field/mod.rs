@@ -60,6 +60,8 @@ pub struct MapField<'a> {
     _message: MessageWithScope<'a>,
     key: FieldElem<'a>,
     value: FieldElem<'a>,
+    /// Stored in `BTreeMap` instead of `HashMap`.
+    btree_map: bool,
 }
 
 #[derive(Clone)]
@@ -138,6 +140,11 @@ pub(crate) struct FieldGen<'a> {
 }
 
 impl<'a> FieldGen<'a> {
+    /// Field is marked with `identity_field` option.
+    pub(crate) fn is_identity(&self) -> bool {
+        self.customize.identity_field.unwrap_or(false)
+    }
+
     pub(crate) fn parse(
         field: FieldWithContext<'a>,
         root_scope: &'a RootScope<'a>,
@@ -160,7 +167,7 @@ impl<'a> FieldGen<'a> {
 
         let generate_accessors = customize
             .generate_accessors
-            .unwrap_or(field_may_have_custom_default_value)
+            .unwrap_or(field_may_have_custom_default_value || customize.prost_compat == Some(true))
             || field.is_oneof();
 
         let default_generate_getter = generate_accessors || field_may_have_custom_default_value;
@@ -181,6 +188,7 @@ impl<'a> FieldGen<'a> {
                     _message: message,
                     key,
                     value,
+                    btree_map: customize.btreemap_fields.unwrap_or(false),
                 })
             }
             RuntimeFieldType::Repeated(..) => {
@@ -188,7 +196,8 @@ impl<'a> FieldGen<'a> {
 
                 FieldKind::Repeated(RepeatedField {
                     elem,
-                    packed: field.field.proto().options.get_or_default().packed(),
+                    packed: field.field.proto().options.get_or_default().packed()
+                        && !customize.force_unpacked_repeated.unwrap_or(false),
                 })
             }
             RuntimeFieldType::Singular(..) => {
@@ -235,6 +244,11 @@ impl<'a> FieldGen<'a> {
         })
     }
 
+    /// Print `<redacted>` instead of field value in `Debug`.
+    pub(crate) fn redact_debug(&self) -> bool {
+        self.customize.redact_debug.unwrap_or(false)
+    }
+
     // for message level
     fn file_and_mod(&self) -> FileAndMod {
         self.proto_field
@@ -275,11 +289,19 @@ impl<'a> FieldGen<'a> {
         match self.kind {
             FieldKind::Repeated(ref repeated) => repeated.rust_type(reference),
             FieldKind::Map(MapField {
-                ref key, ref value, ..
-            }) => RustType::HashMap(
-                Box::new(key.rust_storage_elem_type(reference)),
-                Box::new(value.rust_storage_elem_type(reference)),
-            ),
+                ref key,
+                ref value,
+                btree_map,
+                ..
+            }) => {
+                let key = Box::new(key.rust_storage_elem_type(reference));
+                let value = Box::new(value.rust_storage_elem_type(reference));
+                if btree_map {
+                    RustType::BTreeMap(key, value)
+                } else {
+                    RustType::HashMap(key, value)
+                }
+            }
             FieldKind::Singular(ref singular) => singular.rust_storage_type(reference),
             FieldKind::Oneof(..) => unreachable!(),
         }
@@ -318,6 +340,16 @@ impl<'a> FieldGen<'a> {
         }
     }
 
+    /// Type of param of `fn set_foo(..)` as code in the scope of the message.
+    pub(crate) fn set_xxx_param_type_code(&self) -> String {
+        let reference = self
+            .proto_field
+            .message
+            .scope
+            .file_and_mod(self.customize.clone());
+        self.set_xxx_param_type(&reference).to_code(&self.customize)
+    }
+
     // for field `foo`, return type if `fn take_foo(..)`
     fn take_xxx_return_type(&self, reference: &FileAndMod) -> RustType {
         self.set_xxx_param_type(reference)
@@ -575,6 +607,9 @@ impl<'a> FieldGen<'a> {
             w.all_documentation(self.info, &self.path);
 
             write_protoc_insertion_point_for_field(w, &self.customize, &self.proto_field.field);
+            if self.customize.serde_omit_defaults.unwrap_or(false) {
+                w.write_line(serde_omit_default_attr(&self.customize));
+            }
             w.field_decl_vis(
                 Visibility::Public,
                 &self.rust_name.to_string(),
@@ -890,6 +925,64 @@ impl<'a> FieldGen<'a> {
         format!("clear_{}", self.rust_name)
     }
 
+    /// Statement reading this field with `protobuf_json_mapping::rt::FieldParser`
+    /// in code generated with `gen_json_methods` option,
+    /// or `None` if the field is merged with reflection.
+    pub(crate) fn merge_from_json_stmt(&self, m: &str, parser: &str) -> Option<String> {
+        fn read_fn(elem: &FieldElem) -> Option<&'static str> {
+            match elem {
+                FieldElem::Primitive(t, PrimitiveTypeVariant::Default) => match t {
+                    Type::TYPE_INT32 | Type::TYPE_SINT32 | Type::TYPE_SFIXED32 => Some("read_i32"),
+                    Type::TYPE_INT64 | Type::TYPE_SINT64 | Type::TYPE_SFIXED64 => Some("read_i64"),
+                    Type::TYPE_UINT32 | Type::TYPE_FIXED32 => Some("read_u32"),
+                    Type::TYPE_UINT64 | Type::TYPE_FIXED64 => Some("read_u64"),
+                    Type::TYPE_FLOAT => Some("read_f32"),
+                    Type::TYPE_DOUBLE => Some("read_f64"),
+                    Type::TYPE_BOOL => Some("read_bool"),
+                    Type::TYPE_STRING => Some("read_string"),
+                    Type::TYPE_BYTES => Some("read_bytes"),
+                    _ => None,
+                },
+                _ => None,
+            }
+        }
+
+        match &self.kind {
+            FieldKind::Singular(SingularField {
+                flag:
+                    SingularFieldFlag::WithFlag {
+                        option_kind: OptionKind::Option,
+                        ..
+                    },
+                elem,
+            }) => Some(format!(
+                "{}.{} = ::std::option::Option::Some({}.{}()?);",
+                m,
+                self.rust_name,
+                parser,
+                read_fn(elem)?
+            )),
+            FieldKind::Singular(SingularField {
+                flag: SingularFieldFlag::WithoutFlag,
+                elem,
+            }) => Some(format!(
+                "{}.{} = {}.{}()?;",
+                m,
+                self.rust_name,
+                parser,
+                read_fn(elem)?
+            )),
+            FieldKind::Repeated(r) => Some(format!(
+                "{}.read_repeated(&mut {}.{}, |p| p.{}())?;",
+                parser,
+                m,
+                self.rust_name,
+                read_fn(&r.elem)?
+            )),
+            _ => None,
+        }
+    }
+
     fn write_merge_from_field_message_string_bytes_repeated(
         &self,
         r: &RepeatedField,
@@ -941,12 +1034,31 @@ impl<'a> FieldGen<'a> {
                 typed
             };
 
-            w.write_line(&format!(
-                "self.{} = ::std::option::Option::Some({}({}));",
-                o.oneof_field_name,
-                o.variant_path(&self.proto_field.message.scope.rust_path_to_file()),
-                maybe_boxed.value
-            ));
+            let variant_path = o.variant_path(&self.proto_field.message.scope.rust_path_to_file());
+            let assign = format!(
+                "self.{} = ::std::option::Option::Some({}({}))",
+                o.oneof_field_name, variant_path, maybe_boxed.value
+            );
+
+            match o.elem {
+                // Message which is already set is merged, as for singular message fields.
+                FieldElem::Message(..) => {
+                    w.match_block(format!("self.{}", o.oneof_field_name), |w| {
+                        w.case_expr(
+                            format!("::std::option::Option::Some({}(ref mut v))", variant_path),
+                            if o.boxed {
+                                "is.merge_message(&mut **v)?"
+                            } else {
+                                "is.merge_message(v)?"
+                            },
+                        );
+                        w.case_expr("_", &assign);
+                    });
+                }
+                _ => {
+                    w.write_line(format!("{};", assign));
+                }
+            }
         })
     }
 
@@ -1255,6 +1367,76 @@ impl<'a> FieldGen<'a> {
         }
     }
 
+    pub(crate) fn write_element_estimated_size(
+        &self,
+        elem: &FieldElem,
+        w: &mut CodeWriter,
+        item_var: &RustValueTyped,
+        sum_var: &str,
+    ) {
+        w.write_line(format!(
+            "{} += {};",
+            sum_var,
+            elem.estimated_element_size(self.proto_field.number() as u32, item_var)
+        ));
+    }
+
+    pub(crate) fn write_message_estimated_field_size(&self, sum_var: &str, w: &mut CodeWriter) {
+        match &self.kind {
+            FieldKind::Singular(s @ SingularField { elem, .. }) => {
+                self.write_if_let_self_field_is_some(s, w, |v, w| {
+                    self.write_element_estimated_size(elem, w, v, sum_var)
+                });
+            }
+            FieldKind::Repeated(RepeatedField { packed, elem, .. }) => {
+                match (elem.proto_type().max_encoded_size(), packed) {
+                    (Some(s), true) => {
+                        w.write_line(format!(
+                            "{} += {} + 5 + {} * {}.len();",
+                            sum_var,
+                            self.tag_size(),
+                            s,
+                            self.self_field()
+                        ));
+                    }
+                    (Some(s), false) => {
+                        w.write_line(format!(
+                            "{} += {} * {}.len();",
+                            sum_var,
+                            s + self.tag_size(),
+                            self.self_field()
+                        ));
+                    }
+                    (None, _) => {
+                        self.write_for_self_field(w, "value", |w, value_type| {
+                            self.write_element_estimated_size(
+                                elem,
+                                w,
+                                &RustValueTyped {
+                                    value: "value".to_owned(),
+                                    rust_type: value_type.clone(),
+                                },
+                                sum_var,
+                            );
+                        });
+                    }
+                }
+            }
+            FieldKind::Map(MapField { key, value, .. }) => {
+                self.for_each_map_entry(key, value, w, |k, v, w| {
+                    w.write_line(format!(
+                        "{} += {} + 5 + {} + {};",
+                        sum_var,
+                        self.tag_size(),
+                        key.estimated_element_size(1, k),
+                        value.estimated_element_size(2, v)
+                    ));
+                });
+            }
+            FieldKind::Oneof(..) => unreachable!(),
+        }
+    }
+
     fn write_message_field_get_singular_message(&self, s: &SingularField, w: &mut CodeWriter) {
         match s.flag {
             SingularFieldFlag::WithoutFlag => unimplemented!(),
@@ -1413,20 +1595,114 @@ impl<'a> FieldGen<'a> {
             get_xxx_return_type.to_code(&self.customize)
         );
 
-        w.pub_fn(&fn_def, |w| match self.kind {
-            FieldKind::Oneof(ref o) => {
-                self.write_message_field_get_oneof(o, w);
+        self.write_inline_attr(w);
+        w.pub_fn(&fn_def, |w| {
+            if self.customize.instrument_getters.unwrap_or(false) {
+                w.write_line(format!(
+                    "{}::rt::log_getter(\"{}\");",
+                    protobuf_crate_path(&self.customize),
+                    self.proto_field.field.full_name(),
+                ));
             }
-            FieldKind::Singular(ref s) => {
-                self.write_message_field_get_singular(s, w);
+            match self.kind {
+                FieldKind::Oneof(ref o) => {
+                    self.write_message_field_get_oneof(o, w);
+                }
+                FieldKind::Singular(ref s) => {
+                    self.write_message_field_get_singular(s, w);
+                }
+                FieldKind::Repeated(..) | FieldKind::Map(..) => {
+                    let self_field = self.self_field();
+                    w.write_line(&format!("&{}", self_field));
+                }
             }
-            FieldKind::Repeated(..) | FieldKind::Map(..) => {
-                let self_field = self.self_field();
-                w.write_line(&format!("&{}", self_field));
+        });
+    }
+
+    fn is_required(&self) -> bool {
+        matches!(
+            self.kind,
+            FieldKind::Singular(SingularField {
+                flag: SingularFieldFlag::WithFlag { required: true, .. },
+                ..
+            })
+        )
+    }
+
+    fn write_message_field_try_get(&self, w: &mut CodeWriter) {
+        let fn_def = format!(
+            "try_get_{}(&self) -> {}::Result<{}>",
+            self.rust_name,
+            protobuf_crate_path(&self.customize),
+            self.getter_return_type().to_code(&self.customize)
+        );
+        let err = format!(
+            "{}::rt::required_field_not_set(\"{}\")",
+            protobuf_crate_path(&self.customize),
+            self.proto_field.field.full_name(),
+        );
+        self.write_inline_attr(w);
+        w.pub_fn(&fn_def, |w| {
+            if let FieldElem::Message(..) = self.elem() {
+                w.write_line(format!(
+                    "{}.as_ref().ok_or_else(|| {})",
+                    self.self_field(),
+                    err
+                ));
+            } else {
+                w.if_stmt(self.self_field_is_none(), |w| {
+                    w.write_line(format!("return ::std::result::Result::Err({});", err));
+                });
+                w.write_line(format!(
+                    "::std::result::Result::Ok(self.{}())",
+                    self.rust_name
+                ));
+            }
+        });
+    }
+
+    fn write_message_field_expect(&self, w: &mut CodeWriter) {
+        let fn_def = format!(
+            "expect_{}(&self) -> {}",
+            self.rust_name,
+            self.getter_return_type().to_code(&self.customize)
+        );
+        let msg = format!(
+            "\"required field `{}` is not set\"",
+            self.proto_field.field.full_name()
+        );
+        self.write_inline_attr(w);
+        self.write_track_caller_attr(w);
+        w.pub_fn(&fn_def, |w| {
+            if let FieldElem::Message(..) = self.elem() {
+                w.write_line(format!("{}.as_ref().expect({})", self.self_field(), msg));
+            } else {
+                w.if_stmt(self.self_field_is_none(), |w| {
+                    w.write_line(format!("panic!({});", msg));
+                });
+                w.write_line(format!("self.{}()", self.rust_name));
             }
         });
     }
 
+    fn write_message_field_iter(&self, repeated: &RepeatedField, w: &mut CodeWriter) {
+        let elem_type = repeated
+            .elem
+            .rust_storage_elem_type(&self.file_and_mod())
+            .to_code(&self.customize);
+        self.write_inline_attr(w);
+        w.pub_fn(
+            &format!(
+                "{}(&self) -> impl ::std::iter::Iterator<Item = &{}> + '_",
+                self.iter_name(),
+                elem_type
+            ),
+            |w| {
+                w.write_line(format!("{}.iter()", self.self_field()));
+            },
+        );
+    }
+
     fn has_has(&self) -> bool {
         match self.kind {
             FieldKind::Repeated(..) | FieldKind::Map(..) => false,
@@ -1462,7 +1738,7 @@ impl<'a> FieldGen<'a> {
         RustIdent::new(&format!("has_{}", self.rust_name.get()))
     }
 
-    fn set_name(&self) -> RustIdent {
+    pub(crate) fn set_name(&self) -> RustIdent {
         RustIdent::new(&format!("set_{}", self.rust_name.get()))
     }
 
@@ -1470,7 +1746,12 @@ impl<'a> FieldGen<'a> {
         RustIdent::new(&format!("mut_{}", self.rust_name.get()))
     }
 
+    fn iter_name(&self) -> RustIdent {
+        RustIdent::new(&format!("{}_iter", self.rust_name.get()))
+    }
+
     fn write_message_field_has(&self, w: &mut CodeWriter) {
+        self.write_inline_attr(w);
         w.pub_fn(
             &format!("{}(&self) -> bool", self.has_name()),
             |w| match self.kind {
@@ -1505,6 +1786,7 @@ impl<'a> FieldGen<'a> {
                 .file_and_mod(self.customize.clone()),
         );
         w.comment("Param is passed by value, moved");
+        self.write_inline_attr(w);
         w.pub_fn(
             &format!(
                 "{}(&mut self, v: {})",
@@ -1603,6 +1885,15 @@ impl<'a> FieldGen<'a> {
                 mut_xxx_return_type.to_code(&self.customize)
             ),
         };
+        self.write_inline_attr(w);
+        match self.kind {
+            FieldKind::Singular(SingularField {
+                flag: SingularFieldFlag::WithFlag { .. },
+                ..
+            })
+            | FieldKind::Oneof(..) => self.write_track_caller_attr(w),
+            _ => {}
+        }
         w.pub_fn(&fn_def, |w| {
             match self.kind {
                 FieldKind::Repeated(..) | FieldKind::Map(..) => {
@@ -1778,6 +2069,10 @@ impl<'a> FieldGen<'a> {
                 .file_and_mod(self.customize.clone()),
         );
         w.comment("Take field");
+        self.write_inline_attr(w);
+        if let FieldKind::Oneof(..) = self.kind {
+            self.write_track_caller_attr(w);
+        }
         w.pub_fn(
             &format!(
                 "take_{}(&mut self) -> {}",
@@ -1798,6 +2093,19 @@ impl<'a> FieldGen<'a> {
         );
     }
 
+    fn write_inline_attr(&self, w: &mut CodeWriter) {
+        if self.customize.inline_accessors.unwrap_or(false) {
+            w.write_line("#[inline]");
+        }
+    }
+
+    /// For accessors which unwrap internally.
+    fn write_track_caller_attr(&self, w: &mut CodeWriter) {
+        if self.customize.track_caller.unwrap_or(false) {
+            w.write_line("#[track_caller]");
+        }
+    }
+
     pub(crate) fn write_message_single_field_accessors(&self, w: &mut CodeWriter) {
         if self.generate_accessors || self.generate_getter {
             w.write_line("");
@@ -1810,12 +2118,38 @@ impl<'a> FieldGen<'a> {
             self.write_message_field_get(w);
         }
 
+        // Message getter is not generated by default, but `try_get_` does not need it.
+        if self.is_required()
+            && self.customize.result_required_getters.unwrap_or(false)
+            && (self.generate_getter || matches!(self.elem(), FieldElem::Message(..)))
+        {
+            w.write_line("");
+            self.write_message_field_try_get(w);
+        }
+
+        // Panicking getters are only useful together with `#[track_caller]`.
+        if self.is_required()
+            && self.customize.track_caller.unwrap_or(false)
+            && (self.generate_getter || matches!(self.elem(), FieldElem::Message(..)))
+        {
+            w.write_line("");
+            self.write_message_field_expect(w);
+        }
+
+        if let FieldKind::Repeated(ref repeated) = self.kind {
+            if self.customize.iter_accessors.unwrap_or(false) {
+                w.write_line("");
+                self.write_message_field_iter(repeated, w);
+            }
+        }
+
         if !self.generate_accessors {
             return;
         }
 
         w.write_line("");
         let clear_field_func = self.clear_field_func();
+        self.write_inline_attr(w);
         w.pub_fn(&format!("{}(&mut self)", clear_field_func), |w| {
             self.write_clear(w);
         });
@@ -1840,6 +2174,14 @@ impl<'a> FieldGen<'a> {
     }
 }
 
+/// `#[serde]` attribute for `serde_omit_defaults` option.
+pub(crate) fn serde_omit_default_attr(customize: &Customize) -> String {
+    format!(
+        "#[serde(default, skip_serializing_if = \"{}::rt::is_default\")]",
+        protobuf_crate_path(customize)
+    )
+}
+
 pub(crate) fn rust_field_name_for_protobuf_field_name(name: &str) -> RustIdent {
     RustIdent::new(name)
 }
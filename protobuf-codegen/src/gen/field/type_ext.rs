@@ -11,6 +11,7 @@ pub(crate) trait TypeExt {
     fn rust_type(&self) -> RustType;
     fn os_write_fn_param_type(&self) -> RustType;
     fn encoded_size(&self) -> Option<u32>;
+    fn max_encoded_size(&self) -> Option<u32>;
 }
 
 impl TypeExt for Type {
@@ -112,4 +113,18 @@ impl TypeExt for Type {
             _ => None,
         }
     }
+
+    /// Max size of encoded value of scalar type (excluding tag).
+    fn max_encoded_size(&self) -> Option<u32> {
+        match self {
+            Type::TYPE_UINT32 | Type::TYPE_SINT32 => Some(5),
+            // Negative `int32` and enum values are encoded as 10 bytes.
+            Type::TYPE_INT32
+            | Type::TYPE_INT64
+            | Type::TYPE_UINT64
+            | Type::TYPE_SINT64
+            | Type::TYPE_ENUM => Some(10),
+            _ => self.encoded_size(),
+        }
+    }
 }
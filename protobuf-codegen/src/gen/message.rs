@@ -1,8 +1,12 @@
+use std::collections::HashSet;
 use std::fmt;
 
 use protobuf::descriptor::*;
 use protobuf::reflect::FileDescriptor;
 use protobuf::reflect::MessageDescriptor;
+use protobuf::reflect::RuntimeFieldType;
+use protobuf::reflect::RuntimeType;
+use protobuf_parse::camel_case;
 use protobuf_parse::snake_case;
 
 use crate::customize::ctx::CustomizeElemCtx;
@@ -11,6 +15,8 @@ use crate::customize::rustproto_proto::customize_from_rustproto_for_message;
 use crate::gen::code_writer::*;
 use crate::gen::descriptor::write_fn_descriptor;
 use crate::gen::enums::*;
+use crate::gen::field::elem::FieldElem;
+use crate::gen::field::serde_omit_default_attr;
 use crate::gen::field::FieldGen;
 use crate::gen::field::FieldKind;
 use crate::gen::file_and_mod::FileAndMod;
@@ -30,6 +36,10 @@ use crate::gen::scope::RootScope;
 use crate::gen::scope::WithScope;
 use crate::Customize;
 
+/// Messages with more fields do not get tuple conversions
+/// generated with `tuple_conversion` option.
+const TUPLE_CONVERSION_MAX_FIELDS: usize = 12;
+
 /// Protobuf message Rust type name
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct RustTypeMessage(pub RustIdentWithPath);
@@ -57,6 +67,40 @@ impl RustTypeMessage {
     }
 }
 
+#[derive(Debug, thiserror::Error)]
+enum MessageGenError {
+    #[error("`identity_field` option is not supported for oneof field `{0}`")]
+    IdentityFieldInOneof(String),
+    #[error("`identity_field` option is not supported for floating point field `{0}`")]
+    FloatIdentityField(String),
+    #[error("`identity_field` option is not supported for message field `{0}`")]
+    MessageIdentityField(String),
+}
+
+/// Whether field types of message (and of messages it references) permit `Eq`:
+/// there are no floating point fields, and all referenced messages are
+/// defined in the same file (and so generated with the same options).
+fn message_supports_derive_eq(message: &MessageDescriptor, visited: &mut HashSet<String>) -> bool {
+    if !visited.insert(message.full_name().to_owned()) {
+        // Recursive messages are checked when first visited.
+        return true;
+    }
+    let type_supports_eq = |t: RuntimeType, visited: &mut HashSet<String>| match t {
+        RuntimeType::F32 | RuntimeType::F64 => false,
+        RuntimeType::Message(m) => {
+            m.file_descriptor() == message.file_descriptor()
+                && message_supports_derive_eq(&m, visited)
+        }
+        _ => true,
+    };
+    message.fields().all(|f| match f.runtime_field_type() {
+        RuntimeFieldType::Singular(t) | RuntimeFieldType::Repeated(t) => {
+            type_supports_eq(t, visited)
+        }
+        RuntimeFieldType::Map(k, v) => type_supports_eq(k, visited) && type_supports_eq(v, visited),
+    })
+}
+
 /// Message info for codegen
 pub(crate) struct MessageGen<'a> {
     file_descriptor: &'a FileDescriptor,
@@ -173,6 +217,11 @@ impl<'a> MessageGen<'a> {
             .collect()
     }
 
+    /// Fields marked with `identity_field` option.
+    fn identity_fields(&'a self) -> Vec<&'a FieldGen<'a>> {
+        self.fields.iter().filter(|f| f.is_identity()).collect()
+    }
+
     fn fields_except_group(&'a self) -> Vec<&'a FieldGen> {
         self.fields
             .iter()
@@ -337,6 +386,27 @@ impl<'a> MessageGen<'a> {
         });
     }
 
+    fn write_estimated_size(&self, w: &mut CodeWriter) {
+        // there are unused variables in oneof
+        w.allow(&["unused_variables"]);
+        w.def_fn("estimated_size(&self) -> usize", |w| {
+            w.write_line("let mut my_size = 0;");
+            for field in self.fields_except_oneof_and_group() {
+                field.write_message_estimated_field_size("my_size", w);
+            }
+            self.write_match_each_oneof_variant(w, |w, variant, v| {
+                variant
+                    .field
+                    .write_element_estimated_size(variant.elem(), w, v, "my_size");
+            });
+            w.write_line(format!(
+                "my_size += {}::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;",
+                protobuf_crate_path(&self.customize.for_elem)
+            ));
+            w.write_line("my_size");
+        });
+    }
+
     fn write_field_accessors(&self, w: &mut CodeWriter) {
         for f in self.fields_except_group() {
             f.write_message_single_field_accessors(w);
@@ -349,8 +419,43 @@ impl<'a> MessageGen<'a> {
                 w.write_line("::std::default::Default::default()");
             });
 
+            if self
+                .customize
+                .for_elem
+                .emit_field_count_assert
+                .unwrap_or(false)
+            {
+                w.write_line("");
+                w.write_line("/// Number of fields in this message, including fields in oneofs.");
+                w.pub_const("FIELD_COUNT", "usize", &self.fields.len().to_string());
+            }
+
+            if self
+                .customize
+                .for_elem
+                .emit_full_name_const
+                .unwrap_or(false)
+            {
+                w.write_line("");
+                w.write_line("/// Fully qualified protobuf message name.");
+                w.pub_const(
+                    "FULL_NAME",
+                    "&'static str",
+                    &format!("\"{}\"", self.message.message.full_name()),
+                );
+            }
+
+            if self.customize.for_elem.emit_field_meta.unwrap_or(false) {
+                w.write_line("");
+                self.write_field_meta(w);
+            }
+
             self.write_field_accessors(w);
 
+            if self.customize.for_elem.prost_compat.unwrap_or(false) {
+                self.write_prost_compat_fns(w);
+            }
+
             if !self.lite_runtime {
                 w.write_line("");
                 self.write_generated_message_descriptor_data(w);
@@ -358,6 +463,212 @@ impl<'a> MessageGen<'a> {
         });
     }
 
+    fn write_prost_compat_fns(&self, w: &mut CodeWriter) {
+        let protobuf_crate = protobuf_crate_path(&self.customize.for_elem);
+        w.write_line("");
+        w.write_line("/// Size of serialized message, like `prost::Message::encoded_len`.");
+        w.pub_fn("encoded_len(&self) -> usize", |w| {
+            w.write_line(format!(
+                "{}::Message::compute_size(self) as usize",
+                protobuf_crate
+            ));
+        });
+        w.write_line("");
+        w.write_line("/// Serialize message, like `prost::Message::encode_to_vec`.");
+        w.write_line("///");
+        w.write_line("/// Like in `prost`, required fields are not checked,");
+        w.write_line("/// unlike in `write_to_bytes`.");
+        w.pub_fn("encode_to_vec(&self) -> ::std::vec::Vec<u8>", |w| {
+            w.write_line(format!(
+                "let size = {}::Message::compute_size(self) as usize;",
+                protobuf_crate
+            ));
+            w.write_line("let mut v = ::std::vec::Vec::with_capacity(size);");
+            w.write_line(format!(
+                "let mut os = {}::CodedOutputStream::vec(&mut v);",
+                protobuf_crate
+            ));
+            w.write_line(format!(
+                "{}::Message::write_to_with_cached_sizes(self, &mut os).expect(\"write to vec\");",
+                protobuf_crate
+            ));
+            w.write_line("os.flush().expect(\"write to vec\");");
+            w.write_line("drop(os);");
+            w.write_line("v");
+        });
+        w.write_line("");
+        w.write_line("/// Parse message, like `prost::Message::decode`.");
+        w.pub_fn(
+            &format!("decode(buf: &[u8]) -> {}::Result<Self>", protobuf_crate),
+            |w| {
+                w.write_line(format!(
+                    "{}::Message::parse_from_bytes(buf)",
+                    protobuf_crate
+                ));
+            },
+        );
+    }
+
+    fn write_field_meta(&self, w: &mut CodeWriter) {
+        let protobuf_crate = protobuf_crate_path(&self.customize.for_elem);
+        w.write_line("/// Static metadata of fields of this message.");
+        w.write_line(format!(
+            "pub const FIELDS: &[{}::reflect::FieldMeta] = &[",
+            protobuf_crate
+        ));
+        w.indented(|w| {
+            for field in &self.fields {
+                let field = &field.proto_field.field;
+                w.write_line(format!("{}::reflect::FieldMeta {{", protobuf_crate));
+                w.indented(|w| {
+                    w.write_line(format!("name: \"{}\",", field.name()));
+                    w.write_line(format!("number: {},", field.number()));
+                    w.write_line(format!(
+                        "type_: {}::descriptor::field_descriptor_proto::Type::{:?},",
+                        protobuf_crate,
+                        field.proto().type_()
+                    ));
+                    w.write_line(format!("repeated: {},", field.is_repeated_or_map()));
+                    w.write_line(format!("in_oneof: {},", field.containing_oneof().is_some()));
+                });
+                w.write_line("},");
+            }
+        });
+        w.write_line("];");
+    }
+
+    fn write_typestate_builder(&self, w: &mut CodeWriter) {
+        let required: Vec<&FieldGen> = self
+            .fields
+            .iter()
+            .filter(|f| f.proto_field.is_required())
+            .collect();
+        if required.is_empty() {
+            return;
+        }
+        w.write_line("");
+        if let Some(f) = required.iter().find(|f| !f.generate_accessors) {
+            w.comment(&format!(
+                "Type-state builder is not generated: required field `{}` has no accessors",
+                f.proto_field.name()
+            ));
+            return;
+        }
+
+        let typestate = format!(
+            "{}::rt::typestate",
+            protobuf_crate_path(&self.customize.for_elem)
+        );
+        let builder = format!("{}Builder", self.rust_name());
+        let state_params: Vec<String> = required
+            .iter()
+            .map(|f| format!("State{}", camel_case(f.proto_field.name())))
+            .collect();
+        let builder_with_states = |states: &[String]| format!("{}<{}>", builder, states.join(", "));
+
+        w.write_line(format!(
+            "/// Builder for [`{}`] which checks at compile time that all required fields are set.",
+            self.rust_name()
+        ));
+        w.write_line("///");
+        w.write_line("/// Type parameters track which required fields are set,");
+        w.write_line("/// `build` is available when all required fields are set.");
+        w.pub_struct(builder_with_states(&state_params), |w| {
+            w.field_decl("message", &self.rust_name().to_string());
+            w.field_decl(
+                "_state",
+                &format!("::std::marker::PhantomData<({},)>", state_params.join(", ")),
+            );
+        });
+
+        w.write_line("");
+        w.impl_self_block(self.rust_name().to_string(), |w| {
+            w.write_line(format!("/// Create new [`{}`].", builder));
+            let unset = vec![format!("{}::Unset", typestate); required.len()];
+            w.pub_fn(
+                &format!("builder() -> {}", builder_with_states(&unset)),
+                |w| {
+                    w.write_line(format!("{} {{", builder));
+                    w.indented(|w| {
+                        w.write_line(format!("message: {}::new(),", self.rust_name()));
+                        w.write_line("_state: ::std::marker::PhantomData,");
+                    });
+                    w.write_line("}");
+                },
+            );
+        });
+
+        w.write_line("");
+        w.expr_block(
+            &format!(
+                "impl<{}> {}",
+                state_params.join(", "),
+                builder_with_states(&state_params)
+            ),
+            |w| {
+                let mut first = true;
+                for field in &self.fields {
+                    if !field.generate_accessors {
+                        continue;
+                    }
+                    if !first {
+                        w.write_line("");
+                    }
+                    first = false;
+                    let param_type = field.set_xxx_param_type_code();
+                    match required
+                        .iter()
+                        .position(|r| r.proto_field.number() == field.proto_field.number())
+                    {
+                        Some(i) => {
+                            let mut states = state_params.clone();
+                            states[i] = format!("{}::Set", typestate);
+                            w.pub_fn(
+                                &format!(
+                                    "{}(self, v: {}) -> {}",
+                                    field.rust_name,
+                                    param_type,
+                                    builder_with_states(&states)
+                                ),
+                                |w| {
+                                    w.write_line("let mut message = self.message;");
+                                    w.write_line(format!("message.{}(v);", field.set_name()));
+                                    w.write_line(format!("{} {{", builder));
+                                    w.indented(|w| {
+                                        w.write_line("message,");
+                                        w.write_line("_state: ::std::marker::PhantomData,");
+                                    });
+                                    w.write_line("}");
+                                },
+                            );
+                        }
+                        None => {
+                            w.pub_fn(
+                                &format!(
+                                    "{}(mut self, v: {}) -> Self",
+                                    field.rust_name, param_type
+                                ),
+                                |w| {
+                                    w.write_line(format!("self.message.{}(v);", field.set_name()));
+                                    w.write_line("self");
+                                },
+                            );
+                        }
+                    }
+                }
+            },
+        );
+
+        w.write_line("");
+        let set = vec![format!("{}::Set", typestate); required.len()];
+        w.impl_self_block(builder_with_states(&set), |w| {
+            w.write_line("/// Build the message, all required fields are set.");
+            w.pub_fn(&format!("build(self) -> {}", self.rust_name()), |w| {
+                w.write_line("self.message");
+            });
+        });
+    }
+
     fn write_unknown_fields(&self, w: &mut CodeWriter) {
         let sig = format!(
             "special_fields(&self) -> &{}::SpecialFields",
@@ -383,20 +694,52 @@ impl<'a> MessageGen<'a> {
             protobuf_crate_path(&self.customize.for_elem),
         );
         w.def_fn(&sig, |w| {
-            w.while_block("let Some(tag) = is.read_raw_tag_or_eof()?", |w| {
-                w.match_block("tag", |w| {
-                    for f in &self.fields_except_group() {
-                        f.write_merge_from_field_case_block(w);
-                    }
-                    w.case_block("tag", |w| {
-                        w.write_line(&format!("{}::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;", protobuf_crate_path(&self.customize.for_elem)));
-                    });
+            if self.customize.for_elem.log_parse_errors.unwrap_or(false) {
+                self.write_merge_from_with_field_path(w);
+            } else {
+                self.write_merge_from_loop(w);
+                w.write_line("::std::result::Result::Ok(())");
+            }
+        });
+    }
+
+    fn write_merge_from_loop(&self, w: &mut CodeWriter) {
+        let log_parse_errors = self.customize.for_elem.log_parse_errors.unwrap_or(false);
+        w.while_block("let Some(tag) = is.read_raw_tag_or_eof()?", |w| {
+            if log_parse_errors {
+                w.write_line("field_number = tag >> 3;");
+            }
+            w.match_block("tag", |w| {
+                for f in &self.fields_except_group() {
+                    f.write_merge_from_field_case_block(w);
+                }
+                w.case_block("tag", |w| {
+                    w.write_line(format!("{}::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;", protobuf_crate_path(&self.customize.for_elem)));
                 });
             });
-            w.write_line("::std::result::Result::Ok(())");
         });
     }
 
+    /// Parse loop is wrapped in a closure, so errors from any field
+    /// can be reported with the number of the field being parsed.
+    fn write_merge_from_with_field_path(&self, w: &mut CodeWriter) {
+        let protobuf_crate = protobuf_crate_path(&self.customize.for_elem);
+        w.write_line("let mut field_number = 0;");
+        w.block(
+            &format!("let r = (|| -> {}::Result<()> {{", protobuf_crate),
+            "})();",
+            |w| {
+                self.write_merge_from_loop(w);
+                w.write_line("::std::result::Result::Ok(())");
+            },
+        );
+        w.write_line(format!(
+            "r.map_err(|e| {}::rt::parse_error_in_field(\"{}\", field_number, is, e))",
+            protobuf_crate,
+            self.message.message.full_name(),
+        ));
+    }
+
     fn write_impl_message_full_fn_descriptor(&self, w: &mut CodeWriter) {
         write_fn_descriptor(
             &self.message.message,
@@ -494,6 +837,8 @@ impl<'a> MessageGen<'a> {
                 w.write_line("");
                 self.write_compute_size(w);
                 w.write_line("");
+                self.write_estimated_size(w);
+                w.write_line("");
                 self.write_write_to_with_cached_sizes(w);
                 w.write_line("");
                 self.write_unknown_fields(w);
@@ -561,12 +906,44 @@ impl<'a> MessageGen<'a> {
         );
     }
 
+    fn write_impl_from_str(&self, w: &mut CodeWriter) {
+        let protobuf_crate = protobuf_crate_path(&self.customize.for_elem);
+        w.impl_for_block("::std::str::FromStr", self.rust_name().to_string(), |w| {
+            w.write_line(format!("type Err = {}::Error;", protobuf_crate));
+            w.write_line("");
+            w.def_fn(
+                "from_str(s: &str) -> ::std::result::Result<Self, Self::Err>",
+                |w| {
+                    w.write_line(format!(
+                        "{}::text_format::parse_from_str(s).map_err(::std::convert::Into::into)",
+                        protobuf_crate
+                    ));
+                },
+            );
+        });
+    }
+
     fn supports_derive_partial_eq(&self) -> bool {
         // There's stack overflow in the compiler when struct has too many fields
         // https://github.com/rust-lang/rust/issues/40119
         self.fields.len() <= 500
     }
 
+    fn total_float_eq(&self) -> bool {
+        self.customize.for_elem.total_float_eq.unwrap_or(false)
+    }
+
+    fn derive_eq_requested(&self) -> bool {
+        self.customize.for_elem.derive_eq.unwrap_or(false)
+    }
+
+    /// Derive `Eq` for this message and its oneofs.
+    pub(crate) fn derive_eq(&self) -> bool {
+        self.derive_eq_requested()
+            && self.supports_derive_partial_eq()
+            && message_supports_derive_eq(&self.message_descriptor, &mut HashSet::new())
+    }
+
     fn write_struct(&self, w: &mut CodeWriter) {
         write_protoc_insertion_point_for_message(
             w,
@@ -574,10 +951,21 @@ impl<'a> MessageGen<'a> {
             &self.message_descriptor,
         );
         let mut derive = Vec::new();
-        if self.supports_derive_partial_eq() {
+        let identity = !self.identity_fields().is_empty();
+        if self.supports_derive_partial_eq() && !identity && !self.total_float_eq() {
             derive.push("PartialEq");
         }
-        derive.extend(&["Clone", "Default", "Debug"]);
+        if identity {
+            // `PartialEq` and `Eq` are implemented with identity fields.
+        } else if self.derive_eq() {
+            derive.push("Eq");
+        } else if self.derive_eq_requested() {
+            w.comment("`Eq` is not derived: message has floating point fields or fields of messages from other files");
+        }
+        derive.extend(&["Clone", "Default"]);
+        if !self.redact_debug() {
+            derive.push("Debug");
+        }
         w.derive(&derive);
 
         w.pub_struct(&format!("{}", self.rust_name()), |w| {
@@ -590,6 +978,16 @@ impl<'a> MessageGen<'a> {
             if !self.oneofs().is_empty() {
                 w.comment("message oneof groups");
                 for oneof in self.oneofs() {
+                    if self
+                        .customize
+                        .for_elem
+                        .serde_flatten_oneofs
+                        .unwrap_or(false)
+                    {
+                        w.write_line("#[serde(flatten)]");
+                    } else if self.customize.for_elem.serde_omit_defaults.unwrap_or(false) {
+                        w.write_line(serde_omit_default_attr(&self.customize.for_elem));
+                    }
                     w.field_decl_vis(
                         Visibility::Public,
                         &oneof.oneof.field_name().to_string(),
@@ -626,6 +1024,54 @@ impl<'a> MessageGen<'a> {
         });
     }
 
+    fn redact_debug(&self) -> bool {
+        self.fields.iter().any(|f| f.redact_debug())
+    }
+
+    fn write_impl_debug(&self, w: &mut CodeWriter) {
+        w.impl_for_block("::std::fmt::Debug", self.rust_name().to_string(), |w| {
+            w.def_fn(
+                "fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result",
+                |w| {
+                    w.write_line(format!("f.debug_struct(\"{}\")", self.rust_name()));
+                    w.indented(|w| {
+                        for field in self.fields_except_oneof_and_group() {
+                            if field.redact_debug() {
+                                w.write_line(format!(
+                                    ".field(\"{}\", &format_args!(\"<redacted>\"))",
+                                    field.rust_name
+                                ));
+                            } else {
+                                w.write_line(format!(
+                                    ".field(\"{0}\", &self.{0})",
+                                    field.rust_name
+                                ));
+                            }
+                        }
+                        for oneof in self.oneofs() {
+                            let redact = self.fields.iter().any(|f| {
+                                f.redact_debug()
+                                    && f.proto_field.field.containing_oneof().as_ref()
+                                        == Some(&oneof.oneof.oneof)
+                            });
+                            let name = oneof.oneof.field_name();
+                            if redact {
+                                w.write_line(format!(
+                                    ".field(\"{}\", &format_args!(\"<redacted>\"))",
+                                    name
+                                ));
+                            } else {
+                                w.write_line(format!(".field(\"{0}\", &self.{0})", name));
+                            }
+                        }
+                        w.write_line(".field(\"special_fields\", &self.special_fields)");
+                        w.write_line(".finish()");
+                    });
+                },
+            );
+        });
+    }
+
     fn write_impl_default_for_amp(&self, w: &mut CodeWriter) {
         w.impl_args_for_block(
             &["'a"],
@@ -643,6 +1089,332 @@ impl<'a> MessageGen<'a> {
         );
     }
 
+    fn write_impl_hash(&self, w: &mut CodeWriter) {
+        let protobuf_crate = protobuf_crate_path(&self.customize.for_elem);
+        w.impl_for_block("::std::hash::Hash", self.rust_name().to_string(), |w| {
+            w.def_fn("hash<H: ::std::hash::Hasher>(&self, state: &mut H)", |w| {
+                w.comment("special fields are not hashed");
+                for field in self.fields_except_oneof_and_group() {
+                    w.write_line(format!(
+                        "{}::rt::hash::ProtobufHash::protobuf_hash(&self.{}, state);",
+                        protobuf_crate, field.rust_name
+                    ));
+                }
+                for oneof in self.oneofs() {
+                    let variants = oneof.variants_except_group();
+                    w.match_block(format!("&self.{}", oneof.oneof.field_name()), |w| {
+                        w.case_expr("::std::option::Option::None", "{}");
+                        for variant in &variants {
+                            w.case_block(
+                                format!(
+                                    "::std::option::Option::Some({}(v))",
+                                    variant.path(&self.file_and_mod())
+                                ),
+                                |w| {
+                                    w.write_line(format!(
+                                        "::std::hash::Hash::hash(&{}u32, state);",
+                                        variant.field.proto_field.number()
+                                    ));
+                                    w.write_line(format!(
+                                        "{}::rt::hash::ProtobufHash::protobuf_hash(v, state);",
+                                        protobuf_crate
+                                    ));
+                                },
+                            );
+                        }
+                        if variants.is_empty() {
+                            w.case_expr("::std::option::Option::Some(_)", "{}");
+                        }
+                    });
+                }
+            });
+        });
+    }
+
+    fn write_impl_total_float_eq(&self, w: &mut CodeWriter) {
+        let protobuf_crate = protobuf_crate_path(&self.customize.for_elem);
+        w.impl_for_block("::std::cmp::PartialEq", self.rust_name().to_string(), |w| {
+            w.def_fn("eq(&self, other: &Self) -> bool", |w| {
+                w.comment("floats are compared by bit pattern");
+                for field in self.fields_except_oneof() {
+                    w.if_stmt(
+                        format!(
+                            "!{}::rt::total_float_eq::TotalFloatEq::total_float_eq(&self.{1}, &other.{1})",
+                            protobuf_crate, field.rust_name
+                        ),
+                        |w| w.write_line("return false;"),
+                    );
+                }
+                for oneof in self.oneofs() {
+                    let field_name = oneof.oneof.field_name();
+                    w.stmt_block(
+                        format!("let eq = match (&self.{0}, &other.{0})", field_name),
+                        |w| {
+                            for variant in oneof.variants_except_group() {
+                                let path = variant.path(&self.file_and_mod());
+                                w.case_expr(
+                                    format!(
+                                        "(::std::option::Option::Some({0}(a)), ::std::option::Option::Some({0}(b)))",
+                                        path
+                                    ),
+                                    format!(
+                                        "{}::rt::total_float_eq::TotalFloatEq::total_float_eq(a, b)",
+                                        protobuf_crate
+                                    ),
+                                );
+                            }
+                            w.case_expr("(a, b)", "a == b");
+                        },
+                    );
+                    w.if_stmt("!eq", |w| w.write_line("return false;"));
+                }
+                w.write_line("self.special_fields == other.special_fields");
+            });
+        });
+    }
+
+    fn write_impl_identity_eq_hash(&self, w: &mut CodeWriter) {
+        let protobuf_crate = protobuf_crate_path(&self.customize.for_elem);
+        let identity_fields = self.identity_fields();
+        w.impl_for_block("::std::cmp::PartialEq", self.rust_name().to_string(), |w| {
+            w.def_fn("eq(&self, other: &Self) -> bool", |w| {
+                w.comment("only identity fields are compared");
+                let eq: Vec<String> = identity_fields
+                    .iter()
+                    .map(|f| format!("self.{0} == other.{0}", f.rust_name))
+                    .collect();
+                w.write_line(eq.join(" && "));
+            });
+        });
+        w.write_line("");
+        w.impl_for_block("::std::cmp::Eq", self.rust_name().to_string(), |_w| {});
+        w.write_line("");
+        w.impl_for_block("::std::hash::Hash", self.rust_name().to_string(), |w| {
+            w.def_fn("hash<H: ::std::hash::Hasher>(&self, state: &mut H)", |w| {
+                w.comment("only identity fields are hashed");
+                for field in &identity_fields {
+                    w.write_line(format!(
+                        "{}::rt::hash::ProtobufHash::protobuf_hash(&self.{}, state);",
+                        protobuf_crate, field.rust_name
+                    ));
+                }
+            });
+        });
+    }
+
+    /// Names and types of struct fields included in tuple conversion,
+    /// in struct declaration order.
+    fn tuple_members(&self) -> Vec<(String, String)> {
+        let mut members: Vec<(String, String)> = self
+            .fields_except_oneof_and_group()
+            .into_iter()
+            .map(|f| {
+                let t = f
+                    .full_storage_type(&self.file_and_mod())
+                    .to_code(&self.customize.for_elem);
+                (f.rust_name.to_string(), t)
+            })
+            .collect();
+        for oneof in self.oneofs() {
+            members.push((
+                oneof.oneof.field_name().to_string(),
+                oneof.full_storage_type().to_code(&self.customize.for_elem),
+            ));
+        }
+        members
+    }
+
+    fn write_impl_tuple_conversion(&self, w: &mut CodeWriter) {
+        let members = self.tuple_members();
+        if members.is_empty() {
+            return;
+        }
+        w.write_line("");
+        if members.len() > TUPLE_CONVERSION_MAX_FIELDS {
+            w.comment(&format!(
+                "tuple conversion is not generated: message has more than {} fields",
+                TUPLE_CONVERSION_MAX_FIELDS
+            ));
+            return;
+        }
+        let types: Vec<&str> = members.iter().map(|(_, t)| t.as_str()).collect();
+        let tuple_type = if types.len() == 1 {
+            format!("({},)", types[0])
+        } else {
+            format!("({})", types.join(", "))
+        };
+        w.impl_for_block(
+            format!("::std::convert::From<{}>", self.rust_name()),
+            &tuple_type,
+            |w| {
+                w.def_fn(&format!("from(m: {}) -> Self", self.rust_name()), |w| {
+                    let values: Vec<String> =
+                        members.iter().map(|(n, _)| format!("m.{}", n)).collect();
+                    if values.len() == 1 {
+                        w.write_line(format!("({},)", values[0]));
+                    } else {
+                        w.write_line(format!("({})", values.join(", ")));
+                    }
+                });
+            },
+        );
+        w.write_line("");
+        w.impl_for_block(
+            format!("::std::convert::From<{}>", tuple_type),
+            self.rust_name().to_string(),
+            |w| {
+                w.def_fn(&format!("from(t: {}) -> Self", tuple_type), |w| {
+                    w.expr_block(&self.rust_name().to_string(), |w| {
+                        for (i, (n, _)) in members.iter().enumerate() {
+                            w.write_line(format!("{}: t.{},", n, i));
+                        }
+                        w.write_line("..::std::default::Default::default()");
+                    });
+                });
+            },
+        );
+    }
+
+    /// Fields of the `Copy` twin, `None` if some field is not `Copy`.
+    fn copy_twin_members(&self) -> Option<Vec<(String, String)>> {
+        if !self.oneofs().is_empty() {
+            return None;
+        }
+        self.fields
+            .iter()
+            .map(|f| match f.kind {
+                FieldKind::Singular(ref s)
+                    if s.elem.is_copy() && !matches!(s.elem, FieldElem::Group) =>
+                {
+                    let t = f
+                        .full_storage_type(&self.file_and_mod())
+                        .to_code(&self.customize.for_elem);
+                    Some((f.rust_name.to_string(), t))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn write_copy_twin(&self, w: &mut CodeWriter) {
+        let members = match self.copy_twin_members() {
+            Some(members) => members,
+            None => return,
+        };
+        let message = self.rust_name().to_string();
+        let twin = format!("{}Copy", message);
+        // Do not shadow a message or enum which is already named like the twin.
+        if self
+            .message
+            .scope
+            .messages_and_enums()
+            .iter()
+            .any(|m| m.rust_name().to_string() == twin)
+        {
+            return;
+        }
+        w.write_line("");
+        w.write_line(format!("/// Fields of [`{}`] in a `Copy` struct.", message));
+        w.write_line("///");
+        w.write_line("/// Unknown fields are dropped on conversion from the message.");
+        w.derive(&["Clone", "Copy", "PartialEq", "Default", "Debug"]);
+        w.pub_struct(&twin, |w| {
+            for (n, t) in &members {
+                w.pub_field_decl(n, t);
+            }
+        });
+        // Parameters are unused when message has no fields.
+        let (m, c) = if members.is_empty() {
+            ("_m", "_c")
+        } else {
+            ("m", "c")
+        };
+        w.write_line("");
+        w.impl_for_block(format!("::std::convert::From<&{}>", message), &twin, |w| {
+            w.def_fn(&format!("from({}: &{}) -> Self", m, message), |w| {
+                w.expr_block(&twin, |w| {
+                    for (n, _) in &members {
+                        w.write_line(format!("{}: m.{},", n, n));
+                    }
+                });
+            });
+        });
+        w.write_line("");
+        w.impl_for_block(format!("::std::convert::From<{}>", twin), &message, |w| {
+            w.def_fn(&format!("from({}: {}) -> Self", c, twin), |w| {
+                w.expr_block(&message, |w| {
+                    for (n, _) in &members {
+                        w.write_line(format!("{}: c.{},", n, n));
+                    }
+                    w.write_line("..::std::default::Default::default()");
+                });
+            });
+        });
+    }
+
+    fn write_impl_json_methods(&self, w: &mut CodeWriter) {
+        let result = "::std::result::Result<(), ::protobuf_json_mapping::ParseError>";
+        w.write_line("");
+        w.impl_self_block(self.rust_name().to_string(), |w| {
+            w.write_line("/// Merge JSON into this message.");
+            w.write_line("///");
+            w.write_line("/// Same as `protobuf_json_mapping::merge_from_str`, but does not use reflection");
+            w.write_line("/// for scalar, string and bytes fields.");
+            w.pub_fn(&format!("merge_from_json_str(&mut self, json: &str) -> {}", result), |w| {
+                w.write_line(
+                    "self.merge_from_json_str_with_options(json, &::std::default::Default::default())",
+                );
+            });
+            w.write_line("");
+            w.write_line("/// Merge JSON into this message with the provided options.");
+            w.pub_fn(
+                &format!(
+                    "merge_from_json_str_with_options(&mut self, json: &str, parse_options: &::protobuf_json_mapping::ParseOptions) -> {}",
+                    result
+                ),
+                |w| {
+                    let mut names = HashSet::new();
+                    let mut cases = Vec::new();
+                    for f in self.fields_except_oneof_and_group() {
+                        let stmt = match f.merge_from_json_stmt("m", "p") {
+                            Some(stmt) => stmt,
+                            None => continue,
+                        };
+                        // Both proto field name and JSON name are accepted.
+                        let mut keys = Vec::new();
+                        for name in [f.proto_field.name(), f.proto_field.json_name()] {
+                            if names.insert(name.to_owned()) {
+                                keys.push(format!("\"{}\"", name));
+                            }
+                        }
+                        if !keys.is_empty() {
+                            cases.push((keys.join(" | "), stmt));
+                        }
+                    }
+                    if cases.is_empty() {
+                        w.write_line("::protobuf_json_mapping::rt::merge_from_str_with_options(self, json, parse_options, |_, _, _| ::std::result::Result::Ok(false))");
+                        return;
+                    }
+                    w.block(
+                        "::protobuf_json_mapping::rt::merge_from_str_with_options(self, json, parse_options, |m, field_name, p| {",
+                        "})",
+                        |w| {
+                            w.match_block("field_name", |w| {
+                                for (keys, stmt) in &cases {
+                                    w.case_block(keys, |w| {
+                                        w.write_line(stmt);
+                                    });
+                                }
+                                w.case_expr("_", "return ::std::result::Result::Ok(false)");
+                            });
+                            w.write_line("::std::result::Result::Ok(true)");
+                        },
+                    );
+                },
+            );
+        });
+    }
+
     fn write_dummy_impl_partial_eq(&self, w: &mut CodeWriter) {
         w.impl_for_block(
             "::std::cmp::PartialEq",
@@ -657,15 +1429,65 @@ impl<'a> MessageGen<'a> {
     }
 
     pub fn write(&self, w: &mut CodeWriter) -> anyhow::Result<()> {
+        for field in self.identity_fields() {
+            if let FieldKind::Oneof(..) = field.kind {
+                return Err(MessageGenError::IdentityFieldInOneof(
+                    field.proto_field.field.full_name(),
+                )
+                .into());
+            }
+            // Floats would make `Eq` not reflexive, and referenced messages
+            // are not guaranteed to implement `Hash`.
+            let types = match field.proto_field.field.runtime_field_type() {
+                RuntimeFieldType::Singular(t) | RuntimeFieldType::Repeated(t) => vec![t],
+                RuntimeFieldType::Map(k, v) => vec![k, v],
+            };
+            for t in types {
+                match t {
+                    RuntimeType::F32 | RuntimeType::F64 => {
+                        return Err(MessageGenError::FloatIdentityField(
+                            field.proto_field.field.full_name(),
+                        )
+                        .into());
+                    }
+                    RuntimeType::Message(_) => {
+                        return Err(MessageGenError::MessageIdentityField(
+                            field.proto_field.field.full_name(),
+                        )
+                        .into());
+                    }
+                    _ => {}
+                }
+            }
+        }
+
         w.all_documentation(self.info, self.path);
         self.write_struct(w);
 
         w.write_line("");
         self.write_impl_default_for_amp(w);
 
-        if !self.supports_derive_partial_eq() {
+        if self.redact_debug() {
+            w.write_line("");
+            self.write_impl_debug(w);
+        }
+
+        if !self.identity_fields().is_empty() {
             w.write_line("");
-            self.write_dummy_impl_partial_eq(w);
+            self.write_impl_identity_eq_hash(w);
+        } else {
+            if self.total_float_eq() {
+                w.write_line("");
+                self.write_impl_total_float_eq(w);
+            } else if !self.supports_derive_partial_eq() {
+                w.write_line("");
+                self.write_dummy_impl_partial_eq(w);
+            }
+
+            if self.customize.for_elem.derive_hash.unwrap_or(false) {
+                w.write_line("");
+                self.write_impl_hash(w);
+            }
         }
 
         w.write_line("");
@@ -680,10 +1502,36 @@ impl<'a> MessageGen<'a> {
             w.write_line("");
             self.write_impl_display(w);
 
+            if self.customize.for_elem.fromstr_text_format.unwrap_or(false) {
+                w.write_line("");
+                self.write_impl_from_str(w);
+            }
+
             w.write_line("");
             self.write_impl_value(w);
         }
 
+        if self.customize.for_elem.typestate_builder.unwrap_or(false) {
+            self.write_typestate_builder(w);
+        }
+
+        if self.customize.for_elem.tuple_conversion.unwrap_or(false) {
+            self.write_impl_tuple_conversion(w);
+        }
+
+        if self
+            .customize
+            .for_elem
+            .derive_copy_where_possible
+            .unwrap_or(false)
+        {
+            self.write_copy_twin(w);
+        }
+
+        if self.customize.for_elem.gen_json_methods.unwrap_or(false) && !self.lite_runtime {
+            self.write_impl_json_methods(w);
+        }
+
         let mod_name = message_name_to_nested_mod_name(&self.message.message.name());
 
         let oneofs = self.oneofs();
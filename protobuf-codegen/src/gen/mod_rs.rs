@@ -1,14 +1,46 @@
+use std::collections::BTreeMap;
+
 use crate::compiler_plugin;
 use crate::gen::code_writer::CodeWriter;
 
-pub(crate) fn gen_mod_rs(mods: &[String]) -> compiler_plugin::GenResult {
+/// Generated file: directory, module name, optional `cfg` predicate.
+///
+/// Directory is the list of modules configured with
+/// [`Customize::module_path_for_package`](crate::Customize::module_path_for_package),
+/// empty for the output directory.
+pub(crate) type GenMod = (Vec<String>, String, Option<String>);
+
+/// Path of the file `name` in directory `dir` relative to the output directory.
+pub(crate) fn path_in_dir(dir: &[String], name: &str) -> String {
+    let mut path = String::new();
+    for d in dir {
+        path.push_str(d);
+        path.push('/');
+    }
+    path.push_str(name);
+    path
+}
+
+/// Write `#[cfg(..)]` attribute for module if configured
+/// with [`Customize::module_cfg`](crate::Customize::module_cfg).
+fn write_mod_cfg(w: &mut CodeWriter, cfg: &Option<String>) {
+    if let Some(cfg) = cfg {
+        w.write_line(format!("#[cfg({})]", cfg));
+    }
+}
+
+/// Generate `mod.rs` with `pub mod` statement for each module.
+///
+/// `mods` are pairs of module name and optional `cfg` predicate.
+pub(crate) fn gen_mod_rs(mods: &[(String, Option<String>)]) -> compiler_plugin::GenResult {
     let v = CodeWriter::with_no_error(|w| {
         w.comment(&format!("{}generated", "@"));
         w.write_line("");
-        let mut mods: Vec<&String> = mods.into_iter().collect();
+        let mut mods: Vec<&(String, Option<String>)> = mods.iter().collect();
         mods.sort();
-        for m in mods {
-            w.write_line(&format!("pub mod {};", m));
+        for (m, cfg) in mods {
+            write_mod_cfg(w, cfg);
+            w.write_line(format!("pub mod {};", m));
         }
     });
     compiler_plugin::GenResult {
@@ -16,3 +48,149 @@ pub(crate) fn gen_mod_rs(mods: &[String]) -> compiler_plugin::GenResult {
         content: v.into_bytes(),
     }
 }
+
+/// Generate `mod.rs` for each directory of generated files.
+///
+/// `mod.rs` of the parent directory declares subdirectories as modules.
+pub(crate) fn gen_mod_rs_tree(mods: &[GenMod]) -> Vec<compiler_plugin::GenResult> {
+    let mut dirs: BTreeMap<Vec<String>, Vec<(String, Option<String>)>> = BTreeMap::new();
+    dirs.entry(Vec::new()).or_default();
+    for (dir, m, cfg) in mods {
+        dirs.entry(dir.clone())
+            .or_default()
+            .push((m.clone(), cfg.clone()));
+        for i in 0..dir.len() {
+            let parent = dirs.entry(dir[..i].to_vec()).or_default();
+            let sub = (dir[i].clone(), None);
+            if !parent.contains(&sub) {
+                parent.push(sub);
+            }
+        }
+    }
+    dirs.into_iter()
+        .map(|(dir, mods)| {
+            let mut r = gen_mod_rs(&mods);
+            r.name = path_in_dir(&dir, &r.name);
+            r
+        })
+        .collect()
+}
+
+fn write_single_module_dir(w: &mut CodeWriter, files: &[&(GenMod, String)], depth: usize) {
+    for ((_, m, cfg), content) in files.iter().filter(|f| f.0 .0.len() == depth) {
+        w.write_line("");
+        write_mod_cfg(w, cfg);
+        w.pub_mod(m, |w| {
+            w.write_text(content);
+        });
+    }
+    let mut subdirs: Vec<&String> = files
+        .iter()
+        .filter(|f| f.0 .0.len() > depth)
+        .map(|f| &f.0 .0[depth])
+        .collect();
+    subdirs.dedup();
+    for d in subdirs {
+        let files: Vec<&(GenMod, String)> = files
+            .iter()
+            .filter(|f| f.0 .0.get(depth) == Some(d))
+            .copied()
+            .collect();
+        w.write_line("");
+        w.pub_mod(d, |w| write_single_module_dir(w, &files, depth + 1));
+    }
+}
+
+/// Generate all files as nested modules of single `mod.rs`.
+///
+/// `files` are pairs of generated module and its content,
+/// files in directories are nested into modules of these directories.
+pub(crate) fn gen_single_module(files: &[(GenMod, String)]) -> compiler_plugin::GenResult {
+    let v = CodeWriter::with_no_error(|w| {
+        w.comment(&format!("{}generated", "@"));
+        let mut files: Vec<&(GenMod, String)> = files.iter().collect();
+        files.sort_by(|a, b| (&a.0 .0, &a.0 .1).cmp(&(&b.0 .0, &b.0 .1)));
+        write_single_module_dir(w, &files, 0);
+    });
+    compiler_plugin::GenResult {
+        name: "mod.rs".to_owned(),
+        content: v.into_bytes(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::gen_mod_rs;
+    use super::gen_mod_rs_tree;
+    use super::gen_single_module;
+
+    #[test]
+    fn test_mod_cfg() {
+        let r = gen_mod_rs(&[
+            ("foo".to_owned(), Some("feature = \"foo\"".to_owned())),
+            ("bar".to_owned(), None),
+        ]);
+        assert_eq!(
+            "// @generated\n\npub mod bar;\n#[cfg(feature = \"foo\")]\npub mod foo;\n",
+            String::from_utf8(r.content).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_mod_rs_tree() {
+        let dir = |d: &[&str]| d.iter().map(|d| d.to_string()).collect::<Vec<_>>();
+        let r = gen_mod_rs_tree(&[
+            (dir(&["proto", "v1"]), "foo".to_owned(), None),
+            (dir(&["proto", "v2"]), "bar".to_owned(), None),
+            (dir(&[]), "baz".to_owned(), None),
+        ]);
+        let r: Vec<(String, String)> = r
+            .into_iter()
+            .map(|r| (r.name, String::from_utf8(r.content).unwrap()))
+            .collect();
+        assert_eq!(
+            vec![
+                (
+                    "mod.rs".to_owned(),
+                    "// @generated\n\npub mod baz;\npub mod proto;\n".to_owned()
+                ),
+                (
+                    "proto/mod.rs".to_owned(),
+                    "// @generated\n\npub mod v1;\npub mod v2;\n".to_owned()
+                ),
+                (
+                    "proto/v1/mod.rs".to_owned(),
+                    "// @generated\n\npub mod foo;\n".to_owned()
+                ),
+                (
+                    "proto/v2/mod.rs".to_owned(),
+                    "// @generated\n\npub mod bar;\n".to_owned()
+                ),
+            ],
+            r
+        );
+    }
+
+    #[test]
+    fn test_single_module_dirs() {
+        let r = gen_single_module(&[
+            (
+                (vec!["proto".to_owned()], "foo".to_owned(), None),
+                "struct Foo;".to_owned(),
+            ),
+            (
+                (Vec::new(), "bar".to_owned(), None),
+                "struct Bar;".to_owned(),
+            ),
+        ]);
+        assert_eq!(
+            "// @generated\n\
+             \n\
+             pub mod bar {\n    struct Bar;\n}\n\
+             \n\
+             pub mod proto {\n\
+             \n    pub mod foo {\n        struct Foo;\n    }\n}\n",
+            String::from_utf8(r.content).unwrap()
+        );
+    }
+}
@@ -250,7 +250,10 @@ impl<'a> OneofGen<'a> {
     }
 
     fn write_enum(&self, w: &mut CodeWriter) {
-        let derive = vec!["Clone", "PartialEq", "Debug"];
+        let mut derive = vec!["Clone", "PartialEq", "Debug"];
+        if self.message.derive_eq() {
+            derive.push("Eq");
+        }
         w.derive(&derive);
         w.write_line("#[non_exhaustive]");
         write_protoc_insertion_point_for_oneof(w, &self.customize.for_elem, &self.oneof.oneof);
@@ -261,6 +264,17 @@ impl<'a> OneofGen<'a> {
                     &self.customize.for_children,
                     &variant.field.proto_field.field,
                 );
+                if self
+                    .customize
+                    .for_elem
+                    .serde_flatten_oneofs
+                    .unwrap_or(false)
+                {
+                    w.write_line(format!(
+                        "#[serde(rename = \"{}\")]",
+                        variant.field.proto_field.name()
+                    ));
+                }
                 w.write_line(&format!(
                     "{}({}),",
                     variant.oneof_field.oneof_variant_rust_name,
@@ -359,10 +373,62 @@ impl<'a> OneofGen<'a> {
         });
     }
 
+    fn write_impl_from_members(&self, w: &mut CodeWriter) {
+        let file_and_mod = self.file_and_mod();
+        let variants: Vec<(OneofVariantGen, String)> = self
+            .variants_except_group()
+            .into_iter()
+            .map(|v| {
+                let t = v
+                    .oneof_field
+                    .elem
+                    .rust_storage_elem_type(&file_and_mod)
+                    .to_code(&self.customize.for_elem);
+                (v, t)
+            })
+            .collect();
+        let mut shared = HashSet::new();
+        for (variant, t) in &variants {
+            if variants.iter().filter(|(_, t2)| t2 == t).count() != 1 {
+                if shared.insert(t) {
+                    w.write_line("");
+                    w.comment(&format!(
+                        "`From<{}>` is not generated: type is shared by several oneof members",
+                        t
+                    ));
+                }
+                continue;
+            }
+            let value = if variant.oneof_field.boxed {
+                "::std::boxed::Box::new(v)"
+            } else {
+                "v"
+            };
+            w.write_line("");
+            w.impl_for_block(
+                format!("::std::convert::From<{}>", t),
+                self.oneof.rust_name().ident.to_string(),
+                |w| {
+                    w.def_fn(&format!("from(v: {}) -> Self", t), |w| {
+                        w.write_line(format!(
+                            "{}::{}({})",
+                            self.oneof.rust_name().ident,
+                            variant.oneof_field.oneof_variant_rust_name,
+                            value
+                        ));
+                    });
+                },
+            );
+        }
+    }
+
     pub fn write(&self, w: &mut CodeWriter) {
         self.write_enum(w);
         w.write_line("");
         self.write_impl_oneof(w);
+        if self.customize.for_elem.oneof_from_members.unwrap_or(false) {
+            self.write_impl_from_members(w);
+        }
         if !self.lite_runtime {
             w.write_line("");
             self.write_impl_oneof_full(w);
@@ -1,6 +1,8 @@
 use crate::gen::inside::protobuf_crate_path;
+use crate::gen::rust::component::RustPathComponent;
 use crate::gen::rust::ident::RustIdent;
 use crate::gen::rust::path::RustPath;
+use crate::gen::rust::rel_path::RustRelativePath;
 use crate::gen::strx;
 use crate::gen::well_known_types::WELL_KNOWN_TYPES_PROTO_FILE_FULL_NAMES;
 use crate::Customize;
@@ -39,6 +41,69 @@ pub(crate) fn proto_path_to_rust_mod(path: &str) -> RustIdent {
     RustIdent::new(&name)
 }
 
+#[derive(Debug, thiserror::Error)]
+enum PathsError {
+    #[error("module path `{1}` for package `{0}` must start with `crate` to generate files of the package")]
+    ModulePathNotInCrate(String, String),
+}
+
+/// Modules (relative to the crate root) where files of the package are generated,
+/// if the package is configured with
+/// [`module_path_for_package`](Customize::module_path_for_package).
+pub(crate) fn package_mods(
+    package: &str,
+    customize: &Customize,
+) -> anyhow::Result<Option<Vec<String>>> {
+    let module_path = match customize.module_path_for_package_get(package) {
+        Some(module_path) => module_path,
+        None => return Ok(None),
+    };
+    let mut components = module_path.split("::").map(|c| c.trim().to_owned());
+    if components.next().as_deref() != Some("crate") {
+        return Err(
+            PathsError::ModulePathNotInCrate(package.to_owned(), module_path.to_owned()).into(),
+        );
+    }
+    Ok(Some(components.collect()))
+}
+
+/// Path to the module where files of `package` are generated,
+/// relative to module `relative_mod` inside of a file of `current_package`.
+///
+/// Module paths starting with `crate` are relative to the module
+/// which includes top-level generated `mod.rs`, so generated code works
+/// wherever that `mod.rs` is included. Other module paths are used as is.
+pub(crate) fn package_module_path(
+    package: &str,
+    current_package: &str,
+    relative_mod: &RustRelativePath,
+    customize: &Customize,
+) -> RustPath {
+    let dest = match package_mods(package, customize) {
+        Ok(dest) => dest.unwrap_or_default(),
+        Err(_) => {
+            return RustPath::from(customize.module_path_for_package_get(package).unwrap());
+        }
+    };
+    // Module path of the current file is checked when the file is generated.
+    let source = package_mods(current_package, customize)
+        .ok()
+        .flatten()
+        .unwrap_or_default();
+    let common = source.iter().zip(&dest).take_while(|(s, d)| s == d).count();
+    let mut path = relative_mod
+        .to_reverse()
+        .into_path()
+        .append(RustPath::super_path());
+    for _ in common..source.len() {
+        path = path.append_component(RustPathComponent::SUPER);
+    }
+    for d in &dest[common..] {
+        path = path.append_ident(RustIdent::new(d));
+    }
+    path
+}
+
 /// Used in protobuf-codegen-identical-test
 pub fn proto_name_to_rs(proto_file_path: &str) -> String {
     format!("{}.rs", proto_path_to_rust_mod(proto_file_path))
@@ -46,6 +111,8 @@ pub fn proto_name_to_rs(proto_file_path: &str) -> String {
 
 pub(crate) fn proto_path_to_fn_file_descriptor(
     proto_path: &str,
+    package: &str,
+    current_package: &str,
     customize: &Customize,
 ) -> RustPath {
     let protobuf_crate = protobuf_crate_path(customize);
@@ -58,16 +125,24 @@ pub(crate) fn proto_path_to_fn_file_descriptor(
             .append_ident("well_known_types".into())
             .append_ident(proto_path_to_rust_mod(s))
             .append_ident("file_descriptor".into()),
-        s => RustPath::super_path()
-            .append_ident(proto_path_to_rust_mod(s))
-            .append_ident("file_descriptor".into()),
+        s => package_module_path(
+            package,
+            current_package,
+            &RustRelativePath::default(),
+            customize,
+        )
+        .append_ident(proto_path_to_rust_mod(s))
+        .append_ident("file_descriptor".into()),
     }
 }
 
 #[cfg(test)]
 mod test {
+    use super::package_module_path;
     use super::proto_path_to_rust_mod;
     use crate::gen::rust::ident::RustIdent;
+    use crate::gen::rust::rel_path::RustRelativePath;
+    use crate::Customize;
 
     #[test]
     fn test_mod_path_proto_ext() {
@@ -106,4 +181,26 @@ mod test {
             proto_path_to_rust_mod("foo\\bar\\baz.proto"),
         )
     }
+
+    #[test]
+    fn test_package_module_path() {
+        let customize = Customize::default()
+            .module_path_for_package("a", "crate::gen::a")
+            .module_path_for_package("b", "crate::gen::b")
+            .module_path_for_package("e", "::ext::e");
+        let path = |package, current_package, relative_mod: &[&str]| {
+            package_module_path(
+                package,
+                current_package,
+                &RustRelativePath::from_idents(relative_mod.iter().map(|m| RustIdent::from(*m))),
+                &customize,
+            )
+            .to_string()
+        };
+        assert_eq!("super", path("c", "d", &[]));
+        assert_eq!("super::super::super", path("c", "a", &[]));
+        assert_eq!("super::super::b", path("b", "a", &[]));
+        assert_eq!("super::super::gen::a", path("a", "c", &["m"]));
+        assert_eq!("::ext::e", path("e", "a", &[]));
+    }
 }
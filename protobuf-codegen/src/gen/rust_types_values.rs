@@ -11,8 +11,8 @@ use crate::gen::field::type_ext::TypeExt;
 use crate::gen::file_and_mod::FileAndMod;
 use crate::gen::inside::protobuf_crate_path;
 use crate::gen::message::RustTypeMessage;
+use crate::gen::paths::package_module_path;
 use crate::gen::paths::proto_path_to_rust_mod;
-use crate::gen::rust::component::RustPathComponent;
 use crate::gen::rust::ident::RustIdent;
 use crate::gen::rust::ident_with_path::RustIdentWithPath;
 use crate::gen::rust::path::RustPath;
@@ -34,6 +34,7 @@ pub(crate) enum RustType {
     Bool,
     Vec(Box<RustType>),
     HashMap(Box<RustType>, Box<RustType>),
+    BTreeMap(Box<RustType>, Box<RustType>),
     String,
     // [T], not &[T]
     Slice(Box<RustType>),
@@ -75,6 +76,11 @@ impl RustType {
                 key.to_code(customize),
                 value.to_code(customize)
             ),
+            RustType::BTreeMap(ref key, ref value) => format!(
+                "::std::collections::BTreeMap<{}, {}>",
+                key.to_code(customize),
+                value.to_code(customize)
+            ),
             RustType::String => format!("::std::string::String"),
             RustType::Slice(ref param) => format!("[{}]", param.to_code(customize)),
             RustType::Str => format!("str"),
@@ -221,6 +227,7 @@ impl RustType {
             RustType::Bool => "false".to_string(),
             RustType::Vec(..) => EXPR_VEC_NEW.to_string(),
             RustType::HashMap(..) => "::std::collections::HashMap::new()".to_string(),
+            RustType::BTreeMap(..) => "::std::collections::BTreeMap::new()".to_string(),
             RustType::String => "::std::string::String::new()".to_string(),
             RustType::Bytes => "::bytes::Bytes::new()".to_string(),
             RustType::Chars => format!("{}::Chars::new()", protobuf_crate_path(customize)),
@@ -266,7 +273,8 @@ impl RustType {
             | RustType::Chars
             | RustType::String
             | RustType::MessageField(..)
-            | RustType::HashMap(..) => format!("{}.clear()", v),
+            | RustType::HashMap(..)
+            | RustType::BTreeMap(..) => format!("{}.clear()", v),
             RustType::Bool
             | RustType::Float(..)
             | RustType::Int(..)
@@ -513,12 +521,14 @@ pub(crate) fn message_or_enum_to_rust_relative(
             message_or_enum.rust_name_to_file()
         ))
     } else {
-        current
-            .relative_mod
-            .to_reverse()
-            .into_path()
-            .append_component(RustPathComponent::SUPER)
-            .append_with_ident(message_or_enum.rust_name_with_file())
+        // Either package may be generated into explicitly configured module.
+        package_module_path(
+            message_or_enum.file_descriptor().package(),
+            &current.package,
+            &current.relative_mod,
+            &current.customize,
+        )
+        .append_with_ident(message_or_enum.rust_name_with_file())
     }
 }
 
@@ -279,6 +279,7 @@ impl<'a> Scope<'a> {
     pub fn file_and_mod(&self, customize: Customize) -> FileAndMod {
         FileAndMod {
             file: self.file_scope.file_descriptor.proto().name().to_owned(),
+            package: self.file_scope.file_descriptor.package().to_owned(),
             relative_mod: self.rust_path_to_file(),
             customize,
         }
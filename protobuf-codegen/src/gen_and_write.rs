@@ -54,6 +54,11 @@ pub fn gen_and_write(
     for r in &results {
         let mut file_path = out_dir.to_owned();
         file_path.push(&r.name);
+        // Files of packages with configured module path are generated into subdirectories.
+        if let Some(dir) = file_path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|e| Error::FailedToWriteFile(file_path.display().to_string(), e))?;
+        }
         fs::write(&file_path, r.content.as_slice())
             .map_err(|e| Error::FailedToWriteFile(file_path.display().to_string(), e))?;
     }
@@ -30,6 +30,12 @@
 //! mostly complex message options specified in `.proto` files.
 //! I never saw anyone using them, but you have been warned.
 //!
+//! Pure rust parser also accepts `edition = "2023"` files: they are lowered to proto3,
+//! with `features.field_presence` mapped to field labels
+//! and `features.repeated_field_encoding` to `packed` option.
+//! Closed enums, `LEGACY_REQUIRED` field presence, delimited message encoding,
+//! disabled UTF-8 validation and field `default` values are not supported.
+//!
 //! Note `protoc` command can be obtained from
 //! [`protoc-bin-vendored`](https://docs.rs/protoc-bin-vendored) crate.
 //!
@@ -138,6 +144,7 @@ mod gen;
 pub mod gen_and_write;
 pub mod protoc_gen_rust;
 
+pub use codegen::convert::ConvertCodegen;
 pub use codegen::Codegen;
 pub use customize::Customize;
 pub use customize::CustomizeCallback;
@@ -1,11 +1,11 @@
 //! JSON printer and parser which tries to follow
 //! [protobuf conventions](https://developers.google.com/protocol-buffers/docs/proto3#json).
 
-mod base64;
 mod float;
 mod parse;
 mod print;
 mod rfc_3339;
+pub mod rt;
 mod well_known_wrapper;
 
 pub use self::parse::merge_from_str;
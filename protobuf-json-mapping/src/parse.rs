@@ -29,6 +29,8 @@ use protobuf::well_known_types::wrappers::UInt64Value;
 use protobuf::Enum;
 use protobuf::MessageDyn;
 use protobuf::MessageFull;
+use protobuf_support::base64;
+use protobuf_support::base64::FromBase64Error;
 use protobuf_support::lexer::json_number_lit::JsonNumberLit;
 use protobuf_support::lexer::lexer_impl::Lexer;
 use protobuf_support::lexer::lexer_impl::LexerError;
@@ -38,10 +40,8 @@ use protobuf_support::lexer::token::Token;
 use protobuf_support::lexer::tokenizer::Tokenizer;
 use protobuf_support::lexer::tokenizer::TokenizerError;
 
-use super::base64;
 use super::float;
 use super::rfc_3339;
-use crate::base64::FromBase64Error;
 use crate::well_known_wrapper::WellKnownWrapper;
 
 #[derive(Debug, thiserror::Error)]
@@ -81,7 +81,7 @@ enum ParseErrorWithoutLocInner {
 /// JSON parse error.
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
-struct ParseErrorWithoutLoc(ParseErrorWithoutLocInner);
+pub(crate) struct ParseErrorWithoutLoc(ParseErrorWithoutLocInner);
 
 impl From<TokenizerError> for ParseErrorWithoutLoc {
     fn from(e: TokenizerError) -> Self {
@@ -121,11 +121,11 @@ pub struct ParseError {
     loc: Loc,
 }
 
-type ParseResultWithoutLoc<A> = Result<A, ParseErrorWithoutLoc>;
+pub(crate) type ParseResultWithoutLoc<A> = Result<A, ParseErrorWithoutLoc>;
 type ParseResult<A> = Result<A, ParseError>;
 
 #[derive(Clone)]
-struct Parser<'a> {
+pub(crate) struct Parser<'a> {
     tokenizer: Tokenizer<'a>,
     parse_options: ParseOptions,
 }
@@ -237,7 +237,14 @@ impl FromJsonNumber for f64 {
 }
 
 impl<'a> Parser<'a> {
-    fn read_bool(&mut self) -> ParseResultWithoutLoc<bool> {
+    pub(crate) fn new(json: &'a str, parse_options: &ParseOptions) -> Parser<'a> {
+        Parser {
+            tokenizer: Tokenizer::new(json, ParserLanguage::Json),
+            parse_options: parse_options.clone(),
+        }
+    }
+
+    pub(crate) fn read_bool(&mut self) -> ParseResultWithoutLoc<bool> {
         if self.tokenizer.next_ident_if_eq("true")? {
             Ok(true)
         } else if self.tokenizer.next_ident_if_eq("false")? {
@@ -309,31 +316,31 @@ impl<'a> Parser<'a> {
         Ok(())
     }
 
-    fn read_u32(&mut self) -> ParseResultWithoutLoc<u32> {
+    pub(crate) fn read_u32(&mut self) -> ParseResultWithoutLoc<u32> {
         self.read_number()
     }
 
-    fn read_u64(&mut self) -> ParseResultWithoutLoc<u64> {
+    pub(crate) fn read_u64(&mut self) -> ParseResultWithoutLoc<u64> {
         self.read_number()
     }
 
-    fn read_i32(&mut self) -> ParseResultWithoutLoc<i32> {
+    pub(crate) fn read_i32(&mut self) -> ParseResultWithoutLoc<i32> {
         self.read_number()
     }
 
-    fn read_i64(&mut self) -> ParseResultWithoutLoc<i64> {
+    pub(crate) fn read_i64(&mut self) -> ParseResultWithoutLoc<i64> {
         self.read_number()
     }
 
-    fn read_f32(&mut self) -> ParseResultWithoutLoc<f32> {
+    pub(crate) fn read_f32(&mut self) -> ParseResultWithoutLoc<f32> {
         self.read_number()
     }
 
-    fn read_f64(&mut self) -> ParseResultWithoutLoc<f64> {
+    pub(crate) fn read_f64(&mut self) -> ParseResultWithoutLoc<f64> {
         self.read_number()
     }
 
-    fn read_string(&mut self) -> ParseResultWithoutLoc<String> {
+    pub(crate) fn read_string(&mut self) -> ParseResultWithoutLoc<String> {
         let str_lit = self.tokenizer.next_str_lit()?;
 
         let mut lexer = Lexer::new(&str_lit.escaped, ParserLanguage::Json);
@@ -349,7 +356,7 @@ impl<'a> Parser<'a> {
         Ok(r)
     }
 
-    fn read_bytes(&mut self) -> ParseResultWithoutLoc<Vec<u8>> {
+    pub(crate) fn read_bytes(&mut self) -> ParseResultWithoutLoc<Vec<u8>> {
         let s = self.read_string()?;
         self.parse_bytes(&s)
     }
@@ -430,7 +437,7 @@ impl<'a> Parser<'a> {
         Ok(())
     }
 
-    fn read_list<C>(&mut self, mut read_item: C) -> ParseResultWithoutLoc<()>
+    pub(crate) fn read_list<C>(&mut self, mut read_item: C) -> ParseResultWithoutLoc<()>
     where
         C: for<'b> FnMut(&'b mut Self) -> ParseResultWithoutLoc<()>,
     {
@@ -579,7 +586,7 @@ impl<'a> Parser<'a> {
         Ok(())
     }
 
-    fn merge_field(
+    pub(crate) fn merge_field(
         &mut self,
         message: &mut dyn MessageDyn,
         field: &FieldDescriptor,
@@ -658,6 +665,30 @@ impl<'a> Parser<'a> {
 
         let descriptor = message.descriptor_dyn();
 
+        self.merge_object(message, |s, message, field_name| {
+            // Proto3 JSON parsers are required to accept both
+            // the converted `lowerCamelCase` name and the proto field name.
+            match descriptor.field_by_name_or_json_name(field_name) {
+                Some(field) => {
+                    s.merge_field(message, &field)?;
+                    Ok(true)
+                }
+                None => Ok(false),
+            }
+        })
+    }
+
+    /// Read JSON object, `merge_field` is called after field name and colon are read
+    /// and returns `false` if field name is unknown.
+    pub(crate) fn merge_object<M, F>(
+        &mut self,
+        message: &mut M,
+        mut merge_field: F,
+    ) -> ParseResultWithoutLoc<()>
+    where
+        M: ?Sized,
+        F: FnMut(&mut Self, &mut M, &str) -> ParseResultWithoutLoc<bool>,
+    {
         self.tokenizer.next_symbol_expect_eq('{', "object")?;
         let mut first = true;
         while !self.tokenizer.next_symbol_if_eq('}')? {
@@ -667,23 +698,17 @@ impl<'a> Parser<'a> {
             first = false;
 
             let field_name = self.read_string()?;
-            // Proto3 JSON parsers are required to accept both
-            // the converted `lowerCamelCase` name and the proto field name.
-            match descriptor.field_by_name_or_json_name(&field_name) {
-                Some(field) => {
-                    self.tokenizer.next_symbol_expect_eq(':', "object")?;
-                    self.merge_field(message, &field)?;
-                }
-                None if self.parse_options.ignore_unknown_fields => {
-                    self.tokenizer.next_symbol_expect_eq(':', "object")?;
-                    self.skip_json_value()?;
-                }
-                None => {
-                    return Err(ParseErrorWithoutLoc(
-                        ParseErrorWithoutLocInner::UnknownFieldName(field_name),
-                    ))
-                }
-            };
+            self.tokenizer.next_symbol_expect_eq(':', "object")?;
+            if merge_field(self, message, &field_name)? {
+                continue;
+            }
+            if self.parse_options.ignore_unknown_fields {
+                self.skip_json_value()?;
+            } else {
+                return Err(ParseErrorWithoutLoc(
+                    ParseErrorWithoutLocInner::UnknownFieldName(field_name),
+                ));
+            }
         }
         Ok(())
     }
@@ -828,8 +853,16 @@ impl<'a> Parser<'a> {
     }
 
     fn merge(&mut self, message: &mut dyn MessageDyn) -> ParseResult<()> {
-        match self.merge_inner(message) {
-            Ok(()) => Ok(()),
+        self.with_loc(|s| s.merge_inner(message))
+    }
+
+    /// Attach current location to the error.
+    pub(crate) fn with_loc<A>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> ParseResultWithoutLoc<A>,
+    ) -> ParseResult<A> {
+        match f(self) {
+            Ok(a) => Ok(a),
             Err(error) => Err(ParseError {
                 error,
                 loc: self.tokenizer.loc(),
@@ -865,10 +898,7 @@ pub fn merge_from_str_with_options(
     json: &str,
     parse_options: &ParseOptions,
 ) -> ParseResult<()> {
-    let mut parser = Parser {
-        tokenizer: Tokenizer::new(json, ParserLanguage::Json),
-        parse_options: parse_options.clone(),
-    };
+    let mut parser = Parser::new(json, parse_options);
     parser.merge(message)
 }
 
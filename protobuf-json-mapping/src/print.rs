@@ -29,8 +29,8 @@ use protobuf::well_known_types::wrappers::StringValue;
 use protobuf::well_known_types::wrappers::UInt32Value;
 use protobuf::well_known_types::wrappers::UInt64Value;
 use protobuf::MessageDyn;
+use protobuf_support::base64;
 
-use crate::base64;
 use crate::float;
 use crate::rfc_3339::TmUtc;
 use crate::well_known_wrapper::WellKnownWrapper;
@@ -0,0 +1,120 @@
+//! # Functions and types used by code generated with `gen_json_methods` option
+//!
+//! These are not considered to be public API of protobuf-json-mapping,
+//! so they can be changed any time.
+
+use protobuf::MessageFull;
+
+use crate::parse::ParseErrorWithoutLoc;
+use crate::parse::Parser;
+use crate::ParseError;
+use crate::ParseOptions;
+
+/// Error returned by [`FieldParser`] operations.
+///
+/// Location is attached to the error when it is converted to [`ParseError`].
+pub struct FieldParseError(ParseErrorWithoutLoc);
+
+impl From<ParseErrorWithoutLoc> for FieldParseError {
+    fn from(e: ParseErrorWithoutLoc) -> Self {
+        FieldParseError(e)
+    }
+}
+
+/// Reader of a field value passed to generated code.
+pub struct FieldParser<'p, 'a> {
+    parser: &'p mut Parser<'a>,
+}
+
+impl<'p, 'a> FieldParser<'p, 'a> {
+    /// Read JSON value as `bool`.
+    pub fn read_bool(&mut self) -> Result<bool, FieldParseError> {
+        Ok(self.parser.read_bool()?)
+    }
+
+    /// Read JSON value as `int32`, `sint32` or `sfixed32`.
+    pub fn read_i32(&mut self) -> Result<i32, FieldParseError> {
+        Ok(self.parser.read_i32()?)
+    }
+
+    /// Read JSON value as `int64`, `sint64` or `sfixed64`.
+    pub fn read_i64(&mut self) -> Result<i64, FieldParseError> {
+        Ok(self.parser.read_i64()?)
+    }
+
+    /// Read JSON value as `uint32` or `fixed32`.
+    pub fn read_u32(&mut self) -> Result<u32, FieldParseError> {
+        Ok(self.parser.read_u32()?)
+    }
+
+    /// Read JSON value as `uint64` or `fixed64`.
+    pub fn read_u64(&mut self) -> Result<u64, FieldParseError> {
+        Ok(self.parser.read_u64()?)
+    }
+
+    /// Read JSON value as `float`.
+    pub fn read_f32(&mut self) -> Result<f32, FieldParseError> {
+        Ok(self.parser.read_f32()?)
+    }
+
+    /// Read JSON value as `double`.
+    pub fn read_f64(&mut self) -> Result<f64, FieldParseError> {
+        Ok(self.parser.read_f64()?)
+    }
+
+    /// Read JSON value as `string`.
+    pub fn read_string(&mut self) -> Result<String, FieldParseError> {
+        Ok(self.parser.read_string()?)
+    }
+
+    /// Read JSON value as base64-encoded `bytes`.
+    pub fn read_bytes(&mut self) -> Result<Vec<u8>, FieldParseError> {
+        Ok(self.parser.read_bytes()?)
+    }
+
+    /// Read JSON list (or `null`) replacing the content of a repeated field.
+    pub fn read_repeated<T>(
+        &mut self,
+        vec: &mut Vec<T>,
+        mut read_item: impl FnMut(&mut FieldParser<'_, 'a>) -> Result<T, FieldParseError>,
+    ) -> Result<(), FieldParseError> {
+        vec.clear();
+        Ok(self.parser.read_list(|parser| {
+            let item = read_item(&mut FieldParser { parser }).map_err(|e| e.0)?;
+            vec.push(item);
+            Ok(())
+        })?)
+    }
+}
+
+/// Merge JSON object into a message.
+///
+/// `merge_field` is called for each field name found in JSON,
+/// and returns `false` if it does not handle that field,
+/// in that case the field is merged with reflection.
+pub fn merge_from_str_with_options<M, F>(
+    message: &mut M,
+    json: &str,
+    parse_options: &ParseOptions,
+    mut merge_field: F,
+) -> Result<(), ParseError>
+where
+    M: MessageFull,
+    F: FnMut(&mut M, &str, &mut FieldParser) -> Result<bool, FieldParseError>,
+{
+    let mut parser = Parser::new(json, parse_options);
+    parser.with_loc(|parser| {
+        parser.merge_object(message, |parser, message, field_name| {
+            if merge_field(message, field_name, &mut FieldParser { parser }).map_err(|e| e.0)? {
+                return Ok(true);
+            }
+            match M::descriptor().field_by_name_or_json_name(field_name) {
+                Some(field) => {
+                    parser.merge_field(message, &field)?;
+                    Ok(true)
+                }
+                None => Ok(false),
+            }
+        })
+    })
+}
@@ -341,6 +341,12 @@ impl<'a> Resolver<'a> {
                 .full_name
                 .to_string(),
         );
+        if input.client_streaming {
+            output.set_client_streaming(true);
+        }
+        if input.server_streaming {
+            output.set_server_streaming(true);
+        }
         Ok(output)
     }
 
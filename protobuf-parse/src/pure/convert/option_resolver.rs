@@ -1,4 +1,5 @@
 use anyhow::Context;
+use protobuf::descriptor::field_descriptor_proto::Type;
 use protobuf::descriptor::DescriptorProto;
 use protobuf::descriptor::EnumDescriptorProto;
 use protobuf::descriptor::EnumValueDescriptorProto;
@@ -771,7 +772,17 @@ impl<'a> OptionResoler<'a> {
         scope: &ProtobufAbsPathRef,
         input: &[model::ProtobufOption],
     ) -> anyhow::Result<Option<protobuf::descriptor::FieldOptions>> {
-        self.custom_options(scope, input)
+        // `default` and `json_name` are stored in `FieldDescriptorProto`,
+        // so like in `protoc` they alone do not produce `FieldOptions`.
+        let input: Vec<model::ProtobufOption> = input
+            .iter()
+            .filter(|o| match &o.name {
+                ProtobufOptionName::Builtin(n) => n.get() != "default" && n.get() != "json_name",
+                ProtobufOptionName::Ext(..) => true,
+            })
+            .cloned()
+            .collect();
+        self.custom_options(scope, &input)
     }
 
     fn message_options(
@@ -881,6 +892,14 @@ impl<'a> OptionResoler<'a> {
         field_model: &model::Field,
     ) -> anyhow::Result<()> {
         field_proto.options = self.field_options(scope, &field_model.options)?.into();
+        if field_model.packed_by_features
+            && !matches!(
+                field_proto.type_(),
+                Type::TYPE_STRING | Type::TYPE_BYTES | Type::TYPE_MESSAGE | Type::TYPE_GROUP
+            )
+        {
+            field_proto.options.mut_or_insert_default().set_packed(true);
+        }
         Ok(())
     }
 
@@ -0,0 +1,394 @@
+//! Support for `edition = "2023"` files.
+//!
+//! Bundled `descriptor.proto` has no editions support, so edition files are lowered
+//! to proto3 model before conversion: resolved `features.*` options are removed,
+//! field presence is expressed with labels (explicit presence is proto3 `optional`)
+//! and repeated field encoding with `packed` option.
+
+use crate::protobuf_path::ProtobufPath;
+use crate::pure::model;
+use crate::pure::model::FieldOrOneOf;
+use crate::pure::model::FieldType;
+use crate::pure::model::ProtobufConstant;
+use crate::pure::model::ProtobufOption;
+use crate::pure::model::ProtobufOptionName;
+use crate::pure::model::ProtobufOptionNamePart;
+use crate::pure::model::Rule;
+
+#[derive(Debug, thiserror::Error)]
+enum EditionsError {
+    #[error("unsupported edition: `{0}`, only edition `2023` is supported")]
+    UnsupportedEdition(String),
+    #[error("unknown feature: `{0}`")]
+    UnknownFeature(String),
+    #[error("feature `{0}` value must be an identifier, got `{1}`")]
+    FeatureValueNotIdent(String, String),
+    #[error("unknown value of feature `{0}`: `{1}`")]
+    UnknownFeatureValue(String, String),
+    #[error("feature `{0}` value `{1}` is not supported")]
+    UnsupportedFeatureValue(String, String),
+    #[error("label `{0}` is not allowed in editions, use `features.field_presence` instead")]
+    LabelNotAllowed(&'static str),
+    #[error("option `packed` is not allowed in editions, use `features.repeated_field_encoding` instead")]
+    PackedNotAllowed,
+    #[error("option `default` is not supported in editions")]
+    DefaultNotSupported,
+}
+
+pub(crate) const SUPPORTED_EDITIONS: &[&str] = &["2023"];
+
+pub(crate) fn check_edition(edition: &str) -> anyhow::Result<()> {
+    if SUPPORTED_EDITIONS.contains(&edition) {
+        Ok(())
+    } else {
+        Err(EditionsError::UnsupportedEdition(edition.to_owned()).into())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FieldPresence {
+    Explicit,
+    Implicit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RepeatedFieldEncoding {
+    Packed,
+    Expanded,
+}
+
+/// Features affecting codegen, resolved for current scope.
+#[derive(Debug, Clone, Copy)]
+struct Features {
+    field_presence: FieldPresence,
+    repeated_field_encoding: RepeatedFieldEncoding,
+}
+
+impl Features {
+    /// Defaults of edition 2023.
+    fn edition_2023() -> Features {
+        Features {
+            field_presence: FieldPresence::Explicit,
+            repeated_field_encoding: RepeatedFieldEncoding::Packed,
+        }
+    }
+
+    /// Remove `features.*` options from `options` and apply them
+    /// on top of `self` (features of the parent scope).
+    fn child(&self, options: &mut Vec<ProtobufOption>) -> anyhow::Result<Features> {
+        let mut features = *self;
+        let mut other = Vec::with_capacity(options.len());
+        for option in options.drain(..) {
+            match feature_name(&option) {
+                Some(name) => features.set(&name, &option.value)?,
+                None => other.push(option),
+            }
+        }
+        *options = other;
+        Ok(features)
+    }
+
+    fn set(&mut self, name: &str, value: &ProtobufConstant) -> anyhow::Result<()> {
+        let value = match value {
+            ProtobufConstant::Ident(ProtobufPath::Rel(value)) => value.to_string(),
+            value => {
+                return Err(
+                    EditionsError::FeatureValueNotIdent(name.to_owned(), value.to_string()).into(),
+                )
+            }
+        };
+        let unknown = || EditionsError::UnknownFeatureValue(name.to_owned(), value.clone());
+        let unsupported = || EditionsError::UnsupportedFeatureValue(name.to_owned(), value.clone());
+        match name {
+            "field_presence" => {
+                self.field_presence = match value.as_str() {
+                    "EXPLICIT" => FieldPresence::Explicit,
+                    "IMPLICIT" => FieldPresence::Implicit,
+                    // Required fields cannot be expressed in proto3.
+                    "LEGACY_REQUIRED" => return Err(unsupported().into()),
+                    _ => return Err(unknown().into()),
+                }
+            }
+            "repeated_field_encoding" => {
+                self.repeated_field_encoding = match value.as_str() {
+                    "PACKED" => RepeatedFieldEncoding::Packed,
+                    "EXPANDED" => RepeatedFieldEncoding::Expanded,
+                    _ => return Err(unknown().into()),
+                }
+            }
+            // Features below are accepted only with values matching
+            // what generated code does anyway.
+            "enum_type" => match value.as_str() {
+                "OPEN" => {}
+                "CLOSED" => return Err(unsupported().into()),
+                _ => return Err(unknown().into()),
+            },
+            "utf8_validation" => match value.as_str() {
+                "VERIFY" => {}
+                "NONE" => return Err(unsupported().into()),
+                _ => return Err(unknown().into()),
+            },
+            "message_encoding" => match value.as_str() {
+                "LENGTH_PREFIXED" => {}
+                "DELIMITED" => return Err(unsupported().into()),
+                _ => return Err(unknown().into()),
+            },
+            "json_format" => match value.as_str() {
+                "ALLOW" | "LEGACY_BEST_EFFORT" => {}
+                _ => return Err(unknown().into()),
+            },
+            _ => return Err(EditionsError::UnknownFeature(name.to_owned()).into()),
+        }
+        Ok(())
+    }
+}
+
+/// Name of the feature if the option is `features.<name>`.
+fn feature_name(option: &ProtobufOption) -> Option<String> {
+    match &option.name {
+        ProtobufOptionName::Ext(ext) => match &ext.0[..] {
+            [ProtobufOptionNamePart::Direct(features), ProtobufOptionNamePart::Direct(name)]
+                if features.get() == "features" =>
+            {
+                Some(name.get().to_owned())
+            }
+            _ => None,
+        },
+        ProtobufOptionName::Builtin(_) => None,
+    }
+}
+
+fn lower_field(field: &mut model::Field, parent: &Features) -> anyhow::Result<()> {
+    let features = parent.child(&mut field.options)?;
+    if field
+        .options
+        .iter()
+        .any(|o| o.name == ProtobufOptionName::simple("packed"))
+    {
+        return Err(EditionsError::PackedNotAllowed.into());
+    }
+    // Proto3 fields cannot have default values.
+    if field
+        .options
+        .iter()
+        .any(|o| o.name == ProtobufOptionName::simple("default"))
+    {
+        return Err(EditionsError::DefaultNotSupported.into());
+    }
+    match field.rule {
+        Some(Rule::Optional) => return Err(EditionsError::LabelNotAllowed("optional").into()),
+        Some(Rule::Required) => return Err(EditionsError::LabelNotAllowed("required").into()),
+        Some(Rule::Repeated) => {
+            field.packed_by_features =
+                features.repeated_field_encoding == RepeatedFieldEncoding::Packed;
+        }
+        None => {
+            if let FieldType::Map(..) = field.typ {
+                return Ok(());
+            }
+            field.rule = match features.field_presence {
+                FieldPresence::Explicit => Some(Rule::Optional),
+                FieldPresence::Implicit => None,
+            };
+        }
+    }
+    Ok(())
+}
+
+fn lower_oneof(oneof: &mut model::OneOf, parent: &Features) -> anyhow::Result<()> {
+    let features = parent.child(&mut oneof.options)?;
+    for field in &mut oneof.fields {
+        // Oneof members always have explicit presence.
+        features.child(&mut field.t.options)?;
+    }
+    Ok(())
+}
+
+fn lower_enum(enumeration: &mut model::Enumeration, parent: &Features) -> anyhow::Result<()> {
+    let features = parent.child(&mut enumeration.options)?;
+    for value in &mut enumeration.values {
+        features.child(&mut value.options)?;
+    }
+    Ok(())
+}
+
+fn lower_message(message: &mut model::Message, parent: &Features) -> anyhow::Result<()> {
+    let features = parent.child(&mut message.options)?;
+    for field in &mut message.fields {
+        match &mut field.t {
+            FieldOrOneOf::Field(field) => lower_field(&mut field.t, &features)?,
+            FieldOrOneOf::OneOf(oneof) => lower_oneof(oneof, &features)?,
+        }
+    }
+    for nested in &mut message.messages {
+        lower_message(&mut nested.t, &features)?;
+    }
+    for nested in &mut message.enums {
+        lower_enum(&mut nested.t, &features)?;
+    }
+    for extension in &mut message.extensions {
+        lower_field(&mut extension.t.field.t, &features)?;
+    }
+    Ok(())
+}
+
+/// Lower edition file parsed in proto3 mode to proto3 model.
+pub(crate) fn lower(file: &mut model::FileDescriptor) -> anyhow::Result<()> {
+    let features = Features::edition_2023().child(&mut file.options)?;
+    for message in &mut file.messages {
+        lower_message(&mut message.t, &features)?;
+    }
+    for enumeration in &mut file.enums {
+        lower_enum(&mut enumeration.t, &features)?;
+    }
+    for extension in &mut file.extensions {
+        lower_field(&mut extension.t.field.t, &features)?;
+    }
+    for service in &mut file.services {
+        let features = features.child(&mut service.t.options)?;
+        for method in &mut service.t.methods {
+            features.child(&mut method.options)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::fmt;
+
+    use protobuf::descriptor::field_descriptor_proto::Label;
+    use protobuf::descriptor::FieldDescriptorProto;
+    use protobuf::descriptor::FileDescriptorProto;
+
+    use crate::proto_path::ProtoPath;
+    use crate::pure::parse_and_typecheck::parse_and_typecheck_custom;
+    use crate::pure::parse_and_typecheck::ProtoPathResolver;
+    use crate::pure::parse_and_typecheck::ResolvedProtoFile;
+    use crate::ProtoPathBuf;
+
+    fn parse(content: &str) -> anyhow::Result<FileDescriptorProto> {
+        struct ResolverImpl(String);
+
+        impl fmt::Display for ResolverImpl {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "ResolverImpl")
+            }
+        }
+
+        impl ProtoPathResolver for ResolverImpl {
+            fn resolve(&self, proto_path: &ProtoPath) -> anyhow::Result<Option<ResolvedProtoFile>> {
+                if proto_path == "ed.proto" {
+                    Ok(Some(ResolvedProtoFile {
+                        path: "ed.proto".to_owned(),
+                        content: self.0.as_bytes().to_vec(),
+                    }))
+                } else {
+                    Ok(None)
+                }
+            }
+        }
+
+        let mut files = parse_and_typecheck_custom(
+            &[ProtoPathBuf::new("ed.proto".to_owned()).unwrap()],
+            ResolverImpl(content.to_owned()),
+        )?;
+        Ok(files.pop().unwrap())
+    }
+
+    fn field<'a>(file: &'a FileDescriptorProto, name: &str) -> &'a FieldDescriptorProto {
+        file.message_type[0]
+            .field
+            .iter()
+            .find(|f| f.name() == name)
+            .unwrap()
+    }
+
+    #[test]
+    fn field_presence() {
+        let file = parse(
+            r#"
+            edition = "2023";
+            option features.field_presence = IMPLICIT;
+            message Foo {
+                option features.field_presence = EXPLICIT;
+                int32 explicit = 1;
+                int32 implicit = 2 [features.field_presence = IMPLICIT];
+                Foo message = 4;
+                map<string, int32> map = 5;
+                oneof o {
+                    string in_oneof = 6;
+                }
+            }
+            message Bar {
+                int32 file_default = 1;
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!("proto3", file.syntax());
+        assert!(file.options.is_none());
+
+        let explicit = field(&file, "explicit");
+        assert_eq!(Label::LABEL_OPTIONAL, explicit.label());
+        assert!(explicit.proto3_optional());
+        assert!(explicit.options.is_none());
+
+        let implicit = field(&file, "implicit");
+        assert_eq!(Label::LABEL_OPTIONAL, implicit.label());
+        assert!(!implicit.proto3_optional());
+        assert!(implicit.options.is_none());
+
+        assert!(field(&file, "message").proto3_optional());
+        assert_eq!(Label::LABEL_REPEATED, field(&file, "map").label());
+
+        let in_oneof = field(&file, "in_oneof");
+        assert!(!in_oneof.proto3_optional());
+        let oneof = &file.message_type[0].oneof_decl[in_oneof.oneof_index() as usize];
+        assert_eq!("o", oneof.name());
+
+        assert!(file.message_type[0].options.is_none());
+        assert!(!file.message_type[1].field[0].proto3_optional());
+    }
+
+    #[test]
+    fn repeated_field_encoding() {
+        let file = parse(
+            r#"
+            edition = "2023";
+            enum E { E_ZERO = 0; }
+            message Foo {
+                repeated int32 packed = 1;
+                repeated int32 expanded = 2 [features.repeated_field_encoding = EXPANDED];
+                repeated E enums = 3;
+                repeated Foo messages = 4;
+                repeated string strings = 5;
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert!(field(&file, "packed").options.packed());
+        assert!(field(&file, "expanded").options.is_none());
+        assert!(field(&file, "enums").options.packed());
+        assert!(field(&file, "messages").options.is_none());
+        assert!(field(&file, "strings").options.is_none());
+    }
+
+    #[test]
+    fn errors() {
+        for content in [
+            r#"edition = "2024";"#,
+            r#"edition = "2023"; message Foo { optional int32 a = 1; }"#,
+            r#"edition = "2023"; message Foo { repeated int32 a = 1 [packed = true]; }"#,
+            r#"edition = "2023"; option features.enum_type = CLOSED;"#,
+            r#"edition = "2023"; option features.field_presence = LEGACY_REQUIRED;"#,
+            r#"edition = "2023"; message Foo { int32 a = 1 [default = 1]; }"#,
+            r#"edition = "2023"; option features.field_presence = SOMETIMES;"#,
+            r#"edition = "2023"; option features.no_such_feature = YES;"#,
+        ] {
+            assert!(parse(content).is_err(), "{}", content);
+        }
+    }
+}
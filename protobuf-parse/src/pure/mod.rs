@@ -1,6 +1,7 @@
 //! Pure rust `.proto` file parser.
 
 pub(crate) mod convert;
+mod editions;
 pub(crate) mod model;
 pub(crate) mod parse_and_typecheck;
 pub(crate) mod parse_dependencies;
@@ -204,6 +204,9 @@ pub(crate) struct Field {
     pub number: i32,
     /// Non-builtin options
     pub options: Vec<ProtobufOption>,
+    /// Repeated field is packed by editions `features.repeated_field_encoding`
+    /// if its type is packable, which is known only after type resolution
+    pub packed_by_features: bool,
 }
 
 /// A Protobuf field of oneof group
@@ -563,6 +566,8 @@ pub(crate) struct FileDescriptor {
     pub package: ProtobufAbsPath,
     /// Protobuf Syntax
     pub syntax: Syntax,
+    /// Edition if the file is edition file, such files are lowered to proto3
+    pub edition: Option<String>,
     /// Top level messages
     pub messages: Vec<WithLoc<Message>>,
     /// Enums
@@ -347,4 +347,48 @@ mod test {
         assert_eq!(1, resolved.len());
         assert_eq!("Foo", resolved[0].message_type[0].name());
     }
+
+    #[test]
+    fn json_name_option() {
+        struct ResolverImpl;
+
+        impl fmt::Display for ResolverImpl {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "ResolverImpl")
+            }
+        }
+
+        impl ProtoPathResolver for ResolverImpl {
+            fn resolve(&self, proto_path: &ProtoPath) -> anyhow::Result<Option<ResolvedProtoFile>> {
+                assert_eq!("xx.proto", proto_path.to_str());
+                Ok(Some(ResolvedProtoFile {
+                    path: "xx.proto".to_string(),
+                    content: r#"
+                        syntax = "proto3";
+                        message Foo {
+                            int32 plain_field = 1;
+                            int32 custom_field = 2 [json_name = "customName"];
+                            oneof o {
+                                string in_oneof = 3 [json_name = "oneofName"];
+                            }
+                        }
+                    "#
+                    .as_bytes()
+                    .to_vec(),
+                }))
+            }
+        }
+
+        let resolved = super::parse_and_typecheck_custom(
+            &[ProtoPathBuf::new("xx.proto".to_owned()).unwrap()],
+            ResolverImpl,
+        )
+        .unwrap();
+        let fields = &resolved[0].message_type[0].field;
+        assert_eq!("plainField", fields[0].json_name());
+        assert_eq!("customName", fields[1].json_name());
+        assert_eq!("oneofName", fields[2].json_name());
+        // `json_name` is not an option in descriptor.
+        assert!(fields[1].options.is_none(), "{:?}", fields[1].options);
+    }
 }
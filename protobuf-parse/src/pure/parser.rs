@@ -16,6 +16,7 @@ use crate::protobuf_abs_path::ProtobufAbsPath;
 use crate::protobuf_ident::ProtobufIdent;
 use crate::protobuf_path::ProtobufPath;
 use crate::protobuf_rel_path::ProtobufRelPath;
+use crate::pure::editions;
 use crate::pure::model;
 use crate::pure::model::EnumValue;
 use crate::pure::model::Enumeration;
@@ -182,6 +183,7 @@ impl ToU8 for u32 {
 pub(crate) struct Parser<'a> {
     pub tokenizer: Tokenizer<'a>,
     syntax: Syntax,
+    edition: Option<String>,
 }
 
 #[derive(Copy, Clone)]
@@ -295,6 +297,7 @@ impl<'a> Parser<'a> {
         Parser {
             tokenizer: Tokenizer::new(input, ParserLanguage::Proto),
             syntax: Syntax::Proto2,
+            edition: None,
         }
     }
 
@@ -474,8 +477,17 @@ impl<'a> Parser<'a> {
 
     // syntax = "syntax" "=" quote "proto2" quote ";"
     // syntax = "syntax" "=" quote "proto3" quote ";"
+    // edition = "edition" "=" quote "2023" quote ";"
     fn next_syntax(&mut self) -> anyhow::Result<Option<Syntax>> {
-        if self.tokenizer.next_ident_if_eq("syntax")? {
+        if self.tokenizer.next_ident_if_eq("edition")? {
+            self.tokenizer.next_symbol_expect_eq('=', "edition")?;
+            let edition = self.tokenizer.next_str_lit()?.decode_utf8()?;
+            editions::check_edition(&edition)?;
+            self.tokenizer.next_symbol_expect_eq(';', "edition")?;
+            self.edition = Some(edition);
+            // Edition files are parsed as proto3 and then lowered.
+            Ok(Some(Syntax::Proto3))
+        } else if self.tokenizer.next_ident_if_eq("syntax")? {
             self.tokenizer.next_symbol_expect_eq('=', "syntax")?;
             let syntax_str = self.tokenizer.next_str_lit()?.decode_utf8()?;
             let syntax = if syntax_str == "proto2" {
@@ -697,6 +709,7 @@ impl<'a> Parser<'a> {
                 typ: FieldType::Group(Group { name, fields }),
                 number,
                 options: Vec::new(),
+                packed_by_features: false,
             };
             Ok(WithLoc { t: field, loc })
         } else {
@@ -720,6 +733,7 @@ impl<'a> Parser<'a> {
                 typ,
                 number,
                 options,
+                packed_by_features: false,
             };
             Ok(WithLoc { t: field, loc })
         }
@@ -1278,16 +1292,21 @@ impl<'a> Parser<'a> {
             return Err(ParserError::IncorrectInput.into());
         }
 
-        Ok(FileDescriptor {
+        let mut file = FileDescriptor {
             imports,
             package,
             syntax,
+            edition: self.edition.clone(),
             messages,
             enums,
             extensions,
             services,
             options,
-        })
+        };
+        if file.edition.is_some() {
+            editions::lower(&mut file)?;
+        }
+        Ok(file)
     }
 }
 
@@ -1332,6 +1351,16 @@ mod test {
         assert_eq!(Syntax::Proto3, mess);
     }
 
+    #[test]
+    fn test_edition() {
+        let mut parser = Parser::new(r#"  edition = "2023";  "#);
+        assert_eq!(Some(Syntax::Proto3), parser.next_syntax().unwrap());
+        assert_eq!(Some("2023"), parser.edition.as_deref());
+
+        let mut parser = Parser::new(r#"  edition = "2024";  "#);
+        assert!(parser.next_syntax().is_err());
+    }
+
     #[test]
     fn test_field_default_value_int() {
         let msg = r#"  optional int64 f = 4 [default = 12];  "#;
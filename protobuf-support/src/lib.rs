@@ -3,6 +3,7 @@
 //! Code in this crate is used in protobuf crates like `protobuf` or `protobuf-parse`.
 //! None of code in this crate has public API.
 
+pub mod base64;
 pub mod json_name;
 pub mod lexer;
 pub mod text_format;
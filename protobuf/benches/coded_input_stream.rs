@@ -8,7 +8,10 @@ extern crate test;
 use std::io;
 use std::io::Read;
 
+use protobuf::well_known_types::struct_::ListValue;
+use protobuf::well_known_types::struct_::Value;
 use protobuf::CodedInputStream;
+use protobuf::Message;
 
 use self::test::Bencher;
 
@@ -133,3 +136,96 @@ fn read_varint_large(b: &mut Bencher) {
         assert_eq!(1000, count);
     })
 }
+
+/// 1000 length-delimited `ListValue` messages with 10 string values each.
+fn make_list_values() -> Vec<u8> {
+    let mut list = ListValue::new();
+    for i in 0..10 {
+        let mut value = Value::new();
+        value.set_string_value(format!("value {}", i));
+        list.values.push(value);
+    }
+    let mut v = Vec::new();
+    {
+        let mut os = protobuf::CodedOutputStream::vec(&mut v);
+        for _ in 0..1000 {
+            os.write_raw_varint32(list.compute_size() as u32)
+                .expect("write");
+            list.write_to_with_cached_sizes(&mut os).expect("write");
+        }
+        os.flush().expect("flush");
+    }
+    v
+}
+
+#[bench]
+fn read_message(b: &mut Bencher) {
+    let v = make_list_values();
+    b.iter(|| {
+        let mut is = CodedInputStream::from_bytes(test::black_box(&v));
+        while !is.eof().expect("eof") {
+            test::black_box(is.read_message::<ListValue>().expect("read"));
+        }
+    })
+}
+
+#[bench]
+fn read_message_into(b: &mut Bencher) {
+    let v = make_list_values();
+    b.iter(|| {
+        let mut is = CodedInputStream::from_bytes(test::black_box(&v));
+        let mut list = ListValue::new();
+        while !is.eof().expect("eof") {
+            is.read_message_into(&mut list).expect("read");
+            test::black_box(&list);
+        }
+    })
+}
+
+/// Many short strings with a few distinct values.
+#[cfg(feature = "bytes")]
+fn make_repeated_strings() -> Vec<u8> {
+    let mut v = Vec::new();
+    {
+        let mut os = protobuf::CodedOutputStream::vec(&mut v);
+        for i in 0..10_000 {
+            os.write_string_no_tag(&format!("value number {}", i % 10))
+                .expect("write");
+        }
+        os.flush().expect("flush");
+    }
+    v
+}
+
+/// Every string is a separate allocation (`from_bytes` copies).
+#[cfg(feature = "bytes")]
+#[bench]
+fn read_chars_repeated(b: &mut Bencher) {
+    let v = make_repeated_strings();
+    b.iter(|| {
+        let mut is = CodedInputStream::from_bytes(test::black_box(&v));
+        let mut r = Vec::new();
+        while !is.eof().expect("eof") {
+            r.push(is.read_tokio_chars().expect("read"));
+        }
+        test::black_box(r)
+    })
+}
+
+/// Measures time of reading through the interner.
+/// Retained memory is checked in `tests/string_interner_memory.rs`.
+#[cfg(feature = "bytes")]
+#[bench]
+fn read_chars_repeated_interned(b: &mut Bencher) {
+    let v = make_repeated_strings();
+    b.iter(|| {
+        let mut interner = protobuf::HashSetStringInterner::new();
+        let mut is = CodedInputStream::from_bytes(test::black_box(&v));
+        is.set_string_interner(&mut interner);
+        let mut r = Vec::new();
+        while !is.eof().expect("eof") {
+            r.push(is.read_tokio_chars().expect("read"));
+        }
+        test::black_box(r)
+    })
+}
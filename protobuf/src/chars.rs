@@ -2,6 +2,8 @@
 
 use std::borrow::Borrow;
 use std::fmt;
+use std::hash::Hash;
+use std::hash::Hasher;
 use std::ops::Deref;
 use std::str;
 
@@ -9,9 +11,16 @@ use bytes::Bytes;
 
 /// Thin wrapper around `Bytes` which guarantees that bytes are valid UTF-8 string.
 /// Should be API-compatible to `String`.
-#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Chars(Bytes);
 
+/// Hash as `str`, as required by `Borrow<str>` implementation.
+impl Hash for Chars {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state)
+    }
+}
+
 impl Chars {
     /// New empty object.
     pub const fn new() -> Chars {
@@ -109,4 +118,14 @@ mod test {
         assert_eq!(format!("{}", string), format!("{}", chars));
         assert_eq!(format!("{:?}", string), format!("{:?}", chars));
     }
+
+    #[test]
+    fn test_hash_same_as_str() {
+        use std::collections::HashSet;
+
+        let mut set = HashSet::new();
+        set.insert(Chars::from("test"));
+        assert!(set.contains("test"));
+        assert!(!set.contains("tes"));
+    }
 }
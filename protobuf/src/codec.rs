@@ -0,0 +1,211 @@
+//! Length-delimited framing of messages in [`BytesMut`] buffers.
+//!
+//! [`ProtobufCodec`] decodes and encodes a stream of messages,
+//! each prefixed with its length encoded as varint
+//! (same as [`write_length_delimited_to`](crate::Message::write_length_delimited_to)).
+//! With `with-tokio-util` feature it implements `tokio_util::codec::Decoder`
+//! and `Encoder`, so it can be plugged into `Framed`.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use bytes::Buf;
+use bytes::BufMut;
+use bytes::BytesMut;
+
+use crate::varint::decode::decode_varint32;
+use crate::wire_format::check_message_size;
+use crate::Message;
+
+/// Decoder and encoder of length-delimited messages of type `M`.
+///
+/// # Examples
+///
+/// ```
+/// use bytes::BytesMut;
+/// use protobuf::codec::ProtobufCodec;
+/// use protobuf::well_known_types::duration::Duration;
+///
+/// let mut codec = ProtobufCodec::<Duration>::new();
+/// let mut d = Duration::new();
+/// d.seconds = 10;
+///
+/// let mut buf = BytesMut::new();
+/// codec.encode(&d, &mut buf).unwrap();
+/// codec.encode(&d, &mut buf).unwrap();
+///
+/// // Only part of the frame is received.
+/// let mut received = buf.split_to(2);
+/// assert_eq!(None, codec.decode(&mut received).unwrap());
+///
+/// received.unsplit(buf);
+/// assert_eq!(Some(d.clone()), codec.decode(&mut received).unwrap());
+/// assert_eq!(Some(d), codec.decode(&mut received).unwrap());
+/// assert_eq!(None, codec.decode(&mut received).unwrap());
+/// ```
+pub struct ProtobufCodec<M> {
+    _marker: PhantomData<fn() -> M>,
+}
+
+impl<M: Message> ProtobufCodec<M> {
+    /// Construct a codec.
+    pub fn new() -> ProtobufCodec<M> {
+        ProtobufCodec {
+            _marker: PhantomData,
+        }
+    }
+
+    /// Decode a message from the start of the buffer, removing its bytes.
+    ///
+    /// Return `None` if the buffer does not contain a complete frame yet,
+    /// the buffer is left untouched then, and more bytes should be appended to it.
+    pub fn decode(&mut self, src: &mut BytesMut) -> crate::Result<Option<M>> {
+        let (len, len_len) = match decode_varint32(src)? {
+            Some(r) => r,
+            None => return Ok(None),
+        };
+        let len = check_message_size(len as u64)? as usize;
+        if src.len() < len_len + len {
+            src.reserve(len_len + len - src.len());
+            return Ok(None);
+        }
+        src.advance(len_len);
+        let frame = src.split_to(len).freeze();
+        M::parse_from_tokio_bytes(&frame).map(Some)
+    }
+
+    /// Append a message prefixed with its length to the buffer.
+    pub fn encode(&mut self, msg: &M, dst: &mut BytesMut) -> crate::Result<()> {
+        msg.write_length_delimited_to_writer(&mut dst.writer())
+    }
+}
+
+#[cfg(feature = "tokio-util")]
+impl<M: Message> tokio_util::codec::Decoder for ProtobufCodec<M> {
+    type Item = M;
+    type Error = crate::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> crate::Result<Option<M>> {
+        ProtobufCodec::decode(self, src)
+    }
+}
+
+#[cfg(feature = "tokio-util")]
+impl<M: Message> tokio_util::codec::Encoder<M> for ProtobufCodec<M> {
+    type Error = crate::Error;
+
+    fn encode(&mut self, msg: M, dst: &mut BytesMut) -> crate::Result<()> {
+        ProtobufCodec::encode(self, &msg, dst)
+    }
+}
+
+impl<M: Message> Default for ProtobufCodec<M> {
+    fn default() -> Self {
+        ProtobufCodec::new()
+    }
+}
+
+impl<M> Clone for ProtobufCodec<M> {
+    fn clone(&self) -> Self {
+        ProtobufCodec {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<M> fmt::Debug for ProtobufCodec<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ProtobufCodec").finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bytes::BytesMut;
+
+    use super::ProtobufCodec;
+    use crate::well_known_types::struct_::Value;
+
+    fn value(i: usize) -> Value {
+        let mut v = Value::new();
+        v.set_string_value("x".repeat(i * 50));
+        v
+    }
+
+    #[test]
+    fn test_partial_frames() {
+        let mut codec = ProtobufCodec::<Value>::new();
+        let mut sent = BytesMut::new();
+        for i in 0..10 {
+            codec.encode(&value(i), &mut sent).unwrap();
+        }
+
+        // Receive in chunks not aligned with frames.
+        let mut received = BytesMut::new();
+        let mut decoded = Vec::new();
+        while !sent.is_empty() {
+            let n = sent.len().min(7);
+            received.extend_from_slice(&sent.split_to(n));
+            while let Some(m) = codec.decode(&mut received).unwrap() {
+                decoded.push(m);
+            }
+        }
+        assert!(received.is_empty());
+        assert_eq!((0..10).map(value).collect::<Vec<_>>(), decoded);
+    }
+
+    #[test]
+    fn test_empty_message() {
+        let mut codec = ProtobufCodec::<Value>::new();
+        let mut buf = BytesMut::new();
+        codec.encode(&Value::new(), &mut buf).unwrap();
+        assert_eq!(&[0][..], &buf[..]);
+        assert_eq!(Some(Value::new()), codec.decode(&mut buf).unwrap());
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn test_malformed() {
+        let mut codec = ProtobufCodec::<Value>::new();
+        // Length 2, then an invalid tag.
+        let mut buf = BytesMut::from(&[0x02, 0x00, 0x00][..]);
+        assert!(codec.decode(&mut buf).is_err());
+        // Varint too long.
+        let mut buf = BytesMut::from(&[0xff; 11][..]);
+        assert!(codec.decode(&mut buf).is_err());
+    }
+
+    #[cfg(feature = "tokio-util")]
+    #[test]
+    fn test_framed() {
+        use futures::SinkExt;
+        use futures::StreamExt;
+        use tokio_util::codec::Framed;
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        runtime.block_on(async {
+            // Small buffer so frames are split between reads.
+            let (a, b) = tokio::io::duplex(16);
+            let mut sender = Framed::new(a, ProtobufCodec::<Value>::new());
+            let mut receiver = Framed::new(b, ProtobufCodec::<Value>::new());
+
+            let send = async {
+                for i in 0..10 {
+                    sender.send(value(i)).await.unwrap();
+                }
+                sender.close().await.unwrap();
+            };
+            let receive = async {
+                let mut decoded = Vec::new();
+                while let Some(m) = receiver.next().await {
+                    decoded.push(m.unwrap());
+                }
+                decoded
+            };
+            let ((), decoded) = tokio::join!(send, receive);
+            assert_eq!((0..10).map(value).collect::<Vec<_>>(), decoded);
+        });
+    }
+}
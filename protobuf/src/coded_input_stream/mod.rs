@@ -3,11 +3,14 @@ mod buf_read_or_reader;
 mod input_buf;
 mod input_source;
 
+use std::fmt;
 use std::io;
 use std::io::BufRead;
 use std::io::Read;
 use std::mem;
 use std::mem::MaybeUninit;
+#[cfg(feature = "bytes")]
+use std::str;
 
 #[cfg(feature = "bytes")]
 use ::bytes::Bytes;
@@ -35,9 +38,12 @@ use crate::reflect::types::ProtobufTypeTrait;
 use crate::reflect::types::ProtobufTypeUint32;
 use crate::reflect::types::ProtobufTypeUint64;
 use crate::reflect::MessageDescriptor;
+#[cfg(feature = "bytes")]
+use crate::string_interner::StringInterner;
 use crate::unknown::UnknownValue;
 use crate::varint::decode::decode_varint32;
 use crate::varint::decode::decode_varint64;
+use crate::varint::MAX_VARINT32_ENCODED_LEN;
 use crate::varint::MAX_VARINT_ENCODED_LEN;
 use crate::wire_format;
 use crate::wire_format::WireType;
@@ -53,12 +59,40 @@ const DEFAULT_RECURSION_LIMIT: u32 = 100;
 // Max allocated vec when reading length-delimited from unknown input stream
 pub(crate) const READ_RAW_BYTES_MAX_ALLOC: usize = 10_000_000;
 
+/// How [`CodedInputStream`] treats non-finite `float` and `double` values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FloatPolicy {
+    /// Accept any value, including NaN and infinities.
+    #[default]
+    AllowNonFinite,
+    /// Return an error when NaN or infinity is read.
+    RejectNonFinite,
+}
+
 /// Buffered read with handy utilities.
-#[derive(Debug)]
 pub struct CodedInputStream<'a> {
     source: BufReadIter<'a>,
     recursion_level: u32,
     recursion_limit: u32,
+    float_policy: FloatPolicy,
+    #[cfg(feature = "bytes")]
+    string_interner: Option<&'a mut dyn StringInterner>,
+    /// Reused buffer for strings which are interned, so they are not allocated.
+    #[cfg(feature = "bytes")]
+    string_interner_buf: Vec<u8>,
+}
+
+impl<'a> fmt::Debug for CodedInputStream<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut d = f.debug_struct("CodedInputStream");
+        d.field("source", &self.source)
+            .field("recursion_level", &self.recursion_level)
+            .field("recursion_limit", &self.recursion_limit)
+            .field("float_policy", &self.float_policy);
+        #[cfg(feature = "bytes")]
+        d.field("string_interner", &self.string_interner.is_some());
+        d.finish()
+    }
 }
 
 impl<'a> CodedInputStream<'a> {
@@ -99,6 +133,11 @@ impl<'a> CodedInputStream<'a> {
             source,
             recursion_level: 0,
             recursion_limit: DEFAULT_RECURSION_LIMIT,
+            float_policy: FloatPolicy::AllowNonFinite,
+            #[cfg(feature = "bytes")]
+            string_interner: None,
+            #[cfg(feature = "bytes")]
+            string_interner_buf: Vec::new(),
         }
     }
 
@@ -107,6 +146,24 @@ impl<'a> CodedInputStream<'a> {
         self.recursion_limit = limit;
     }
 
+    /// Set how non-finite values of `float` and `double` fields are treated.
+    ///
+    /// By default all values are accepted.
+    pub fn set_float_policy(&mut self, policy: FloatPolicy) {
+        self.float_policy = policy;
+    }
+
+    /// Deduplicate strings read with [`read_tokio_chars`](Self::read_tokio_chars)
+    /// (used for `string` fields generated with `tokio_bytes_for_string` option)
+    /// with given interner.
+    ///
+    /// Interned strings do not share storage with the input even if the stream
+    /// is created with [`from_tokio_bytes`](Self::from_tokio_bytes).
+    #[cfg(feature = "bytes")]
+    pub fn set_string_interner(&mut self, interner: &'a mut dyn StringInterner) {
+        self.string_interner = Some(interner);
+    }
+
     #[inline]
     pub(crate) fn incr_recursion(&mut self) -> crate::Result<()> {
         if self.recursion_level >= self.recursion_limit {
@@ -121,6 +178,12 @@ impl<'a> CodedInputStream<'a> {
         self.recursion_level -= 1;
     }
 
+    /// Nesting level of message currently being parsed, `0` for the outermost message.
+    #[cfg(feature = "with-log")]
+    pub(crate) fn recursion_level(&self) -> u32 {
+        self.recursion_level
+    }
+
     /// How many bytes processed
     pub fn pos(&self) -> u64 {
         self.source.pos()
@@ -153,6 +216,14 @@ impl<'a> CodedInputStream<'a> {
     }
 
     /// Push new limit, return previous limit.
+    ///
+    /// Limit is the number of bytes from the current position:
+    /// reads past it fail with truncated message error,
+    /// and [`eof`](Self::eof) returns `true` at it.
+    /// The limit must not exceed the current limit.
+    ///
+    /// Previous limit must be restored with [`pop_limit`](Self::pop_limit);
+    /// [`with_limit`](Self::with_limit) does it even if reading fails.
     pub fn push_limit(&mut self, limit: u64) -> crate::Result<u64> {
         self.source.push_limit(limit)
     }
@@ -162,7 +233,36 @@ impl<'a> CodedInputStream<'a> {
         self.source.pop_limit(old_limit);
     }
 
+    /// Call function with the limit pushed, and restore previous limit after it,
+    /// whether the function succeeds or not.
+    ///
+    /// ```
+    /// # use protobuf::CodedInputStream;
+    /// let mut is = CodedInputStream::from_bytes(&[1, 2, 3]);
+    /// let r = is.with_limit(2, |is| {
+    ///     is.read_raw_bytes(2)?;
+    ///     is.read_raw_byte()
+    /// });
+    /// assert!(r.is_err());
+    /// assert_eq!(3, is.read_raw_byte().unwrap());
+    /// ```
+    pub fn with_limit<R>(
+        &mut self,
+        limit: u64,
+        f: impl FnOnce(&mut Self) -> crate::Result<R>,
+    ) -> crate::Result<R> {
+        let old_limit = self.push_limit(limit)?;
+        let r = f(self);
+        self.pop_limit(old_limit);
+        r
+    }
+
     /// Are we at EOF?
+    ///
+    /// Nothing is consumed: if the buffer is empty, this function
+    /// fills it from the underlying reader to check if there is more input.
+    /// EOF is also reported at the current limit
+    /// (see [`push_limit`](Self::push_limit)).
     #[inline(always)]
     pub fn eof(&mut self) -> crate::Result<bool> {
         self.source.eof()
@@ -199,11 +299,21 @@ impl<'a> CodedInputStream<'a> {
     }
 
     fn read_raw_varint32_slow(&mut self) -> crate::Result<u32> {
-        let v = self.read_raw_varint64_slow()?;
-        if v > u32::MAX as u64 {
-            return Err(ProtobufError::WireError(WireError::U32Overflow(v)).into());
+        let mut r: u32 = 0;
+        let mut i = 0;
+        loop {
+            let b = self.read_raw_byte()?;
+            // Fifth byte may only contain the remaining four bits of `u32`,
+            // so longer varints and values above `u32::MAX` are rejected.
+            if i == MAX_VARINT32_ENCODED_LEN - 1 && b > 0x0f {
+                return Err(ProtobufError::WireError(WireError::IncorrectVarint).into());
+            }
+            r |= ((b & 0x7f) as u32) << (i * 7);
+            i += 1;
+            if b < 0x80 {
+                return Ok(r);
+            }
         }
-        Ok(v as u32)
     }
 
     /// Read varint
@@ -234,8 +344,35 @@ impl<'a> CodedInputStream<'a> {
         }
     }
 
+    /// Read varint or return `None` if EOF is reached before the first byte.
+    ///
+    /// This allows telling clean EOF (e. g. between length-delimited messages)
+    /// from truncated input: if EOF is reached in the middle of varint,
+    /// `UnexpectedEof` error is returned.
+    #[inline]
+    pub fn read_raw_varint64_or_eof(&mut self) -> crate::Result<Option<u64>> {
+        let rem = self.source.remaining_in_buf();
+        match decode_varint64(rem)? {
+            Some((r, c)) => {
+                self.source.consume(c);
+                Ok(Some(r))
+            }
+            None => {
+                if self.eof()? {
+                    Ok(None)
+                } else {
+                    let v = self.read_raw_varint64_slow()?;
+                    Ok(Some(v))
+                }
+            }
+        }
+    }
+
+    /// Read 32-bit varint or return `None` if EOF is reached before the first byte.
+    ///
+    /// See [`read_raw_varint64_or_eof`](Self::read_raw_varint64_or_eof).
     #[inline]
-    fn read_raw_varint32_or_eof(&mut self) -> crate::Result<Option<u32>> {
+    pub fn read_raw_varint32_or_eof(&mut self) -> crate::Result<Option<u32>> {
         let rem = self.source.remaining_in_buf();
         let v = decode_varint32(rem)?;
         match v {
@@ -278,6 +415,55 @@ impl<'a> CodedInputStream<'a> {
         self.read_raw_varint32_or_eof()
     }
 
+    /// Read tag and return it as pair of field number and wire type,
+    /// or `None` if EOF is reached before the tag.
+    ///
+    /// This is how generated `merge_from` loops over fields,
+    /// it can be used to write parsers by hand:
+    ///
+    /// ```
+    /// # use protobuf::CodedInputStream;
+    /// # use protobuf::rt::WireType;
+    /// let mut is = CodedInputStream::from_bytes(&[0x08, 0x96, 0x01]);
+    /// while let Some((field_number, wire_type)) = is.read_tag_or_eof().unwrap() {
+    ///     assert_eq!((1, WireType::Varint), (field_number, wire_type));
+    ///     assert_eq!(150, is.read_int32().unwrap());
+    /// }
+    /// ```
+    pub fn read_tag_or_eof(&mut self) -> crate::Result<Option<(u32, WireType)>> {
+        match self.read_raw_tag_or_eof()? {
+            Some(tag) => Ok(Some(wire_format::Tag::new(tag)?.unpack())),
+            None => Ok(None),
+        }
+    }
+
+    /// Skip fields until a field with given number is found.
+    ///
+    /// Return the wire type of the found field, the stream is positioned
+    /// to read its value, and the rest of the input is not consumed.
+    /// Return `None` if EOF (or the limit) is reached before the field.
+    ///
+    /// This can be used to dispatch on a header field
+    /// without parsing the whole message:
+    ///
+    /// ```
+    /// # use protobuf::CodedInputStream;
+    /// # use protobuf::rt::WireType;
+    /// // 2: 10, 1: "ab", 3: 30
+    /// let mut is = CodedInputStream::from_bytes(&[0x10, 0x0a, 0x0a, 0x02, b'a', b'b', 0x18, 0x1e]);
+    /// assert_eq!(Some(WireType::LengthDelimited), is.read_until_field(1).unwrap());
+    /// assert_eq!("ab", is.read_string().unwrap());
+    /// ```
+    pub fn read_until_field(&mut self, field_number: u32) -> crate::Result<Option<WireType>> {
+        while let Some((number, wire_type)) = self.read_tag_or_eof()? {
+            if number == field_number {
+                return Ok(Some(wire_type));
+            }
+            self.skip_field(wire_type)?;
+        }
+        Ok(None)
+    }
+
     /// Read tag
     #[inline]
     pub(crate) fn read_tag(&mut self) -> crate::Result<wire_format::Tag> {
@@ -293,14 +479,20 @@ impl<'a> CodedInputStream<'a> {
 
     /// Read `double`
     pub fn read_double(&mut self) -> crate::Result<f64> {
-        let bits = self.read_raw_little_endian64()?;
-        Ok(f64::from_bits(bits))
+        let v = f64::from_bits(self.read_raw_little_endian64()?);
+        if self.float_policy == FloatPolicy::RejectNonFinite && !v.is_finite() {
+            return Err(WireError::NonFiniteFloat(v).into());
+        }
+        Ok(v)
     }
 
     /// Read `float`
     pub fn read_float(&mut self) -> crate::Result<f32> {
-        let bits = self.read_raw_little_endian32()?;
-        Ok(f32::from_bits(bits))
+        let v = f32::from_bits(self.read_raw_little_endian32()?);
+        if self.float_policy == FloatPolicy::RejectNonFinite && !v.is_finite() {
+            return Err(WireError::NonFiniteFloat(v as f64).into());
+        }
+        Ok(v)
     }
 
     /// Read `int64`
@@ -481,6 +673,33 @@ impl<'a> CodedInputStream<'a> {
         self.read_repeated_packed_fixed_into::<ProtobufTypeFixed32>(target)
     }
 
+    /// Read packed `fixed32` field into provided buffer without allocation.
+    ///
+    /// Like [`read_repeated_packed_fixed32_into`](Self::read_repeated_packed_fixed32_into),
+    /// this function reads length-delimited field data after the tag.
+    /// Values are stored at the beginning of `target`,
+    /// and the number of values read is returned.
+    ///
+    /// # Errors
+    ///
+    /// If the field contains more values than `target` can hold.
+    /// In this case values are not read.
+    pub fn read_packed_fixed32_into(&mut self, target: &mut [u32]) -> crate::Result<usize> {
+        let len_bytes = self.read_raw_varint64()?;
+        if len_bytes > target.len() as u64 * 4 {
+            return Err(WireError::PackedFieldTooLong(len_bytes, target.len()).into());
+        }
+
+        let old_limit = self.push_limit(len_bytes)?;
+        let mut count = 0;
+        while !self.eof()? {
+            target[count] = self.read_fixed32()?;
+            count += 1;
+        }
+        self.pop_limit(old_limit);
+        Ok(count)
+    }
+
     /// Read repeated packed `sfixed64`
     pub fn read_repeated_packed_sfixed64_into(
         &mut self,
@@ -595,10 +814,25 @@ impl<'a> CodedInputStream<'a> {
     /// Read `string` field, length delimited
     #[cfg(feature = "bytes")]
     pub fn read_tokio_chars(&mut self) -> crate::Result<Chars> {
+        if self.string_interner.is_some() {
+            return self.read_interned_chars();
+        }
         let bytes = self.read_tokio_bytes()?;
         Ok(Chars::from_bytes(bytes).map_err(ProtobufError::Utf8)?)
     }
 
+    #[cfg(feature = "bytes")]
+    fn read_interned_chars(&mut self) -> crate::Result<Chars> {
+        let len = self.read_raw_varint32()?;
+        let mut buf = mem::take(&mut self.string_interner_buf);
+        let r = self.read_raw_bytes_into(len, &mut buf).and_then(|()| {
+            let s = str::from_utf8(&buf).map_err(ProtobufError::Utf8)?;
+            Ok(self.string_interner.as_mut().unwrap().intern(s))
+        });
+        self.string_interner_buf = buf;
+        r
+    }
+
     /// Read `bytes` field, length delimited
     pub fn read_bytes_into(&mut self, target: &mut Vec<u8>) -> crate::Result<()> {
         let len = self.read_raw_varint32()?;
@@ -664,6 +898,20 @@ impl<'a> CodedInputStream<'a> {
         Ok(r)
     }
 
+    /// Read message into provided instance, reusing its allocations.
+    ///
+    /// The message is cleared before reading, so the result is the same
+    /// as of [`read_message`](Self::read_message), but buffers
+    /// of `message` (e. g. of `repeated` and `string` fields) may be reused.
+    /// This is useful to avoid allocation per message when messages
+    /// are read one by one, for example, from a pool of instances.
+    pub fn read_message_into<M: Message>(&mut self, message: &mut M) -> crate::Result<()> {
+        message.clear();
+        self.merge_message(message)?;
+        message.check_initialized()?;
+        Ok(())
+    }
+
     /// Read message.
     pub fn read_message_dyn(
         &mut self,
@@ -674,6 +922,38 @@ impl<'a> CodedInputStream<'a> {
         r.check_initialized_dyn()?;
         Ok(r)
     }
+
+    /// Read message fields until EOF (or current limit), invoking `f`
+    /// for each element of repeated message field `field_number`.
+    ///
+    /// Elements are not accumulated: the same instance is reused
+    /// (as with [`read_message_into`](Self::read_message_into))
+    /// for each element, so memory usage does not depend on the number of elements.
+    /// Other fields are skipped.
+    ///
+    /// This allows streaming processing of a message with a huge repeated field,
+    /// when the stream contains the outer message.
+    pub fn for_each_repeated_message<M, F>(
+        &mut self,
+        field_number: u32,
+        mut f: F,
+    ) -> crate::Result<()>
+    where
+        M: Message,
+        F: FnMut(&M),
+    {
+        let mut message = M::new();
+        while let Some(tag) = self.read_raw_tag_or_eof()? {
+            let (number, wire_type) = wire_format::Tag::new(tag)?.unpack();
+            if number == field_number && wire_type == WireType::LengthDelimited {
+                self.read_message_into(&mut message)?;
+                f(&message);
+            } else {
+                self.skip_field(wire_type)?;
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<'a> Read for CodedInputStream<'a> {
@@ -701,12 +981,19 @@ mod test {
     use std::io::Read;
 
     use super::CodedInputStream;
+    use super::FloatPolicy;
     use super::READ_RAW_BYTES_MAX_ALLOC;
+    use crate::descriptor::FileDescriptorSet;
     use crate::error::ProtobufError;
+    use crate::error::WireError;
     use crate::hex::decode_hex;
+    use crate::well_known_types::struct_::ListValue;
+    use crate::well_known_types::struct_::Value;
+    use crate::well_known_types::wrappers::DoubleValue;
     use crate::wire_format::Tag;
     use crate::wire_format::WireType;
     use crate::CodedOutputStream;
+    use crate::Message;
 
     fn test_read_partial<F>(hex: &str, mut callback: F)
     where
@@ -808,6 +1095,74 @@ mod test {
         });
     }
 
+    /// Run callback on the stream which buffers single byte at a time,
+    /// so varints are decoded byte-by-byte rather than from the buffer.
+    fn test_read_partial_unbuffered<F>(hex: &str, mut callback: F)
+    where
+        F: FnMut(&mut CodedInputStream),
+    {
+        let d = decode_hex(hex);
+        let mut reader = io::BufReader::with_capacity(1, io::Cursor::new(&d));
+        let mut is = CodedInputStream::from_buf_read(&mut reader as &mut dyn BufRead);
+        callback(&mut is);
+    }
+
+    #[test]
+    fn test_input_stream_read_raw_varint32_overflow() {
+        fn assert_incorrect_varint(r: crate::Result<u32>) {
+            match *r.unwrap_err().0 {
+                ProtobufError::WireError(WireError::IncorrectVarint) => (),
+                e => panic!("{:?}", e),
+            }
+        }
+
+        for hex in [
+            // 6 bytes
+            "80 80 80 80 80 00",
+            // u32::MAX + 1
+            "80 80 80 80 10",
+        ] {
+            test_read_partial(hex, |reader| {
+                assert_incorrect_varint(reader.read_raw_varint32());
+            });
+            test_read_partial_unbuffered(hex, |reader| {
+                assert_incorrect_varint(reader.read_raw_varint32());
+            });
+        }
+
+        test_read_partial_unbuffered("ff ff ff ff 0f", |reader| {
+            assert_eq!(u32::MAX, reader.read_raw_varint32().unwrap());
+        });
+    }
+
+    #[test]
+    fn test_input_stream_read_raw_varint64_overflow() {
+        fn assert_incorrect_varint(r: crate::Result<u64>) {
+            match *r.unwrap_err().0 {
+                ProtobufError::WireError(WireError::IncorrectVarint) => (),
+                e => panic!("{:?}", e),
+            }
+        }
+
+        for hex in [
+            // 11 bytes
+            "80 80 80 80 80 80 80 80 80 80 00",
+            // u64::MAX + 1
+            "80 80 80 80 80 80 80 80 80 02",
+        ] {
+            test_read_partial(hex, |reader| {
+                assert_incorrect_varint(reader.read_raw_varint64());
+            });
+            test_read_partial_unbuffered(hex, |reader| {
+                assert_incorrect_varint(reader.read_raw_varint64());
+            });
+        }
+
+        test_read_partial_unbuffered("ff ff ff ff ff ff ff ff ff 01", |reader| {
+            assert_eq!(u64::MAX, reader.read_raw_varint64().unwrap());
+        });
+    }
+
     #[test]
     fn test_input_stream_read_raw_varint_unexpected_eof() {
         test_read_partial("96 97", |reader| {
@@ -819,6 +1174,210 @@ mod test {
         });
     }
 
+    #[test]
+    fn test_input_stream_read_raw_varint_or_eof() {
+        fn assert_unexpected_eof<T: Debug>(r: crate::Result<T>) {
+            match *r.unwrap_err().0 {
+                ProtobufError::WireError(WireError::UnexpectedEof) => (),
+                e => panic!("{:?}", e),
+            }
+        }
+
+        // Clean EOF between length-delimited messages.
+        test_read_partial("01 17 02 18 19", |reader| {
+            let mut messages = Vec::new();
+            while let Some(len) = reader.read_raw_varint64_or_eof().unwrap() {
+                messages.push(reader.read_raw_bytes(len as u32).unwrap());
+            }
+            assert_eq!(vec![vec![0x17], vec![0x18, 0x19]], messages);
+        });
+        test_read_partial("", |reader| {
+            assert_eq!(None, reader.read_raw_varint32_or_eof().unwrap());
+        });
+
+        // Truncated inside varint.
+        test_read_partial("01 17 96 97", |reader| {
+            assert_eq!(Some(1), reader.read_raw_varint64_or_eof().unwrap());
+            reader.read_raw_byte().unwrap();
+            assert_unexpected_eof(reader.read_raw_varint64_or_eof());
+        });
+        test_read_partial("96", |reader| {
+            assert_unexpected_eof(reader.read_raw_varint32_or_eof());
+        });
+        test_read_partial_unbuffered("80 80", |reader| {
+            assert_unexpected_eof(reader.read_raw_varint64_or_eof());
+        });
+    }
+
+    #[test]
+    fn test_input_stream_eof() {
+        test_read_partial("", |reader| {
+            assert!(reader.eof().unwrap());
+            assert!(reader.eof().unwrap());
+        });
+        test_read_partial("17 18", |reader| {
+            assert!(!reader.eof().unwrap());
+            // `eof` does not consume input.
+            assert!(!reader.eof().unwrap());
+            assert_eq!(0x17, reader.read_raw_byte().unwrap());
+            assert!(!reader.eof().unwrap());
+            assert_eq!(0x18, reader.read_raw_byte().unwrap());
+            assert!(reader.eof().unwrap());
+        });
+        // Buffer is refilled from the reader one byte at a time.
+        test_read_partial_unbuffered("17 18", |reader| {
+            assert!(!reader.eof().unwrap());
+            assert_eq!(0x17, reader.read_raw_byte().unwrap());
+            assert!(!reader.eof().unwrap());
+            assert_eq!(0x18, reader.read_raw_byte().unwrap());
+            assert!(reader.eof().unwrap());
+            assert_eq!(2, reader.pos());
+        });
+        // EOF at limit.
+        test_read_partial("17 18", |reader| {
+            let old_limit = reader.push_limit(1).unwrap();
+            assert!(!reader.eof().unwrap());
+            reader.read_raw_byte().unwrap();
+            assert!(reader.eof().unwrap());
+            reader.pop_limit(old_limit);
+            assert!(!reader.eof().unwrap());
+        });
+    }
+
+    #[test]
+    fn test_input_stream_with_limit() {
+        test_read("01 02 03 04", |reader| {
+            let r = reader.with_limit(2, |reader| {
+                assert_eq!(2, reader.bytes_until_limit());
+                assert_eq!(1, reader.read_raw_byte()?);
+                // Nested limit cannot be larger than the outer one.
+                assert!(reader.push_limit(2).is_err());
+                reader.with_limit(1, |reader| {
+                    assert_eq!(2, reader.read_raw_byte()?);
+                    assert!(reader.eof()?);
+                    Ok(())
+                })?;
+                reader.read_raw_byte()
+            });
+            assert!(r.is_err());
+            // Limit is restored after error.
+            assert_eq!(2, reader.pos());
+            assert_eq!(3, reader.read_raw_byte().unwrap());
+            assert_eq!(4, reader.read_raw_byte().unwrap());
+        });
+
+        test_read_partial("01 02 03", |reader| {
+            assert!(reader
+                .with_limit(2, |reader| reader.read_raw_bytes(3))
+                .is_err());
+            assert_eq!(u64::MAX - reader.pos(), reader.bytes_until_limit());
+        });
+    }
+
+    #[test]
+    fn test_input_stream_read_packed_fixed32_into() {
+        test_read("0c 01 00 00 00 02 00 00 00 ff ff ff ff", |reader| {
+            let mut buf = [0u32; 4];
+            assert_eq!(3, reader.read_packed_fixed32_into(&mut buf).unwrap());
+            assert_eq!([1, 2, u32::MAX, 0], buf);
+        });
+        test_read("00", |reader| {
+            assert_eq!(0, reader.read_packed_fixed32_into(&mut []).unwrap());
+        });
+        test_read_partial("0c 01 00 00 00 02 00 00 00 03 00 00 00", |reader| {
+            let mut buf = [0u32; 2];
+            match *reader.read_packed_fixed32_into(&mut buf).unwrap_err().0 {
+                ProtobufError::WireError(WireError::PackedFieldTooLong(12, 2)) => {}
+                e => panic!("{:?}", e),
+            }
+            assert_eq!([0, 0], buf);
+        });
+        // Length is not a multiple of element size.
+        test_read_partial("03 01 00 00", |reader| {
+            let mut buf = [0u32; 2];
+            assert!(reader.read_packed_fixed32_into(&mut buf).is_err());
+        });
+    }
+
+    #[test]
+    fn test_input_stream_read_message_into() {
+        // Two `ListValue` messages: `[true]` and `["a", "b"]`.
+        test_read(
+            "04 0a 02 20 01 0c 0a 03 1a 01 61 0a 03 1a 01 62",
+            |reader| {
+                let string_value = |s: &str| {
+                    let mut v = Value::new();
+                    v.set_string_value(s.to_owned());
+                    v
+                };
+                let mut bool_value = Value::new();
+                bool_value.set_bool_value(true);
+
+                let mut list = ListValue::new();
+                list.values.push(string_value("previous"));
+                list.special_fields.mut_unknown_fields().add_varint(100, 17);
+
+                reader.read_message_into(&mut list).unwrap();
+                assert_eq!(vec![bool_value], list.values);
+                assert!(list.special_fields.unknown_fields().iter().next().is_none());
+
+                reader.read_message_into(&mut list).unwrap();
+                assert_eq!(vec![string_value("a"), string_value("b")], list.values);
+            },
+        );
+    }
+
+    #[test]
+    fn test_input_stream_read_message_into_not_initialized() {
+        // `FileDescriptorSet` with `file { options { uninterpreted_option { name { } } } }`,
+        // `name` is missing required fields.
+        test_read_partial("09 0a 07 42 05 ba 3e 02 12 00", |reader| {
+            let mut set = FileDescriptorSet::new();
+            assert!(reader.read_message_into(&mut set).is_err());
+        });
+    }
+
+    #[test]
+    fn test_input_stream_for_each_repeated_message() {
+        let mut list = ListValue::new();
+        for i in 0..100 {
+            let mut v = Value::new();
+            v.set_number_value(i as f64);
+            list.values.push(v);
+        }
+        // Not `values` field, skipped.
+        list.special_fields.mut_unknown_fields().add_varint(2, 17);
+        list.special_fields
+            .mut_unknown_fields()
+            .add_length_delimited(3, vec![1, 2]);
+        let bytes = list.write_to_bytes().unwrap();
+
+        let mut reader = CodedInputStream::from_bytes(&bytes);
+        let mut sum = 0.0;
+        let mut count = 0;
+        reader
+            .for_each_repeated_message(1, |v: &Value| {
+                sum += v.number_value();
+                count += 1;
+            })
+            .unwrap();
+        assert_eq!(100, count);
+        assert_eq!(4950.0, sum);
+        assert!(reader.eof().unwrap());
+    }
+
+    #[test]
+    fn test_input_stream_for_each_repeated_message_truncated() {
+        // `values { bool_value: true }` followed by truncated `values`.
+        test_read_partial("0a 02 20 01 0a 05 20", |reader| {
+            let mut count = 0;
+            assert!(reader
+                .for_each_repeated_message(1, |_: &Value| count += 1)
+                .is_err());
+            assert_eq!(1, count);
+        });
+    }
+
     #[test]
     fn test_input_stream_read_raw_varint_pos() {
         test_read_partial("95 01 98", |reader| {
@@ -842,6 +1401,50 @@ mod test {
         test_read_v("40 d5 ab 68 b3 07 3d 46", 23e29, |is| is.read_double());
     }
 
+    #[test]
+    fn test_input_stream_float_policy() {
+        // NaN, +inf, -inf
+        for hex in [
+            "00 00 00 00 00 00 f8 7f",
+            "00 00 00 00 00 00 f0 7f",
+            "00 00 00 00 00 00 f0 ff",
+        ] {
+            test_read(hex, |is| {
+                assert!(!is.read_double().unwrap().is_finite());
+            });
+            test_read_partial(hex, |is| {
+                is.set_float_policy(FloatPolicy::RejectNonFinite);
+                match *is.read_double().unwrap_err().0 {
+                    ProtobufError::WireError(WireError::NonFiniteFloat(_)) => {}
+                    e => panic!("{:?}", e),
+                }
+            });
+        }
+        test_read_partial("00 00 c0 7f", |is| {
+            is.set_float_policy(FloatPolicy::RejectNonFinite);
+            assert!(is.read_float().is_err());
+        });
+        test_read("00 00 00 00 00 00 f0 3f", |is| {
+            is.set_float_policy(FloatPolicy::RejectNonFinite);
+            assert_eq!(1.0, is.read_double().unwrap());
+        });
+    }
+
+    #[test]
+    fn test_float_policy_message() {
+        let mut m = DoubleValue::new();
+        m.value = f64::NAN;
+        let bytes = m.write_to_bytes().unwrap();
+        assert!(DoubleValue::parse_from_bytes(&bytes)
+            .unwrap()
+            .value
+            .is_nan());
+
+        let mut is = CodedInputStream::from_bytes(&bytes);
+        is.set_float_policy(FloatPolicy::RejectNonFinite);
+        assert!(DoubleValue::parse_from(&mut is).is_err());
+    }
+
     #[test]
     fn test_input_stream_skip_raw_bytes() {
         test_read("", |reader| {
@@ -982,4 +1585,134 @@ mod test {
         );
         assert_eq!("field 3", input.read_string().unwrap());
     }
+
+    #[test]
+    fn test_incorrect_wire_type() {
+        fn assert_incorrect_wire_type<T: Debug>(r: crate::Result<T>, expected: u32) {
+            match *r.unwrap_err().0 {
+                ProtobufError::WireError(WireError::IncorrectWireType(w)) if w == expected => (),
+                e => panic!("{:?}", e),
+            }
+        }
+
+        for (hex, wire_type) in [
+            // Field 1 with wire type 6 and 7.
+            ("0e", 6),
+            ("0f 00", 7),
+            // Inside of skipped unknown group.
+            ("0b 10 01 0e", 6),
+            // Inside of nested message.
+            ("0a 01 0f", 7),
+        ] {
+            assert_incorrect_wire_type(ListValue::parse_from_bytes(&decode_hex(hex)), wire_type);
+        }
+
+        // Fuzzer-style input: many group starts followed by reserved wire type.
+        let mut bytes = vec![0x0b; 1000];
+        bytes.push(0x17);
+        assert_incorrect_wire_type(ListValue::parse_from_bytes(&bytes), 7);
+
+        test_read_partial("0e", |reader| {
+            assert_incorrect_wire_type(reader.read_tag_unpack(), 6);
+        });
+    }
+
+    #[test]
+    fn test_read_tag_or_eof() {
+        let mut d = crate::well_known_types::duration::Duration::new();
+        d.seconds = 1;
+        d.nanos = 2;
+        let bytes = d.write_to_bytes().unwrap();
+
+        let mut is = CodedInputStream::from_bytes(&bytes);
+        let mut fields = Vec::new();
+        while let Some((field_number, wire_type)) = is.read_tag_or_eof().unwrap() {
+            fields.push((field_number, wire_type, is.read_int64().unwrap()));
+        }
+        assert_eq!(
+            vec![(1, WireType::Varint, 1), (2, WireType::Varint, 2)],
+            fields
+        );
+        assert_eq!(None, is.read_tag_or_eof().unwrap());
+
+        // Truncated tag is an error, not EOF.
+        let mut is = CodedInputStream::from_bytes(&[0x80]);
+        assert!(is.read_tag_or_eof().is_err());
+        // Invalid wire type.
+        let mut is = CodedInputStream::from_bytes(&[0x0e]);
+        assert!(is.read_tag_or_eof().is_err());
+    }
+
+    #[test]
+    fn test_read_until_field() {
+        let mut bytes = Vec::new();
+        {
+            let mut os = CodedOutputStream::vec(&mut bytes);
+            os.write_string(1, "route").unwrap();
+            os.write_bytes(2, &[17; 10000]).unwrap();
+            os.write_uint32(3, 30).unwrap();
+            os.flush().unwrap();
+        }
+        // Truncated tail must not be read.
+        bytes.push(0x80);
+
+        let mut is = CodedInputStream::from_bytes(&bytes);
+        assert_eq!(
+            Some(WireType::LengthDelimited),
+            is.read_until_field(1).unwrap()
+        );
+        assert_eq!(1, is.pos());
+        assert_eq!("route", is.read_string().unwrap());
+
+        let mut is = CodedInputStream::from_bytes(&bytes);
+        assert_eq!(Some(WireType::Varint), is.read_until_field(3).unwrap());
+        assert_eq!(30, is.read_uint32().unwrap());
+        assert!(is.read_until_field(4).is_err());
+
+        let mut is = CodedInputStream::from_bytes(&bytes[..bytes.len() - 1]);
+        assert_eq!(None, is.read_until_field(4).unwrap());
+        assert!(is.eof().unwrap());
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn test_read_tokio_chars_interned() {
+        use crate::HashSetStringInterner;
+
+        let mut bytes = Vec::new();
+        {
+            let mut os = CodedOutputStream::vec(&mut bytes);
+            for s in ["foo", "bar", "foo", "foo", "bar", ""] {
+                os.write_string_no_tag(s).unwrap();
+            }
+            os.flush().unwrap();
+        }
+
+        let mut interner = HashSetStringInterner::new();
+        let mut read = Vec::new();
+        {
+            let mut is = CodedInputStream::from_bytes(&bytes);
+            is.set_string_interner(&mut interner);
+            while !is.eof().unwrap() {
+                read.push(is.read_tokio_chars().unwrap());
+            }
+        }
+        let read_str: Vec<&str> = read.iter().map(|c| &**c).collect();
+        assert_eq!(vec!["foo", "bar", "foo", "foo", "bar", ""], read_str);
+        assert_eq!(3, interner.len());
+
+        // Equal strings share storage.
+        assert_eq!(read[0].as_ptr(), read[2].as_ptr());
+        assert_eq!(read[0].as_ptr(), read[3].as_ptr());
+        assert_eq!(read[1].as_ptr(), read[4].as_ptr());
+        assert_ne!(read[0].as_ptr(), read[1].as_ptr());
+
+        // Invalid UTF-8 is still rejected.
+        let mut is = CodedInputStream::from_bytes(&[0x01, 0xff]);
+        is.set_string_interner(&mut interner);
+        match *is.read_tokio_chars().unwrap_err().0 {
+            ProtobufError::Utf8(..) => (),
+            e => panic!("{:?}", e),
+        }
+    }
 }
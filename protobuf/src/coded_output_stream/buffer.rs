@@ -1,6 +1,7 @@
 use std::fmt;
 use std::fmt::Formatter;
 use std::mem::MaybeUninit;
+use std::ptr;
 use std::slice;
 
 use crate::misc::maybe_uninit_write_slice;
@@ -75,9 +76,16 @@ impl OutputBuffer {
         self.pos_within_buf += n;
     }
 
-    #[inline]
-    pub(crate) fn rewind(&mut self) {
-        self.pos_within_buf = 0;
+    /// Remove first `n` filled bytes, moving the rest to the buffer start.
+    pub(crate) fn consume(&mut self, n: usize) {
+        assert!(n <= self.pos_within_buf);
+        let remaining = self.pos_within_buf - n;
+        // SAFETY: both ranges are within filled part of the buffer.
+        unsafe {
+            let ptr = self.buffer as *mut MaybeUninit<u8>;
+            ptr::copy(ptr.add(n), ptr, remaining);
+        }
+        self.pos_within_buf = remaining;
     }
 
     #[inline]
@@ -0,0 +1,27 @@
+use std::fmt;
+use std::hash::Hasher;
+
+/// Hasher updated with bytes written by `CodedOutputStream` to the writer.
+pub(crate) struct Checksum<'a>(&'a mut dyn Hasher);
+
+impl<'a> Checksum<'a> {
+    pub(crate) fn new(hasher: &'a mut dyn Hasher) -> Checksum<'a> {
+        Checksum(hasher)
+    }
+
+    pub(crate) fn update(checksum: &mut Option<Checksum>, bytes: &[u8]) {
+        if let Some(checksum) = checksum {
+            checksum.0.write(bytes);
+        }
+    }
+
+    pub(crate) fn finish(&self) -> u64 {
+        self.0.finish()
+    }
+}
+
+impl<'a> fmt::Debug for Checksum<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Checksum").field(&self.finish()).finish()
+    }
+}
@@ -1,7 +1,9 @@
 mod buffer;
+mod checksum;
 mod output_target;
 pub(crate) mod with;
 
+use std::hash::Hasher;
 use std::io;
 use std::io::Write;
 use std::mem::MaybeUninit;
@@ -10,6 +12,7 @@ use std::slice;
 
 use crate::byteorder::LITTLE_ENDIAN;
 use crate::coded_output_stream::buffer::OutputBuffer;
+use crate::coded_output_stream::checksum::Checksum;
 use crate::coded_output_stream::output_target::OutputTarget;
 use crate::error::ProtobufError;
 use crate::rt::packed::vec_packed_enum_or_unknown_data_size;
@@ -45,6 +48,10 @@ pub struct CodedOutputStream<'a> {
     buffer: OutputBuffer,
     /// Absolute position of the buffer start.
     pos_of_buffer_start: u64,
+    /// Keep data in the buffer instead of failing when writer returns `WouldBlock`.
+    buffer_on_would_block: bool,
+    /// Hasher updated with bytes written to the writer.
+    checksum: Option<Checksum<'a>>,
 }
 
 impl<'a> CodedOutputStream<'a> {
@@ -65,9 +72,68 @@ impl<'a> CodedOutputStream<'a> {
             target: OutputTarget::Write(writer, buffer_storage),
             buffer,
             pos_of_buffer_start: 0,
+            buffer_on_would_block: false,
+            checksum: None,
         }
     }
 
+    /// Construct from given `Write`, updating `hasher` with all bytes
+    /// as they are written to the writer.
+    ///
+    /// This allows computing a checksum of the serialized data
+    /// without a second pass over the output.
+    /// The checksum of the bytes written so far is returned by
+    /// [`checksum`](Self::checksum).
+    ///
+    /// Bytes are passed to the hasher in chunks of unspecified size,
+    /// so the hasher must be streaming: its result must not depend on
+    /// how the input is split into [`write`](Hasher::write) calls.
+    /// `Hasher` does not guarantee that in general, and `DefaultHasher`
+    /// does not provide it; use a CRC or a streaming hash like FNV-1a.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::Hasher;
+    ///
+    /// use protobuf::CodedOutputStream;
+    ///
+    /// /// FNV-1a, result does not depend on how input is split.
+    /// struct Fnv(u64);
+    ///
+    /// impl Hasher for Fnv {
+    ///     fn finish(&self) -> u64 {
+    ///         self.0
+    ///     }
+    ///
+    ///     fn write(&mut self, bytes: &[u8]) {
+    ///         for &b in bytes {
+    ///             self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3);
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut hasher = Fnv(0xcbf29ce484222325);
+    /// let mut bytes = Vec::new();
+    /// let mut os = CodedOutputStream::with_checksum(&mut bytes, &mut hasher);
+    /// os.write_string(1, "hello").unwrap();
+    /// os.write_string(2, "world").unwrap();
+    /// let checksum = os.checksum().unwrap();
+    /// drop(os);
+    ///
+    /// let mut expected = Fnv(0xcbf29ce484222325);
+    /// expected.write(&bytes);
+    /// assert_eq!(expected.finish(), checksum);
+    /// ```
+    pub fn with_checksum(
+        writer: &'a mut dyn Write,
+        hasher: &'a mut dyn Hasher,
+    ) -> CodedOutputStream<'a> {
+        let mut os = CodedOutputStream::new(writer);
+        os.checksum = Some(Checksum::new(hasher));
+        os
+    }
+
     /// `CodedOutputStream` which writes directly to bytes.
     ///
     /// Attempt to write more than bytes capacity results in error.
@@ -80,6 +146,8 @@ impl<'a> CodedOutputStream<'a> {
             target: OutputTarget::Bytes,
             buffer,
             pos_of_buffer_start: 0,
+            buffer_on_would_block: false,
+            checksum: None,
         }
     }
 
@@ -90,9 +158,54 @@ impl<'a> CodedOutputStream<'a> {
             target: OutputTarget::Vec(vec),
             buffer,
             pos_of_buffer_start: 0,
+            buffer_on_would_block: false,
+            checksum: None,
+        }
+    }
+
+    /// `CodedOutputStream` which discards the output and only counts written bytes.
+    ///
+    /// Written byte count is returned by
+    /// [`total_bytes_written`](Self::total_bytes_written),
+    /// it can be used to measure serialized size of a message
+    /// without allocating memory for the whole message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use protobuf::CodedOutputStream;
+    /// use protobuf::Message;
+    /// use protobuf::well_known_types::duration::Duration;
+    ///
+    /// let mut d = Duration::new();
+    /// d.seconds = 1000;
+    /// let mut os = CodedOutputStream::counting();
+    /// d.write_to(&mut os).unwrap();
+    /// assert_eq!(d.compute_size(), os.total_bytes_written());
+    /// ```
+    pub fn counting() -> CodedOutputStream<'static> {
+        let mut buffer_storage = Vec::with_capacity(OUTPUT_STREAM_BUFFER_SIZE);
+        // SAFETY: same as in `new`.
+        let buffer = OutputBuffer::new(buffer_storage.spare_capacity_mut());
+        CodedOutputStream {
+            target: OutputTarget::Counting(buffer_storage),
+            buffer,
+            pos_of_buffer_start: 0,
+            buffer_on_would_block: false,
+            checksum: None,
         }
     }
 
+    /// Discard the buffer content of `Counting` target.
+    fn discard_counted(&mut self) {
+        let storage = match &mut self.target {
+            OutputTarget::Counting(storage) => storage,
+            _ => unreachable!(),
+        };
+        self.pos_of_buffer_start += self.buffer.pos_within_buf() as u64;
+        self.buffer = OutputBuffer::new(storage.spare_capacity_mut());
+    }
+
     pub(crate) fn reserve_additional(
         &mut self,
         additional: u32,
@@ -102,7 +215,7 @@ impl<'a> CodedOutputStream<'a> {
             return Ok(());
         }
         match &mut self.target {
-            OutputTarget::Write(..) => Ok(()),
+            OutputTarget::Write(..) | OutputTarget::Counting(..) => Ok(()),
             OutputTarget::Vec(v) => {
                 let reserve = (additional as usize)
                     .checked_add(self.buffer.pos_within_buf())
@@ -157,18 +270,96 @@ impl<'a> CodedOutputStream<'a> {
                     self.buffer.pos_within_buf() as u64
                 );
             }
-            OutputTarget::Write(..) | OutputTarget::Vec(..) => {
-                panic!("must not be called with Writer or Vec");
+            OutputTarget::Write(..) | OutputTarget::Vec(..) | OutputTarget::Counting(..) => {
+                panic!("must not be called with Writer, Vec or counting stream");
             }
         }
     }
 
+    /// Do not fail when writer returns [`WouldBlock`](io::ErrorKind::WouldBlock)
+    /// while writing data, keep data in the buffer (growing it as needed) instead.
+    ///
+    /// This allows serializing messages to non-blocking writers:
+    /// serialization completes, and then [`flush`](Self::flush) should be called
+    /// until it no longer fails with `WouldBlock`.
+    /// Note that `flush` in destructor panics if data cannot be written.
+    ///
+    /// This option has no effect when writing to `Vec` or bytes.
+    pub fn set_buffer_on_would_block(&mut self, buffer_on_would_block: bool) {
+        self.buffer_on_would_block = buffer_on_would_block;
+    }
+
+    /// Number of bytes written to the underlying writer (or `Vec`).
+    ///
+    /// Other [`total_bytes_written`](Self::total_bytes_written) bytes are buffered.
+    /// When a write to the underlying writer fails, bytes accepted by the writer
+    /// are not written again, and other bytes are kept in the buffer,
+    /// so [`flush`](Self::flush) can be retried, e. g. after `WouldBlock`.
+    /// Except large writes which bypass the buffer: their unwritten bytes
+    /// are kept only if the error is tolerated with
+    /// [`set_buffer_on_would_block`](Self::set_buffer_on_would_block).
+    pub fn bytes_flushed(&self) -> u64 {
+        self.pos_of_buffer_start
+    }
+
+    /// Flush the stream and return the checksum of all bytes written.
+    ///
+    /// # Panics
+    ///
+    /// If the stream was not constructed with [`with_checksum`](Self::with_checksum).
+    pub fn checksum(&mut self) -> crate::Result<u64> {
+        assert!(
+            self.checksum.is_some(),
+            "stream was not constructed with `with_checksum`"
+        );
+        self.flush()?;
+        Ok(self.checksum.as_ref().unwrap().finish())
+    }
+
+    /// Write the buffer to writer, keep bytes not accepted by the writer.
+    fn write_buffer_to_writer(&mut self) -> io::Result<()> {
+        let write = match &mut self.target {
+            OutputTarget::Write(write, _) => write,
+            _ => unreachable!(),
+        };
+        let (written, r) = write_partial(*write, self.buffer.filled());
+        Checksum::update(&mut self.checksum, &self.buffer.filled()[..written]);
+        self.pos_of_buffer_start += written as u64;
+        self.buffer.consume(written);
+        r
+    }
+
+    /// Grow buffer of `Write` target, so it has space for `additional` bytes.
+    fn grow_writer_buffer(&mut self, additional: usize) {
+        let storage = match &mut self.target {
+            OutputTarget::Write(_, storage) => storage,
+            _ => unreachable!(),
+        };
+        if additional <= self.buffer.unfilled_len() {
+            return;
+        }
+        // `buffer` starts at `storage` start, mark filled data
+        // as storage content so it is preserved on reallocation.
+        unsafe { storage.set_len(self.buffer.pos_within_buf()) };
+        storage.reserve(additional);
+        unsafe { storage.set_len(0) };
+        self.buffer
+            .replace_buffer_keep_pos(storage.spare_capacity_mut());
+    }
+
+    fn is_tolerated_write_error(&self, e: &io::Error) -> bool {
+        self.buffer_on_would_block && e.kind() == io::ErrorKind::WouldBlock
+    }
+
     fn refresh_buffer(&mut self) -> crate::Result<()> {
         match self.target {
-            OutputTarget::Write(ref mut write, _) => {
-                write.write_all(self.buffer.filled())?;
-                self.pos_of_buffer_start += self.buffer.pos_within_buf() as u64;
-                self.buffer.rewind();
+            OutputTarget::Write(..) => {
+                if let Err(e) = self.write_buffer_to_writer() {
+                    if !self.is_tolerated_write_error(&e) {
+                        return Err(e.into());
+                    }
+                    self.grow_writer_buffer(1);
+                }
             }
             OutputTarget::Vec(ref mut vec) => unsafe {
                 let vec_len = vec.len();
@@ -178,6 +369,7 @@ impl<'a> CodedOutputStream<'a> {
                 self.pos_of_buffer_start += self.buffer.pos_within_buf() as u64;
                 self.buffer = OutputBuffer::new(vec.spare_capacity_mut());
             },
+            OutputTarget::Counting(..) => self.discard_counted(),
             OutputTarget::Bytes => {
                 return Err(ProtobufError::IoError(io::Error::new(
                     io::ErrorKind::Other,
@@ -207,7 +399,11 @@ impl<'a> CodedOutputStream<'a> {
                 self.buffer = OutputBuffer::new(vec.spare_capacity_mut());
                 Ok(())
             }
-            OutputTarget::Write(..) => self.refresh_buffer(),
+            OutputTarget::Write(..) => Ok(self.write_buffer_to_writer()?),
+            OutputTarget::Counting(..) => {
+                self.discard_counted();
+                Ok(())
+            }
         }
     }
 
@@ -220,7 +416,12 @@ impl<'a> CodedOutputStream<'a> {
         Ok(())
     }
 
-    /// Write bytes
+    /// Write bytes as is, without length prefix.
+    ///
+    /// Together with [`write_tag`](Self::write_tag) and
+    /// [`write_raw_varint64`](Self::write_raw_varint64) this can be used
+    /// to write messages by hand, e. g. length-delimited field is written
+    /// as a tag, a length varint and raw bytes.
     pub fn write_raw_bytes(&mut self, bytes: &[u8]) -> crate::Result<()> {
         if bytes.len() <= self.buffer.unfilled_len() {
             // SAFETY: we've just checked that there's enough space in the buffer.
@@ -230,8 +431,6 @@ impl<'a> CodedOutputStream<'a> {
 
         self.refresh_buffer()?;
 
-        assert!(self.buffer.pos_within_buf() == 0);
-
         if bytes.len() <= self.buffer.unfilled_len() {
             // SAFETY: we've just checked that there's enough space in the buffer.
             unsafe { self.buffer.write_bytes(bytes) };
@@ -243,7 +442,28 @@ impl<'a> CodedOutputStream<'a> {
                 unreachable!();
             }
             OutputTarget::Write(ref mut write, _) => {
-                write.write_all(bytes)?;
+                // Buffer is not empty only if previous write would block.
+                let (written, r) = if self.buffer.pos_within_buf() == 0 {
+                    write_partial(*write, bytes)
+                } else {
+                    (0, Err(io::ErrorKind::WouldBlock.into()))
+                };
+                Checksum::update(&mut self.checksum, &bytes[..written]);
+                self.pos_of_buffer_start += written as u64;
+                if let Err(e) = r {
+                    if !self.is_tolerated_write_error(&e) {
+                        return Err(e.into());
+                    }
+                    // Keep the rest, so the stream can be flushed later.
+                    let rest = &bytes[written..];
+                    self.grow_writer_buffer(rest.len());
+                    // SAFETY: `grow_writer_buffer` reserved enough space.
+                    unsafe { self.buffer.write_bytes(rest) };
+                }
+            }
+            OutputTarget::Counting(..) => {
+                assert!(self.buffer.pos_within_buf() == 0);
+                self.pos_of_buffer_start += bytes.len() as u64;
             }
             OutputTarget::Vec(ref mut vec) => {
                 assert!(self.buffer.pos_within_buf() == 0);
@@ -255,7 +475,8 @@ impl<'a> CodedOutputStream<'a> {
         Ok(())
     }
 
-    /// Write a tag
+    /// Write a field tag: field number and [`WireType`](crate::rt::WireType)
+    /// encoded as varint.
     pub fn write_tag(&mut self, field_number: u32, wire_type: WireType) -> crate::Result<()> {
         self.write_raw_varint32(wire_format::Tag::make(field_number, wire_type).value())
     }
@@ -279,7 +500,7 @@ impl<'a> CodedOutputStream<'a> {
         }
     }
 
-    /// Write varint
+    /// Write varint without a tag.
     pub fn write_raw_varint64(&mut self, value: u64) -> crate::Result<()> {
         if self.buffer.unfilled_len() >= MAX_VARINT_ENCODED_LEN {
             // fast path
@@ -581,6 +802,19 @@ impl<'a> CodedOutputStream<'a> {
         Ok(())
     }
 
+    /// Write message without tag and without length prefix.
+    ///
+    /// The reader must know where the message ends, for example,
+    /// from a length written separately or from the end of the stream.
+    pub fn write_raw_message<M: Message>(&mut self, msg: &M) -> crate::Result<()> {
+        msg.write_to(self)
+    }
+
+    /// Write dynamic message without tag and without length prefix.
+    pub fn write_raw_message_dyn(&mut self, msg: &dyn MessageDyn) -> crate::Result<()> {
+        msg.write_to_dyn(self)
+    }
+
     /// Write `bytes` field
     pub fn write_bytes(&mut self, field_number: u32, bytes: &[u8]) -> crate::Result<()> {
         self.write_tag(field_number, WireType::LengthDelimited)?;
@@ -995,6 +1229,50 @@ impl<'a> CodedOutputStream<'a> {
         self.write_message_no_tag_dyn(msg)?;
         Ok(())
     }
+
+    /// Write `repeated` message field with messages produced by an iterator.
+    ///
+    /// Each message is written with its tag and length prefix as soon as it
+    /// is produced, so the messages do not need to be collected first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use protobuf::well_known_types::struct_::ListValue;
+    /// use protobuf::well_known_types::struct_::Value;
+    /// use protobuf::CodedOutputStream;
+    /// use protobuf::Message;
+    ///
+    /// let mut bytes = Vec::new();
+    /// let mut os = CodedOutputStream::vec(&mut bytes);
+    /// // `values` is field 1 of `ListValue`.
+    /// let values = (0..3).map(|i| {
+    ///     let mut v = Value::new();
+    ///     v.set_number_value(i as f64);
+    ///     v
+    /// });
+    /// os.write_repeated_message_iter(1, values).unwrap();
+    /// os.flush().unwrap();
+    /// drop(os);
+    ///
+    /// let list = ListValue::parse_from_bytes(&bytes).unwrap();
+    /// assert_eq!(3, list.values.len());
+    /// ```
+    pub fn write_repeated_message_iter<M, I>(
+        &mut self,
+        field_number: u32,
+        messages: I,
+    ) -> crate::Result<()>
+    where
+        M: Message,
+        I: IntoIterator<Item = M>,
+    {
+        for msg in messages {
+            self.write_tag(field_number, WireType::LengthDelimited)?;
+            self.write_message_no_tag(&msg)?;
+        }
+        Ok(())
+    }
 }
 
 impl<'a> Write for CodedOutputStream<'a> {
@@ -1008,6 +1286,28 @@ impl<'a> Write for CodedOutputStream<'a> {
     }
 }
 
+/// Like `write_all`, but also return the number of bytes written on error.
+fn write_partial(write: &mut dyn Write, data: &[u8]) -> (usize, io::Result<()>) {
+    let mut written = 0;
+    while written < data.len() {
+        match write.write(&data[written..]) {
+            Ok(0) => {
+                return (
+                    written,
+                    Err(io::Error::new(
+                        io::ErrorKind::WriteZero,
+                        "failed to write whole buffer",
+                    )),
+                );
+            }
+            Ok(n) => written += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return (written, Err(e)),
+        }
+    }
+    (written, Ok(()))
+}
+
 impl<'a> Drop for CodedOutputStream<'a> {
     fn drop(&mut self) {
         // This may panic
@@ -1022,6 +1322,8 @@ mod test {
     use super::*;
     use crate::hex::decode_hex;
     use crate::hex::encode_hex;
+    use crate::well_known_types::duration::Duration;
+    use crate::CodedInputStream;
 
     fn test_write<F>(expected: &str, mut gen: F)
     where
@@ -1065,6 +1367,52 @@ mod test {
             r.drain(..7);
             assert_eq!(encode_hex(&expected_bytes), encode_hex(&r));
         }
+
+        // count bytes
+        {
+            let mut os = CodedOutputStream::counting();
+            gen(&mut os).unwrap();
+            os.flush().unwrap();
+            assert_eq!(expected_bytes.len() as u64, os.total_bytes_written());
+        }
+    }
+
+    #[test]
+    fn test_counting_equals_compute_size() {
+        let mut duration = Duration::new();
+        duration.seconds = 1_000_000_000;
+        duration.nanos = -1;
+        let mut file_descriptor_proto = crate::descriptor::file_descriptor().proto().clone();
+        let messages: Vec<&mut dyn MessageDyn> = vec![
+            &mut duration,
+            // Larger than the buffer.
+            &mut file_descriptor_proto,
+        ];
+        for m in messages {
+            let mut os = CodedOutputStream::counting();
+            m.write_to_dyn(&mut os).unwrap();
+            assert_eq!(m.compute_size_dyn(), os.total_bytes_written());
+            assert_eq!(
+                m.write_to_bytes_dyn().unwrap().len() as u64,
+                os.total_bytes_written()
+            );
+        }
+
+        let mut os = CodedOutputStream::counting();
+        Duration::new().write_to(&mut os).unwrap();
+        assert_eq!(0, os.total_bytes_written());
+    }
+
+    #[test]
+    fn test_counting_length_delimited() {
+        let mut duration = Duration::new();
+        duration.seconds = 1;
+        assert_eq!(2, duration.compute_size());
+        let mut os = CodedOutputStream::counting();
+        // Tag, length and message, then length and message.
+        os.write_message(1, &duration).unwrap();
+        duration.write_length_delimited_to(&mut os).unwrap();
+        assert_eq!(4 + 3, os.total_bytes_written());
     }
 
     #[test]
@@ -1222,4 +1570,285 @@ mod test {
             assert_eq!((i + 1) * 3, stream.total_bytes_written());
         }
     }
+
+    #[test]
+    fn test_write_message_no_tag_read_back() {
+        let mut d = Duration::new();
+        d.seconds = 1;
+        d.nanos = 2;
+
+        test_write("04 08 01 10 02", |os| os.write_message_no_tag(&d));
+        test_write("04 08 01 10 02", |os| os.write_message_no_tag_dyn(&d));
+
+        let mut bytes = Vec::new();
+        {
+            let mut os = CodedOutputStream::vec(&mut bytes);
+            os.write_tag(2, WireType::LengthDelimited).unwrap();
+            os.write_message_no_tag(&d).unwrap();
+            os.flush().unwrap();
+        }
+        let mut is = CodedInputStream::from_bytes(&bytes);
+        assert_eq!(Some(2 << 3 | 2), is.read_raw_tag_or_eof().unwrap());
+        assert_eq!(d, is.read_message::<Duration>().unwrap());
+        assert!(is.eof().unwrap());
+    }
+
+    #[test]
+    fn test_write_raw_message() {
+        let mut d = Duration::new();
+        d.seconds = 1;
+        d.nanos = 2;
+
+        test_write("08 01 10 02", |os| os.write_raw_message(&d));
+        test_write("08 01 10 02", |os| os.write_raw_message_dyn(&d));
+
+        // Length prefix written manually.
+        let mut bytes = Vec::new();
+        {
+            let mut os = CodedOutputStream::vec(&mut bytes);
+            os.write_raw_varint32(d.compute_size() as u32).unwrap();
+            os.write_raw_message(&d).unwrap();
+            os.flush().unwrap();
+        }
+        let mut is = CodedInputStream::from_bytes(&bytes);
+        assert_eq!(d, is.read_message::<Duration>().unwrap());
+    }
+
+    /// Writer which accepts at most `max_write` bytes per call
+    /// and returns `WouldBlock` every other call.
+    struct WouldBlockWrite {
+        data: Vec<u8>,
+        max_write: usize,
+        block: bool,
+    }
+
+    impl Write for WouldBlockWrite {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.block = !self.block;
+            if self.block {
+                return Err(io::ErrorKind::WouldBlock.into());
+            }
+            let n = buf.len().min(self.max_write);
+            self.data.extend_from_slice(&buf[..n]);
+            Ok(n)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn flush_until_written(os: &mut CodedOutputStream) {
+        loop {
+            match os.flush() {
+                Ok(()) => return,
+                Err(e) => match *e.0 {
+                    ProtobufError::IoError(e) if e.kind() == io::ErrorKind::WouldBlock => {}
+                    e => panic!("{:?}", e),
+                },
+            }
+        }
+    }
+
+    #[test]
+    fn test_flush_retry_after_would_block() {
+        let mut w = WouldBlockWrite {
+            data: Vec::new(),
+            max_write: 3,
+            block: false,
+        };
+        {
+            let mut os = CodedOutputStream::new(&mut w);
+            os.write_raw_bytes(b"hello").unwrap();
+            assert_eq!(0, os.bytes_flushed());
+            assert!(os.flush().is_err());
+            assert_eq!(0, os.bytes_flushed());
+            // Partial write, then `WouldBlock`.
+            assert!(os.flush().is_err());
+            assert_eq!(3, os.bytes_flushed());
+            flush_until_written(&mut os);
+            assert_eq!(5, os.bytes_flushed());
+            assert_eq!(5, os.total_bytes_written());
+        }
+        assert_eq!(b"hello", &w.data[..]);
+    }
+
+    #[test]
+    fn test_buffer_on_would_block() {
+        let mut list = crate::well_known_types::struct_::ListValue::new();
+        for i in 0..3000 {
+            let mut v = crate::well_known_types::struct_::Value::new();
+            v.set_string_value(format!("value {}", i));
+            list.values.push(v);
+        }
+        // Large bytes are written directly to the writer.
+        let large = vec![17; 3 * OUTPUT_STREAM_BUFFER_SIZE];
+
+        let mut w = WouldBlockWrite {
+            data: Vec::new(),
+            max_write: 1000,
+            block: false,
+        };
+        {
+            let mut os = CodedOutputStream::new(&mut w);
+            os.set_buffer_on_would_block(true);
+            list.write_to_with_cached_sizes(&mut os).unwrap();
+            os.write_bytes(1, &large).unwrap();
+            list.write_to_with_cached_sizes(&mut os).unwrap();
+            flush_until_written(&mut os);
+            assert_eq!(os.total_bytes_written(), os.bytes_flushed());
+        }
+
+        let mut expected = Vec::new();
+        {
+            let mut os = CodedOutputStream::vec(&mut expected);
+            list.write_to_with_cached_sizes(&mut os).unwrap();
+            os.write_bytes(1, &large).unwrap();
+            list.write_to_with_cached_sizes(&mut os).unwrap();
+            os.flush().unwrap();
+        }
+        assert_eq!(expected, w.data);
+    }
+
+    #[test]
+    fn test_would_block_error_by_default() {
+        let mut w = WouldBlockWrite {
+            data: Vec::new(),
+            max_write: 1000,
+            block: false,
+        };
+        let large = vec![17; 3 * OUTPUT_STREAM_BUFFER_SIZE];
+        let mut os = CodedOutputStream::new(&mut w);
+        match *os.write_raw_bytes(&large).unwrap_err().0 {
+            ProtobufError::IoError(e) => assert_eq!(io::ErrorKind::WouldBlock, e.kind()),
+            e => panic!("{:?}", e),
+        }
+        // Not written bytes are not buffered, so caller knows where to resume.
+        assert_eq!(0, os.bytes_flushed());
+        os.flush().unwrap();
+        drop(os);
+        assert!(w.data.is_empty());
+    }
+
+    struct BrokenPipeWrite;
+
+    impl Write for BrokenPipeWrite {
+        fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+            Err(io::ErrorKind::BrokenPipe.into())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_fatal_error_not_buffered() {
+        let large = vec![17; 3 * OUTPUT_STREAM_BUFFER_SIZE];
+        for buffer_on_would_block in [false, true] {
+            let mut w = BrokenPipeWrite;
+            let mut os = CodedOutputStream::new(&mut w);
+            os.set_buffer_on_would_block(buffer_on_would_block);
+            match *os.write_raw_bytes(&large).unwrap_err().0 {
+                ProtobufError::IoError(e) => assert_eq!(io::ErrorKind::BrokenPipe, e.kind()),
+                e => panic!("{:?}", e),
+            }
+            match &os.target {
+                OutputTarget::Write(_, storage) => assert!(storage.capacity() < large.len()),
+                _ => unreachable!(),
+            }
+            // Nothing to retry, so drop does not panic.
+            drop(os);
+        }
+    }
+
+    /// FNV-1a, which does not depend on how input is split into chunks.
+    #[derive(Default)]
+    struct Fnv(u64);
+
+    impl Hasher for Fnv {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            if self.0 == 0 {
+                self.0 = 0xcbf29ce484222325;
+            }
+            for &b in bytes {
+                self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_with_checksum() {
+        let message = Duration {
+            seconds: 1000,
+            nanos: 17,
+            ..Default::default()
+        };
+        // Large bytes are written directly to the writer.
+        let large = vec![17; 3 * OUTPUT_STREAM_BUFFER_SIZE];
+
+        let mut w = WouldBlockWrite {
+            data: Vec::new(),
+            max_write: 1000,
+            block: false,
+        };
+        let mut hasher = Fnv::default();
+        let checksum;
+        {
+            let mut os = CodedOutputStream::with_checksum(&mut w, &mut hasher);
+            os.set_buffer_on_would_block(true);
+            for _ in 0..1000 {
+                os.write_message(1, &message).unwrap();
+            }
+            os.write_bytes(2, &large).unwrap();
+            os.write_message(1, &message).unwrap();
+            flush_until_written(&mut os);
+            checksum = os.checksum().unwrap();
+        }
+        assert_eq!(checksum, hasher.finish());
+
+        let mut expected = Fnv::default();
+        expected.write(&w.data);
+        assert_eq!(expected.finish(), checksum);
+    }
+
+    #[test]
+    #[should_panic(expected = "not constructed with `with_checksum`")]
+    fn test_checksum_without_hasher() {
+        let mut v = Vec::new();
+        let mut os = CodedOutputStream::vec(&mut v);
+        let _ = os.checksum();
+    }
+
+    #[test]
+    fn test_write_repeated_message_iter() {
+        use crate::well_known_types::struct_::ListValue;
+        use crate::well_known_types::struct_::Value;
+
+        let values = || {
+            (0..10000).map(|i| {
+                let mut v = Value::new();
+                v.set_string_value(format!("value {}", i));
+                v
+            })
+        };
+
+        let mut bytes = Vec::new();
+        {
+            let mut os = CodedOutputStream::new(&mut bytes as &mut dyn Write);
+            os.write_repeated_message_iter(1, values()).unwrap();
+            os.flush().unwrap();
+        }
+
+        let list = ListValue::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(values().collect::<Vec<_>>(), list.values);
+
+        let mut expected = ListValue::new();
+        expected.values = values().collect();
+        assert_eq!(expected.write_to_bytes().unwrap(), bytes);
+    }
 }
@@ -11,6 +11,9 @@ pub(crate) enum OutputTarget<'a> {
     /// Lifetime parameter of `CodedOutputStream` guarantees the buffer is valid
     /// during the lifetime of `CodedOutputStream`.
     Bytes,
+    /// Output is discarded, only the byte count is kept.
+    /// The vec is the storage for the buffer, like in `Write`.
+    Counting(Vec<u8>),
 }
 
 impl<'a> fmt::Debug for OutputTarget<'a> {
@@ -27,6 +30,7 @@ impl<'a> fmt::Debug for OutputTarget<'a> {
                 .field("cap", &vec.capacity())
                 .finish_non_exhaustive(),
             OutputTarget::Bytes => f.debug_tuple("Bytes").finish(),
+            OutputTarget::Counting(..) => f.debug_tuple("Counting").finish_non_exhaustive(),
         }
     }
 }
@@ -100,6 +100,16 @@ impl crate::Message for FileDescriptorSet {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        for value in &self.file {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         for v in &self.file {
             crate::rt::write_message_field_with_cached_size(1, v, os)?;
@@ -527,6 +537,45 @@ impl crate::Message for FileDescriptorProto {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        if let Some(v) = self.name.as_ref() {
+            my_size += 1 + 5 + v.len();
+        }
+        if let Some(v) = self.package.as_ref() {
+            my_size += 1 + 5 + v.len();
+        }
+        for value in &self.dependency {
+            my_size += 1 + 5 + value.len();
+        };
+        my_size += 11 * self.public_dependency.len();
+        my_size += 11 * self.weak_dependency.len();
+        for value in &self.message_type {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        for value in &self.enum_type {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        for value in &self.service {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        for value in &self.extension {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        if let Some(v) = self.options.as_ref() {
+            my_size += 1 + 5 + v.estimated_size();
+        }
+        if let Some(v) = self.source_code_info.as_ref() {
+            my_size += 1 + 5 + v.estimated_size();
+        }
+        if let Some(v) = self.syntax.as_ref() {
+            my_size += 1 + 5 + v.len();
+        }
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         if let Some(v) = self.name.as_ref() {
             os.write_string(1, v)?;
@@ -908,6 +957,43 @@ impl crate::Message for DescriptorProto {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        if let Some(v) = self.name.as_ref() {
+            my_size += 1 + 5 + v.len();
+        }
+        for value in &self.field {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        for value in &self.extension {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        for value in &self.nested_type {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        for value in &self.enum_type {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        for value in &self.extension_range {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        for value in &self.oneof_decl {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        if let Some(v) = self.options.as_ref() {
+            my_size += 1 + 5 + v.estimated_size();
+        }
+        for value in &self.reserved_range {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        for value in &self.reserved_name {
+            my_size += 1 + 5 + value.len();
+        };
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         if let Some(v) = self.name.as_ref() {
             os.write_string(1, v)?;
@@ -1147,6 +1233,22 @@ pub mod descriptor_proto {
             my_size
         }
 
+        #[allow(unused_variables)]
+        fn estimated_size(&self) -> usize {
+            let mut my_size = 0;
+            if let Some(v) = self.start {
+                my_size += 11;
+            }
+            if let Some(v) = self.end {
+                my_size += 11;
+            }
+            if let Some(v) = self.options.as_ref() {
+                my_size += 1 + 5 + v.estimated_size();
+            }
+            my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+            my_size
+        }
+
         fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
             if let Some(v) = self.start {
                 os.write_int32(1, v)?;
@@ -1333,6 +1435,19 @@ pub mod descriptor_proto {
             my_size
         }
 
+        #[allow(unused_variables)]
+        fn estimated_size(&self) -> usize {
+            let mut my_size = 0;
+            if let Some(v) = self.start {
+                my_size += 11;
+            }
+            if let Some(v) = self.end {
+                my_size += 11;
+            }
+            my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+            my_size
+        }
+
         fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
             if let Some(v) = self.start {
                 os.write_int32(1, v)?;
@@ -1468,6 +1583,16 @@ impl crate::Message for ExtensionRangeOptions {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        for value in &self.uninterpreted_option {
+            my_size += 2 + 5 + value.estimated_size();
+        };
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         for v in &self.uninterpreted_option {
             crate::rt::write_message_field_with_cached_size(999, v, os)?;
@@ -2049,6 +2174,46 @@ impl crate::Message for FieldDescriptorProto {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        if let Some(v) = self.name.as_ref() {
+            my_size += 1 + 5 + v.len();
+        }
+        if let Some(v) = self.number {
+            my_size += 11;
+        }
+        if let Some(v) = self.label {
+            my_size += 11;
+        }
+        if let Some(v) = self.type_ {
+            my_size += 11;
+        }
+        if let Some(v) = self.type_name.as_ref() {
+            my_size += 1 + 5 + v.len();
+        }
+        if let Some(v) = self.extendee.as_ref() {
+            my_size += 1 + 5 + v.len();
+        }
+        if let Some(v) = self.default_value.as_ref() {
+            my_size += 1 + 5 + v.len();
+        }
+        if let Some(v) = self.oneof_index {
+            my_size += 11;
+        }
+        if let Some(v) = self.json_name.as_ref() {
+            my_size += 1 + 5 + v.len();
+        }
+        if let Some(v) = self.options.as_ref() {
+            my_size += 1 + 5 + v.estimated_size();
+        }
+        if let Some(v) = self.proto3_optional {
+            my_size += 3;
+        }
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         if let Some(v) = self.name.as_ref() {
             os.write_string(1, v)?;
@@ -2514,6 +2679,19 @@ impl crate::Message for OneofDescriptorProto {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        if let Some(v) = self.name.as_ref() {
+            my_size += 1 + 5 + v.len();
+        }
+        if let Some(v) = self.options.as_ref() {
+            my_size += 1 + 5 + v.estimated_size();
+        }
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         if let Some(v) = self.name.as_ref() {
             os.write_string(1, v)?;
@@ -2753,6 +2931,28 @@ impl crate::Message for EnumDescriptorProto {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        if let Some(v) = self.name.as_ref() {
+            my_size += 1 + 5 + v.len();
+        }
+        for value in &self.value {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        if let Some(v) = self.options.as_ref() {
+            my_size += 1 + 5 + v.estimated_size();
+        }
+        for value in &self.reserved_range {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        for value in &self.reserved_name {
+            my_size += 1 + 5 + value.len();
+        };
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         if let Some(v) = self.name.as_ref() {
             os.write_string(1, v)?;
@@ -2954,6 +3154,19 @@ pub mod enum_descriptor_proto {
             my_size
         }
 
+        #[allow(unused_variables)]
+        fn estimated_size(&self) -> usize {
+            let mut my_size = 0;
+            if let Some(v) = self.start {
+                my_size += 11;
+            }
+            if let Some(v) = self.end {
+                my_size += 11;
+            }
+            my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+            my_size
+        }
+
         fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
             if let Some(v) = self.start {
                 os.write_int32(1, v)?;
@@ -3170,6 +3383,22 @@ impl crate::Message for EnumValueDescriptorProto {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        if let Some(v) = self.name.as_ref() {
+            my_size += 1 + 5 + v.len();
+        }
+        if let Some(v) = self.number {
+            my_size += 11;
+        }
+        if let Some(v) = self.options.as_ref() {
+            my_size += 1 + 5 + v.estimated_size();
+        }
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         if let Some(v) = self.name.as_ref() {
             os.write_string(1, v)?;
@@ -3377,6 +3606,22 @@ impl crate::Message for ServiceDescriptorProto {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        if let Some(v) = self.name.as_ref() {
+            my_size += 1 + 5 + v.len();
+        }
+        for value in &self.method {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        if let Some(v) = self.options.as_ref() {
+            my_size += 1 + 5 + v.estimated_size();
+        }
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         if let Some(v) = self.name.as_ref() {
             os.write_string(1, v)?;
@@ -3731,6 +3976,31 @@ impl crate::Message for MethodDescriptorProto {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        if let Some(v) = self.name.as_ref() {
+            my_size += 1 + 5 + v.len();
+        }
+        if let Some(v) = self.input_type.as_ref() {
+            my_size += 1 + 5 + v.len();
+        }
+        if let Some(v) = self.output_type.as_ref() {
+            my_size += 1 + 5 + v.len();
+        }
+        if let Some(v) = self.options.as_ref() {
+            my_size += 1 + 5 + v.estimated_size();
+        }
+        if let Some(v) = self.client_streaming {
+            my_size += 2;
+        }
+        if let Some(v) = self.server_streaming {
+            my_size += 2;
+        }
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         if let Some(v) = self.name.as_ref() {
             os.write_string(1, v)?;
@@ -4760,6 +5030,76 @@ impl crate::Message for FileOptions {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        if let Some(v) = self.java_package.as_ref() {
+            my_size += 1 + 5 + v.len();
+        }
+        if let Some(v) = self.java_outer_classname.as_ref() {
+            my_size += 1 + 5 + v.len();
+        }
+        if let Some(v) = self.java_multiple_files {
+            my_size += 2;
+        }
+        if let Some(v) = self.java_generate_equals_and_hash {
+            my_size += 3;
+        }
+        if let Some(v) = self.java_string_check_utf8 {
+            my_size += 3;
+        }
+        if let Some(v) = self.optimize_for {
+            my_size += 11;
+        }
+        if let Some(v) = self.go_package.as_ref() {
+            my_size += 1 + 5 + v.len();
+        }
+        if let Some(v) = self.cc_generic_services {
+            my_size += 3;
+        }
+        if let Some(v) = self.java_generic_services {
+            my_size += 3;
+        }
+        if let Some(v) = self.py_generic_services {
+            my_size += 3;
+        }
+        if let Some(v) = self.php_generic_services {
+            my_size += 3;
+        }
+        if let Some(v) = self.deprecated {
+            my_size += 3;
+        }
+        if let Some(v) = self.cc_enable_arenas {
+            my_size += 3;
+        }
+        if let Some(v) = self.objc_class_prefix.as_ref() {
+            my_size += 2 + 5 + v.len();
+        }
+        if let Some(v) = self.csharp_namespace.as_ref() {
+            my_size += 2 + 5 + v.len();
+        }
+        if let Some(v) = self.swift_prefix.as_ref() {
+            my_size += 2 + 5 + v.len();
+        }
+        if let Some(v) = self.php_class_prefix.as_ref() {
+            my_size += 2 + 5 + v.len();
+        }
+        if let Some(v) = self.php_namespace.as_ref() {
+            my_size += 2 + 5 + v.len();
+        }
+        if let Some(v) = self.php_metadata_namespace.as_ref() {
+            my_size += 2 + 5 + v.len();
+        }
+        if let Some(v) = self.ruby_package.as_ref() {
+            my_size += 2 + 5 + v.len();
+        }
+        for value in &self.uninterpreted_option {
+            my_size += 2 + 5 + value.estimated_size();
+        };
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         if let Some(v) = self.java_package.as_ref() {
             os.write_string(1, v)?;
@@ -5200,6 +5540,28 @@ impl crate::Message for MessageOptions {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        if let Some(v) = self.message_set_wire_format {
+            my_size += 2;
+        }
+        if let Some(v) = self.no_standard_descriptor_accessor {
+            my_size += 2;
+        }
+        if let Some(v) = self.deprecated {
+            my_size += 2;
+        }
+        if let Some(v) = self.map_entry {
+            my_size += 2;
+        }
+        for value in &self.uninterpreted_option {
+            my_size += 2 + 5 + value.estimated_size();
+        };
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         if let Some(v) = self.message_set_wire_format {
             os.write_bool(1, v)?;
@@ -5600,6 +5962,34 @@ impl crate::Message for FieldOptions {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        if let Some(v) = self.ctype {
+            my_size += 11;
+        }
+        if let Some(v) = self.packed {
+            my_size += 2;
+        }
+        if let Some(v) = self.jstype {
+            my_size += 11;
+        }
+        if let Some(v) = self.lazy {
+            my_size += 2;
+        }
+        if let Some(v) = self.deprecated {
+            my_size += 2;
+        }
+        if let Some(v) = self.weak {
+            my_size += 2;
+        }
+        for value in &self.uninterpreted_option {
+            my_size += 2 + 5 + value.estimated_size();
+        };
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         if let Some(v) = self.ctype {
             os.write_enum(1, crate::EnumOrUnknown::value(&v))?;
@@ -5896,6 +6286,16 @@ impl crate::Message for OneofOptions {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        for value in &self.uninterpreted_option {
+            my_size += 2 + 5 + value.estimated_size();
+        };
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         for v in &self.uninterpreted_option {
             crate::rt::write_message_field_with_cached_size(999, v, os)?;
@@ -6095,6 +6495,22 @@ impl crate::Message for EnumOptions {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        if let Some(v) = self.allow_alias {
+            my_size += 2;
+        }
+        if let Some(v) = self.deprecated {
+            my_size += 2;
+        }
+        for value in &self.uninterpreted_option {
+            my_size += 2 + 5 + value.estimated_size();
+        };
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         if let Some(v) = self.allow_alias {
             os.write_bool(2, v)?;
@@ -6270,6 +6686,19 @@ impl crate::Message for EnumValueOptions {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        if let Some(v) = self.deprecated {
+            my_size += 2;
+        }
+        for value in &self.uninterpreted_option {
+            my_size += 2 + 5 + value.estimated_size();
+        };
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         if let Some(v) = self.deprecated {
             os.write_bool(1, v)?;
@@ -6440,6 +6869,19 @@ impl crate::Message for ServiceOptions {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        if let Some(v) = self.deprecated {
+            my_size += 3;
+        }
+        for value in &self.uninterpreted_option {
+            my_size += 2 + 5 + value.estimated_size();
+        };
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         if let Some(v) = self.deprecated {
             os.write_bool(33, v)?;
@@ -6645,6 +7087,22 @@ impl crate::Message for MethodOptions {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        if let Some(v) = self.deprecated {
+            my_size += 3;
+        }
+        if let Some(v) = self.idempotency_level {
+            my_size += 12;
+        }
+        for value in &self.uninterpreted_option {
+            my_size += 2 + 5 + value.estimated_size();
+        };
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         if let Some(v) = self.deprecated {
             os.write_bool(33, v)?;
@@ -7107,6 +7565,34 @@ impl crate::Message for UninterpretedOption {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        for value in &self.name {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        if let Some(v) = self.identifier_value.as_ref() {
+            my_size += 1 + 5 + v.len();
+        }
+        if let Some(v) = self.positive_int_value {
+            my_size += 11;
+        }
+        if let Some(v) = self.negative_int_value {
+            my_size += 11;
+        }
+        if let Some(v) = self.double_value {
+            my_size += 9;
+        }
+        if let Some(v) = self.string_value.as_ref() {
+            my_size += 1 + 5 + v.len();
+        }
+        if let Some(v) = self.aggregate_value.as_ref() {
+            my_size += 1 + 5 + v.len();
+        }
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         for v in &self.name {
             crate::rt::write_message_field_with_cached_size(2, v, os)?;
@@ -7340,6 +7826,19 @@ pub mod uninterpreted_option {
             my_size
         }
 
+        #[allow(unused_variables)]
+        fn estimated_size(&self) -> usize {
+            let mut my_size = 0;
+            if let Some(v) = self.name_part.as_ref() {
+                my_size += 1 + 5 + v.len();
+            }
+            if let Some(v) = self.is_extension {
+                my_size += 2;
+            }
+            my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+            my_size
+        }
+
         fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
             if let Some(v) = self.name_part.as_ref() {
                 os.write_string(1, v)?;
@@ -7471,6 +7970,16 @@ impl crate::Message for SourceCodeInfo {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        for value in &self.location {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         for v in &self.location {
             crate::rt::write_message_field_with_cached_size(1, v, os)?;
@@ -7726,6 +8235,24 @@ pub mod source_code_info {
             my_size
         }
 
+        #[allow(unused_variables)]
+        fn estimated_size(&self) -> usize {
+            let mut my_size = 0;
+            my_size += 1 + 5 + 10 * self.path.len();
+            my_size += 1 + 5 + 10 * self.span.len();
+            if let Some(v) = self.leading_comments.as_ref() {
+                my_size += 1 + 5 + v.len();
+            }
+            if let Some(v) = self.trailing_comments.as_ref() {
+                my_size += 1 + 5 + v.len();
+            }
+            for value in &self.leading_detached_comments {
+                my_size += 1 + 5 + value.len();
+            };
+            my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+            my_size
+        }
+
         fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
             os.write_repeated_packed_int32(1, &self.path)?;
             os.write_repeated_packed_int32(2, &self.span)?;
@@ -7871,6 +8398,16 @@ impl crate::Message for GeneratedCodeInfo {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        for value in &self.annotation {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         for v in &self.annotation {
             crate::rt::write_message_field_with_cached_size(1, v, os)?;
@@ -8117,6 +8654,23 @@ pub mod generated_code_info {
             my_size
         }
 
+        #[allow(unused_variables)]
+        fn estimated_size(&self) -> usize {
+            let mut my_size = 0;
+            my_size += 1 + 5 + 10 * self.path.len();
+            if let Some(v) = self.source_file.as_ref() {
+                my_size += 1 + 5 + v.len();
+            }
+            if let Some(v) = self.begin {
+                my_size += 11;
+            }
+            if let Some(v) = self.end {
+                my_size += 11;
+            }
+            my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+            my_size
+        }
+
         fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
             os.write_repeated_packed_int32(1, &self.path)?;
             if let Some(v) = self.source_file.as_ref() {
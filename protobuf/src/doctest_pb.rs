@@ -78,6 +78,13 @@ impl crate::Message for MyMessage {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
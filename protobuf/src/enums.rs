@@ -26,3 +26,34 @@ pub trait Enum: Eq + Sized + Copy + fmt::Debug + Default + Send + Sync + 'static
     /// All enum values for enum type.
     const VALUES: &'static [Self] = &[];
 }
+
+/// Error returned from `TryFrom<&str>` implementation for enums
+/// generated with `enum_try_from_str` codegen option
+/// when the string is not a name of any enum value.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("unknown value name {:?} of enum `{}`", .value, .enum_name)]
+pub struct EnumFromStrError {
+    enum_name: &'static str,
+    value: String,
+}
+
+impl EnumFromStrError {
+    /// Used by generated code.
+    #[doc(hidden)]
+    pub fn new(enum_name: &'static str, value: &str) -> EnumFromStrError {
+        EnumFromStrError {
+            enum_name,
+            value: value.to_owned(),
+        }
+    }
+
+    /// Full protobuf name of the enum.
+    pub fn enum_name(&self) -> &str {
+        self.enum_name
+    }
+
+    /// String which failed to convert.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
@@ -2,6 +2,7 @@ use std::io;
 use std::str;
 
 use crate::reflect::error::ReflectError;
+use crate::text_format;
 use crate::wire_format::WireType;
 
 /// [`Result`] alias for [`Error`].
@@ -17,6 +18,8 @@ pub(crate) enum WireError {
     UnexpectedWireType(WireType),
     #[error("Incorrect tag")]
     IncorrectTag(u32),
+    #[error("Incorrect wire type {0}")]
+    IncorrectWireType(u32),
     #[error("Incorrect varint")]
     IncorrectVarint,
     #[error("Invalid UTF-8 sequence")]
@@ -34,10 +37,14 @@ pub(crate) enum WireError {
     LimitIncrease,
     #[error("Encoded message size {0} is too large")]
     MessageTooLarge(u64),
-    #[error("Value too large for u32: {}", .0)]
-    U32Overflow(u64),
     #[error("Value too large for i32: {}", .0)]
     I32Overflow(i64),
+    #[error("Packed field of {0} bytes does not fit into buffer of {1} elements")]
+    PackedFieldTooLong(u64, usize),
+    #[error("{0} trailing bytes after length-delimited message")]
+    TrailingBytes(usize),
+    #[error("Non-finite floating point value: {0}")]
+    NonFiniteFloat(f64),
 }
 
 /// Generic protobuf error
@@ -51,6 +58,9 @@ pub(crate) enum ProtobufError {
     WireError(#[from] WireError),
     #[error(transparent)]
     Reflect(#[from] ReflectError),
+    /// Text format parse error (from generated `FromStr`).
+    #[error("Text format parse error: {0}")]
+    TextFormat(#[from] text_format::ParseError),
     /// Protocol contains a string which is not valid UTF-8 string
     #[error("UTF-8 decode error")]
     Utf8(
@@ -70,6 +80,24 @@ pub(crate) enum ProtobufError {
     /// Group field type not implemented.
     #[error("Group field is not supported")]
     GroupIsNotImplemented,
+    /// Required field is not set.
+    #[error("Required field `{0}` is not set")]
+    RequiredFieldNotSet(String),
+    /// Message type of `Any` is not found in provided files.
+    #[error("Message type for `Any` with type URL `{0}` is not found")]
+    AnyTypeNotFound(String),
+    /// Error with description added by [`ProtobufResultExt::context`].
+    #[error("{0}: {1}")]
+    Context(&'static str, #[source] Error),
+    /// `Timestamp` cannot be converted to `chrono::DateTime`.
+    #[cfg(feature = "with-chrono")]
+    #[error("Timestamp is malformed or outside of chrono::DateTime range: {0}s {1}ns")]
+    TimestampOutOfRange(i64, i32),
+    /// Error in nested message with path to the field,
+    /// added by code generated with `log_parse_errors` option.
+    #[cfg(feature = "with-log")]
+    #[error("{0}: {1}")]
+    FieldPath(String, #[source] Error),
 }
 
 /// Error type for protobuf operations.
@@ -98,6 +126,37 @@ impl From<ReflectError> for Error {
     }
 }
 
+impl From<text_format::ParseError> for Error {
+    #[cold]
+    fn from(e: text_format::ParseError) -> Self {
+        Self(Box::new(ProtobufError::TextFormat(e)))
+    }
+}
+
+/// Extension methods for [`Result`].
+pub trait ProtobufResultExt<T> {
+    /// Wrap the error with a description of what was being done,
+    /// for example, which of the messages was being parsed.
+    ///
+    /// Description is prepended to the error message, original error
+    /// is available as [`source`](std::error::Error::source).
+    ///
+    /// ```
+    /// # use protobuf::Message;
+    /// # use protobuf::ProtobufResultExt;
+    /// # use protobuf::well_known_types::empty::Empty;
+    /// let e = Empty::parse_from_bytes(&[0xff]).context("parsing config").unwrap_err();
+    /// assert!(e.to_string().starts_with("parsing config: "));
+    /// ```
+    fn context(self, context: &'static str) -> Result<T>;
+}
+
+impl<T> ProtobufResultExt<T> for Result<T> {
+    fn context(self, context: &'static str) -> Result<T> {
+        self.map_err(|e| ProtobufError::Context(context, e).into())
+    }
+}
+
 impl From<Error> for io::Error {
     #[cold]
     fn from(err: Error) -> Self {
@@ -124,10 +183,36 @@ impl From<io::Error> for Error {
 
 #[cfg(test)]
 mod test {
+    use std::error::Error as _;
     use std::mem;
 
+    use super::ProtobufResultExt;
+    use crate::well_known_types::empty::Empty;
+    use crate::Message;
+
     #[test]
     fn error_size() {
         assert_eq!(mem::size_of::<usize>(), mem::size_of::<crate::Error>());
     }
+
+    #[test]
+    fn context() {
+        let e = Empty::parse_from_bytes(&[0xff]).unwrap_err();
+        let message = e.to_string();
+
+        let e = Empty::parse_from_bytes(&[0xff])
+            .context("parsing config")
+            .unwrap_err();
+        assert_eq!(format!("parsing config: {}", message), e.to_string());
+        assert_eq!(message, e.source().unwrap().to_string());
+
+        let e = Empty::parse_from_bytes(&[0xff])
+            .context("parsing config")
+            .context("loading")
+            .unwrap_err();
+        assert_eq!(
+            format!("loading: parsing config: {}", message),
+            e.to_string()
+        );
+    }
 }
@@ -2,7 +2,8 @@
 //!
 //! ## Features
 //!
-//! This crate has one feature, which is `with-bytes`.
+//! This crate has features `with-bytes`, `with-serde-json`, `with-tokio-util`
+//! and `with-chrono`.
 //!
 //! `with-bytes` enables `protobuf` crate support for
 //! [`bytes` crate](https://github.com/tokio-rs/bytes):
@@ -15,6 +16,17 @@
 //!
 //! See `Customize` struct in [`protobuf-codegen` crate](https://docs.rs/protobuf-codegen).
 //!
+//! `with-serde-json` enables conversions between well-known types
+//! `Struct`, `Value`, `ListValue` and
+//! [`serde_json` crate](https://github.com/serde-rs/json) types.
+//!
+//! `with-tokio-util` implements
+//! [`tokio-util`](https://docs.rs/tokio-util) `Decoder` and `Encoder` traits
+//! for `codec::ProtobufCodec`, so it can be used with `Framed`.
+//!
+//! `with-chrono` enables conversions between well-known type `Timestamp` and
+//! [`chrono`](https://docs.rs/chrono) `DateTime<Utc>`.
+//!
 //! ## Accompanying crates
 //!
 //! * [`protobuf-json-mapping`](https://docs.rs/protobuf-json-mapping)
@@ -31,10 +43,12 @@
 #![deny(rustdoc::broken_intra_doc_links)]
 
 pub use crate::coded_input_stream::CodedInputStream;
+pub use crate::coded_input_stream::FloatPolicy;
 pub use crate::coded_output_stream::CodedOutputStream;
 pub use crate::enum_full::EnumFull;
 pub use crate::enum_or_unknown::EnumOrUnknown;
 pub use crate::enums::Enum;
+pub use crate::enums::EnumFromStrError;
 pub use crate::message::Message;
 pub use crate::message_dyn::MessageDyn;
 pub use crate::message_field::MessageField;
@@ -50,7 +64,12 @@ pub(crate) mod wire_format;
 #[cfg(feature = "bytes")]
 pub use crate::chars::Chars;
 pub use crate::error::Error;
+pub use crate::error::ProtobufResultExt;
 pub use crate::error::Result;
+#[cfg(feature = "bytes")]
+pub use crate::string_interner::HashSetStringInterner;
+#[cfg(feature = "bytes")]
+pub use crate::string_interner::StringInterner;
 
 // generated
 pub mod descriptor;
@@ -58,6 +77,8 @@ pub mod plugin;
 pub mod rustproto;
 
 mod byteorder;
+#[cfg(feature = "bytes")]
+pub mod codec;
 mod coded_input_stream;
 mod coded_output_stream;
 mod enum_full;
@@ -78,6 +99,7 @@ pub mod rt;
 pub mod text_format;
 pub mod well_known_types;
 mod well_known_types_util;
+pub mod wire;
 
 // used by test
 #[cfg(test)]
@@ -88,8 +110,9 @@ mod cached_size;
 mod chars;
 mod fixed;
 mod special;
+mod string_interner;
 mod unknown;
-mod varint;
+pub mod varint;
 mod zigzag;
 
 mod misc;
@@ -3,6 +3,7 @@ use std::io::Write;
 
 use crate::coded_output_stream::with::WithCodedOutputStream;
 use crate::error::ProtobufError;
+use crate::error::WireError;
 use crate::wire_format::check_message_size;
 use crate::CodedInputStream;
 use crate::CodedOutputStream;
@@ -48,6 +49,19 @@ pub trait Message: Default + Clone + Send + Sync + Sized + PartialEq + 'static {
     /// Note if the computation overflows u32, the cached size is stored truncated.
     fn compute_size(&self) -> u64;
 
+    /// Cheap upper bound of the serialized size of this message.
+    ///
+    /// Never less than [`compute_size`](Message::compute_size),
+    /// but may be much larger: for example, every varint is assumed
+    /// to take the maximum number of bytes.
+    ///
+    /// Generated code computes the bound from field storage sizes
+    /// and, unlike `compute_size`, does not cache sizes.
+    /// Default implementation calls `compute_size`, so it does cache sizes.
+    fn estimated_size(&self) -> usize {
+        self.compute_size() as usize
+    }
+
     /// Get size previously computed by `compute_size`.
     ///
     /// Note if message size exceeds u32, the cached size is stored truncated.
@@ -113,6 +127,13 @@ pub trait Message: Default + Clone + Send + Sync + Sized + PartialEq + 'static {
 
     /// Parse message from reader.
     /// Parse stops on EOF or when error encountered.
+    ///
+    /// Whole input is consumed: protobuf messages are not self-delimiting,
+    /// so all bytes until EOF are parsed as fields of this message,
+    /// and there are no trailing bytes to ignore.
+    /// To read several messages from the same reader, write them with
+    /// [`write_length_delimited_to_writer`](Message::write_length_delimited_to_writer)
+    /// and read with [`CodedInputStream::read_message`].
     fn parse_from_reader(reader: &mut dyn Read) -> crate::Result<Self> {
         let mut is = CodedInputStream::new(reader);
         let r = Message::parse_from(&mut is)?;
@@ -121,6 +142,12 @@ pub trait Message: Default + Clone + Send + Sync + Sized + PartialEq + 'static {
     }
 
     /// Parse message from byte array.
+    ///
+    /// Like [`parse_from_reader`](Message::parse_from_reader), whole input is parsed
+    /// as the message: bytes after a complete message are parsed as more fields
+    /// (stored in unknown fields if not known), or fail to parse.
+    /// Use [`parse_length_delimited_from_bytes`](Message::parse_length_delimited_from_bytes)
+    /// to detect trailing bytes after a message.
     fn parse_from_bytes(bytes: &[u8]) -> crate::Result<Self> {
         let mut is = CodedInputStream::from_bytes(bytes);
         let r = Message::parse_from(&mut is)?;
@@ -128,6 +155,36 @@ pub trait Message: Default + Clone + Send + Sync + Sized + PartialEq + 'static {
         Ok(r)
     }
 
+    /// Parse length-delimited message from the start of byte array,
+    /// and return the message and the bytes after it.
+    ///
+    /// Message must be prefixed with its length encoded as varint, as written by
+    /// [`write_length_delimited_to_bytes`](Message::write_length_delimited_to_bytes):
+    /// protobuf messages are not self-delimiting, so without the prefix
+    /// trailing bytes cannot be told apart from message fields.
+    ///
+    /// Trailing bytes are returned, not checked;
+    /// see [`parse_length_delimited_from_bytes`](Message::parse_length_delimited_from_bytes).
+    fn parse_from_bytes_partial(bytes: &[u8]) -> crate::Result<(Self, &[u8])> {
+        let mut is = CodedInputStream::from_bytes(bytes);
+        let r = is.read_message()?;
+        let pos = is.pos() as usize;
+        Ok((r, &bytes[pos..]))
+    }
+
+    /// Parse message prefixed with its length encoded as varint,
+    /// as written by [`write_length_delimited_to_bytes`](Message::write_length_delimited_to_bytes).
+    ///
+    /// Unlike [`parse_from_bytes_partial`](Message::parse_from_bytes_partial),
+    /// bytes after the message are an error, so framing errors are not masked.
+    fn parse_length_delimited_from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        let (r, tail) = Self::parse_from_bytes_partial(bytes)?;
+        if !tail.is_empty() {
+            return Err(WireError::TrailingBytes(tail.len()).into());
+        }
+        Ok(r)
+    }
+
     /// Parse message from `Bytes` object.
     /// Resulting message may share references to the passed bytes object.
     #[cfg(feature = "bytes")]
@@ -153,6 +210,9 @@ pub trait Message: Default + Clone + Send + Sync + Sized + PartialEq + 'static {
     }
 
     /// Write the message to bytes vec.
+    ///
+    /// Serialized bytes are appended to the existing content of the vec,
+    /// so several messages can be written into one buffer.
     fn write_to_vec(&self, v: &mut Vec<u8>) -> crate::Result<()> {
         v.with_coded_output_stream(|os| self.write_to(os))
     }
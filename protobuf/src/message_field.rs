@@ -152,6 +152,11 @@ impl<T: Default> MessageField<T> {
 
 impl<M: Message> MessageField<M> {
     /// Get a reference to contained value or a default instance.
+    ///
+    /// Nothing is cloned or allocated: when the field is not set,
+    /// shared `'static` [`M::default_instance()`](Message::default_instance) is returned.
+    /// Generated getters of message fields (e. g. `foo()` for field `foo`)
+    /// are implemented with this function.
     pub fn get_or_default(&self) -> &M {
         self.as_ref().unwrap_or_else(|| M::default_instance())
     }
@@ -44,4 +44,18 @@ pub trait MessageFull: Message + ProtobufValue + fmt::Debug + fmt::Display {
     fn reflect_eq(&self, other: &Self, mode: &ReflectEqMode) -> bool {
         <dyn MessageDyn>::reflect_eq_dyn(self, other, mode)
     }
+
+    /// Clone message dropping unknown fields, including unknown fields of nested messages.
+    ///
+    /// Unknown fields are not copied, which is cheaper than `clone()`
+    /// when the message holds a lot of them.
+    ///
+    /// # See also
+    ///
+    /// [`reflect::clone_without_unknowns`](crate::reflect::clone_without_unknowns),
+    /// `dyn` version of this function.
+    fn clone_without_unknowns(&self) -> Self {
+        *<dyn MessageDyn>::downcast_box(crate::reflect::clone_without_unknowns(self))
+            .expect("wrong message type")
+    }
 }
@@ -227,6 +227,25 @@ impl crate::Message for Version {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        if let Some(v) = self.major {
+            my_size += 11;
+        }
+        if let Some(v) = self.minor {
+            my_size += 11;
+        }
+        if let Some(v) = self.patch {
+            my_size += 11;
+        }
+        if let Some(v) = self.suffix.as_ref() {
+            my_size += 1 + 5 + v.len();
+        }
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         if let Some(v) = self.major {
             os.write_int32(1, v)?;
@@ -471,6 +490,25 @@ impl crate::Message for CodeGeneratorRequest {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        for value in &self.file_to_generate {
+            my_size += 1 + 5 + value.len();
+        };
+        if let Some(v) = self.parameter.as_ref() {
+            my_size += 1 + 5 + v.len();
+        }
+        for value in &self.proto_file {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        if let Some(v) = self.compiler_version.as_ref() {
+            my_size += 1 + 5 + v.estimated_size();
+        }
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         for v in &self.file_to_generate {
             os.write_string(1, &v)?;
@@ -701,6 +739,22 @@ impl crate::Message for CodeGeneratorResponse {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        if let Some(v) = self.error.as_ref() {
+            my_size += 1 + 5 + v.len();
+        }
+        if let Some(v) = self.supported_features {
+            my_size += 11;
+        }
+        for value in &self.file {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         if let Some(v) = self.error.as_ref() {
             os.write_string(1, v)?;
@@ -1036,6 +1090,25 @@ pub mod code_generator_response {
             my_size
         }
 
+        #[allow(unused_variables)]
+        fn estimated_size(&self) -> usize {
+            let mut my_size = 0;
+            if let Some(v) = self.name.as_ref() {
+                my_size += 1 + 5 + v.len();
+            }
+            if let Some(v) = self.insertion_point.as_ref() {
+                my_size += 1 + 5 + v.len();
+            }
+            if let Some(v) = self.content.as_ref() {
+                my_size += 1 + 5 + v.len();
+            }
+            if let Some(v) = self.generated_code_info.as_ref() {
+                my_size += 2 + 5 + v.estimated_size();
+            }
+            my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+            my_size
+        }
+
         fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
             if let Some(v) = self.name.as_ref() {
                 os.write_string(1, v)?;
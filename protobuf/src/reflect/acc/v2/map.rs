@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::fmt;
 use std::hash::Hash;
@@ -88,3 +89,64 @@ where
         }),
     )
 }
+
+struct BTreeMapFieldAccessorImpl<M, K, V>
+where
+    M: MessageFull,
+    K: ProtobufValue,
+    V: ProtobufValue,
+{
+    get_field: fn(&M) -> &BTreeMap<K, V>,
+    mut_field: fn(&mut M) -> &mut BTreeMap<K, V>,
+}
+
+impl<M, K, V> MapFieldAccessor for BTreeMapFieldAccessorImpl<M, K, V>
+where
+    M: MessageFull,
+    K: ProtobufValue + Ord,
+    K::RuntimeType: RuntimeTypeHashable,
+    V: ProtobufValue,
+{
+    fn get_reflect<'a>(&self, m: &'a dyn MessageDyn) -> ReflectMapRef<'a> {
+        let m = m.downcast_ref().unwrap();
+        let map = (self.get_field)(m);
+        ReflectMapRef::new(map)
+    }
+
+    fn mut_reflect<'a>(&self, m: &'a mut dyn MessageDyn) -> ReflectMapMut<'a> {
+        let m = m.downcast_mut().unwrap();
+        let map = (self.mut_field)(m);
+        ReflectMapMut::new(map)
+    }
+
+    fn element_type(&self) -> (RuntimeType, RuntimeType) {
+        (
+            K::RuntimeType::runtime_type_box(),
+            V::RuntimeType::runtime_type_box(),
+        )
+    }
+}
+
+/// Make accessor for map field stored in `BTreeMap`
+/// (generated with `btreemap_fields` codegen option)
+pub fn make_btree_map_simpler_accessor<M, K, V>(
+    name: &'static str,
+    get_field: for<'a> fn(&'a M) -> &'a BTreeMap<K, V>,
+    mut_field: for<'a> fn(&'a mut M) -> &'a mut BTreeMap<K, V>,
+) -> FieldAccessor
+where
+    M: MessageFull + 'static,
+    K: ProtobufValue + Ord,
+    K::RuntimeType: RuntimeTypeHashable,
+    V: ProtobufValue,
+{
+    FieldAccessor::new(
+        name,
+        AccessorV2::Map(MapFieldAccessorHolder {
+            accessor: Box::new(BTreeMapFieldAccessorImpl::<M, K, V> {
+                get_field,
+                mut_field,
+            }),
+        }),
+    )
+}
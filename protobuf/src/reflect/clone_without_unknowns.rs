@@ -0,0 +1,113 @@
+use crate::message_dyn::MessageDyn;
+use crate::reflect::ReflectFieldRef;
+use crate::reflect::ReflectValueBox;
+use crate::reflect::ReflectValueRef;
+
+/// Clone a message without unknown fields.
+///
+/// Unknown fields are dropped in this message and in all nested messages,
+/// without being copied first, unlike `clone()` followed by clearing them.
+///
+/// Known fields are copied with reflection, so this is slower than `clone()`
+/// for messages without unknown fields.
+///
+/// # See also
+///
+/// [`MessageFull::clone_without_unknowns`](crate::MessageFull::clone_without_unknowns),
+/// typed version of this function.
+pub fn clone_without_unknowns(m: &dyn MessageDyn) -> Box<dyn MessageDyn> {
+    let descriptor = m.descriptor_dyn();
+    let mut r = descriptor.new_instance();
+    for field in descriptor.fields() {
+        match field.get_reflect(m) {
+            ReflectFieldRef::Optional(v) => {
+                if let Some(v) = v.value() {
+                    field.set_singular_field(&mut *r, value_without_unknowns(v));
+                }
+            }
+            ReflectFieldRef::Repeated(repeated) => {
+                if !repeated.is_empty() {
+                    let mut r_repeated = field.mut_repeated(&mut *r);
+                    for v in repeated {
+                        r_repeated.push(value_without_unknowns(v));
+                    }
+                }
+            }
+            ReflectFieldRef::Map(map) => {
+                if !map.is_empty() {
+                    let mut r_map = field.mut_map(&mut *r);
+                    for (k, v) in &map {
+                        r_map.insert(k.to_box(), value_without_unknowns(v));
+                    }
+                }
+            }
+        }
+    }
+    r
+}
+
+fn value_without_unknowns(v: ReflectValueRef) -> ReflectValueBox {
+    match v {
+        ReflectValueRef::Message(m) => ReflectValueBox::Message(clone_without_unknowns(&*m)),
+        v => v.to_box(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::clone_without_unknowns;
+    use crate::well_known_types::struct_::ListValue;
+    use crate::well_known_types::struct_::Value;
+    use crate::Message;
+    use crate::MessageDyn;
+    use crate::MessageFull;
+    use crate::UnknownFields;
+
+    fn list_with_unknowns() -> ListValue {
+        let mut value = Value::new();
+        value.set_number_value(1.5);
+        value.mut_unknown_fields().add_varint(100, 17);
+        let mut list = ListValue::new();
+        list.values.push(value);
+        list.mut_unknown_fields()
+            .add_length_delimited(101, vec![1, 2]);
+        list
+    }
+
+    fn is_empty(unknown_fields: &UnknownFields) -> bool {
+        unknown_fields.iter().next().is_none()
+    }
+
+    #[test]
+    fn test_nested_unknowns_dropped() {
+        let list = list_with_unknowns();
+        let cloned = clone_without_unknowns(&list);
+        let cloned: Box<ListValue> = <dyn MessageDyn>::downcast_box(cloned).unwrap();
+        assert!(is_empty(cloned.unknown_fields()));
+        assert!(is_empty(cloned.values[0].unknown_fields()));
+        assert_eq!(1.5, cloned.values[0].number_value());
+    }
+
+    #[test]
+    fn test_clone_preserves_unknowns() {
+        let list = list_with_unknowns();
+
+        let cloned = list.clone();
+        assert_eq!(list, cloned);
+        assert_eq!(
+            Some(17),
+            cloned.values[0].unknown_fields().get(100).map(|v| match v {
+                crate::UnknownValueRef::Varint(v) => v,
+                _ => panic!(),
+            })
+        );
+
+        let cloned = list.clone_without_unknowns();
+        assert!(is_empty(cloned.unknown_fields()));
+        assert!(is_empty(cloned.values[0].unknown_fields()));
+        let mut expected = ListValue::new();
+        expected.values.push(Value::new());
+        expected.values[0].set_number_value(1.5);
+        assert_eq!(expected, cloned);
+    }
+}
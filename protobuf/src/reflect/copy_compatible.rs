@@ -0,0 +1,220 @@
+use crate::message_dyn::MessageDyn;
+use crate::reflect::FieldDescriptor;
+use crate::reflect::ReflectFieldRef;
+use crate::reflect::ReflectValueBox;
+use crate::reflect::ReflectValueRef;
+use crate::reflect::RuntimeFieldType;
+use crate::reflect::RuntimeType;
+
+/// Copy fields from one message to a message of possibly different type.
+///
+/// Fields are matched by name. Field set in `src` is copied to `dst`
+/// (replacing the previous value, repeated and map fields are replaced entirely)
+/// if the field with the same name in `dst` has the same kind
+/// (singular, repeated or map) and the same type.
+/// Messages of different types in singular and repeated fields
+/// are copied recursively with the same rules.
+///
+/// Fields not set in `src` are left untouched in `dst`.
+///
+/// Return fields of `src` (or of nested messages) which are set,
+/// but were skipped because `dst` has no field with the same name,
+/// or that field type is not compatible.
+pub fn copy_compatible(dst: &mut dyn MessageDyn, src: &dyn MessageDyn) -> Vec<FieldDescriptor> {
+    let mut skipped = Vec::new();
+    copy_compatible_impl(dst, src, &mut skipped);
+    skipped
+}
+
+fn copy_compatible_impl(
+    dst: &mut dyn MessageDyn,
+    src: &dyn MessageDyn,
+    skipped: &mut Vec<FieldDescriptor>,
+) {
+    let dst_descriptor = dst.descriptor_dyn();
+    for src_field in src.descriptor_dyn().fields() {
+        if !src_field.has_field(src) {
+            continue;
+        }
+        let dst_field = match dst_descriptor.field_by_name(src_field.name()) {
+            Some(dst_field) => dst_field,
+            None => {
+                skipped.push(src_field);
+                continue;
+            }
+        };
+        if !copy_field(dst, &dst_field, src, &src_field, skipped) {
+            skipped.push(src_field);
+        }
+    }
+}
+
+/// Copy message value to a message of possibly different type.
+fn copy_message(
+    dst_type: &RuntimeType,
+    value: ReflectValueRef,
+    skipped: &mut Vec<FieldDescriptor>,
+) -> Option<ReflectValueBox> {
+    match (dst_type, value) {
+        (RuntimeType::Message(dst_descriptor), ReflectValueRef::Message(src)) => {
+            let mut dst = dst_descriptor.new_instance();
+            copy_compatible_impl(&mut *dst, &*src, skipped);
+            Some(ReflectValueBox::Message(dst))
+        }
+        _ => None,
+    }
+}
+
+/// Return `false` if field types are not compatible.
+fn copy_field(
+    dst: &mut dyn MessageDyn,
+    dst_field: &FieldDescriptor,
+    src: &dyn MessageDyn,
+    src_field: &FieldDescriptor,
+    skipped: &mut Vec<FieldDescriptor>,
+) -> bool {
+    match (dst_field.runtime_field_type(), src_field.get_reflect(src)) {
+        (RuntimeFieldType::Singular(dst_type), ReflectFieldRef::Optional(src_value)) => {
+            let src_value = match src_value.value() {
+                Some(src_value) => src_value,
+                None => return true,
+            };
+            if dst_type == src_value.get_type() {
+                dst_field.set_singular_field(dst, src_value.to_box());
+                return true;
+            }
+            match copy_message(&dst_type, src_value, skipped) {
+                Some(value) => {
+                    dst_field.set_singular_field(dst, value);
+                    true
+                }
+                None => false,
+            }
+        }
+        (RuntimeFieldType::Repeated(dst_type), ReflectFieldRef::Repeated(src_repeated)) => {
+            let src_type = src_repeated.element_type();
+            let same_type = dst_type == src_type;
+            if !same_type
+                && !matches!(
+                    (&dst_type, &src_type),
+                    (RuntimeType::Message(_), RuntimeType::Message(_))
+                )
+            {
+                return false;
+            }
+            let mut dst_repeated = dst_field.mut_repeated(dst);
+            dst_repeated.clear();
+            for value in src_repeated {
+                let value = if same_type {
+                    value.to_box()
+                } else {
+                    copy_message(&dst_type, value, skipped).unwrap()
+                };
+                dst_repeated.push(value);
+            }
+            true
+        }
+        (RuntimeFieldType::Map(dst_key_type, dst_value_type), ReflectFieldRef::Map(src_map)) => {
+            if dst_key_type != src_map.key_type() || dst_value_type != src_map.value_type() {
+                return false;
+            }
+            let mut dst_map = dst_field.mut_map(dst);
+            dst_map.clear();
+            for (k, v) in &src_map {
+                dst_map.insert(k.to_box(), v.to_box());
+            }
+            true
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::copy_compatible;
+    use crate::descriptor::field_descriptor_proto::Label;
+    use crate::descriptor::EnumValueDescriptorProto;
+    use crate::descriptor::FieldDescriptorProto;
+    use crate::well_known_types::duration::Duration;
+    use crate::well_known_types::timestamp::Timestamp;
+    use crate::well_known_types::type_::EnumValue;
+    use crate::well_known_types::type_::Option as ProtoOption;
+    use crate::well_known_types::type_::Type;
+
+    #[test]
+    fn test_same_fields() {
+        let mut src = Duration::new();
+        src.seconds = 10;
+        src.nanos = 20;
+        let mut dst = Timestamp::new();
+        assert!(copy_compatible(&mut dst, &src).is_empty());
+        assert_eq!(10, dst.seconds);
+        assert_eq!(20, dst.nanos);
+    }
+
+    #[test]
+    fn test_unset_fields_untouched() {
+        let mut src = Duration::new();
+        src.seconds = 10;
+        let mut dst = Timestamp::new();
+        dst.nanos = 30;
+        copy_compatible(&mut dst, &src);
+        assert_eq!(10, dst.seconds);
+        assert_eq!(30, dst.nanos);
+    }
+
+    #[test]
+    fn test_nested_and_skipped() {
+        let mut src = FieldDescriptorProto::new();
+        src.set_name("foo".to_owned());
+        src.set_number(17);
+        src.set_label(Label::LABEL_REPEATED);
+        src.options.mut_or_insert_default().set_deprecated(true);
+        src.options.mut_or_insert_default().set_packed(true);
+
+        let mut dst = EnumValueDescriptorProto::new();
+        let skipped = copy_compatible(&mut dst, &src);
+        assert_eq!("foo", dst.name());
+        assert_eq!(17, dst.number());
+        // `FieldOptions` and `EnumValueOptions` are different types
+        // copied recursively.
+        assert!(dst.options.deprecated());
+
+        let mut skipped: Vec<_> = skipped.iter().map(|f| f.full_name()).collect();
+        skipped.sort();
+        assert_eq!(
+            vec![
+                "google.protobuf.FieldDescriptorProto.label",
+                "google.protobuf.FieldOptions.packed",
+            ],
+            skipped
+        );
+    }
+
+    #[test]
+    fn test_incompatible_type_skipped() {
+        let mut src = EnumValue::new();
+        src.name = "FOO".to_owned();
+        src.number = 1;
+        src.options.push(ProtoOption::new());
+
+        let mut dst = EnumValueDescriptorProto::new();
+        let skipped = copy_compatible(&mut dst, &src);
+        assert_eq!("FOO", dst.name());
+        assert_eq!(1, dst.number());
+        assert!(dst.options.is_none());
+        // Repeated `Option` vs singular `EnumValueOptions`.
+        assert_eq!(1, skipped.len());
+        assert_eq!("google.protobuf.EnumValue.options", skipped[0].full_name());
+    }
+
+    #[test]
+    fn test_repeated_replaced() {
+        let mut src = Type::new();
+        src.oneofs = vec!["a".to_owned(), "b".to_owned()];
+        let mut dst = Type::new();
+        dst.oneofs = vec!["c".to_owned()];
+        copy_compatible(&mut dst, &src);
+        assert_eq!(src, dst);
+    }
+}
@@ -0,0 +1,155 @@
+use std::fmt::Write;
+
+use protobuf_support::text_format::quote_bytes_to;
+
+use crate::message_dyn::MessageDyn;
+use crate::reflect::MessageRef;
+use crate::reflect::ReflectFieldRef;
+use crate::reflect::ReflectValueRef;
+
+/// Bytes fields longer than this are truncated in debug output.
+const MAX_BYTES_LEN: usize = 32;
+
+fn do_indent(buf: &mut String, indent: usize) {
+    for _ in 0..indent {
+        buf.push_str("  ");
+    }
+}
+
+fn print_value(buf: &mut String, indent: usize, value: ReflectValueRef) {
+    match value {
+        ReflectValueRef::Message(m) => print_message(buf, indent, &m),
+        ReflectValueRef::Enum(d, v) => match d.value_by_number(v) {
+            Some(e) => buf.push_str(e.name()),
+            None => write!(buf, "{}", v).unwrap(),
+        },
+        ReflectValueRef::String(s) => quote_bytes_to(s.as_bytes(), buf),
+        ReflectValueRef::Bytes(b) if b.len() > MAX_BYTES_LEN => {
+            quote_bytes_to(&b[..MAX_BYTES_LEN], buf);
+            write!(buf, "... ({} bytes)", b.len()).unwrap();
+        }
+        ReflectValueRef::Bytes(b) => quote_bytes_to(b, buf),
+        ReflectValueRef::I32(v) => write!(buf, "{}", v).unwrap(),
+        ReflectValueRef::I64(v) => write!(buf, "{}", v).unwrap(),
+        ReflectValueRef::U32(v) => write!(buf, "{}", v).unwrap(),
+        ReflectValueRef::U64(v) => write!(buf, "{}", v).unwrap(),
+        ReflectValueRef::Bool(v) => write!(buf, "{}", v).unwrap(),
+        ReflectValueRef::F32(v) => write!(buf, "{}", v).unwrap(),
+        ReflectValueRef::F64(v) => write!(buf, "{}", v).unwrap(),
+    }
+}
+
+fn print_message(buf: &mut String, indent: usize, m: &MessageRef) {
+    let d = m.descriptor_dyn();
+    buf.push_str(d.name());
+    buf.push_str(" {\n");
+    for f in d.fields() {
+        match f.get_reflect(&**m) {
+            ReflectFieldRef::Optional(optional) => {
+                if let Some(v) = optional.value() {
+                    do_indent(buf, indent + 1);
+                    write!(buf, "{}: ", f.name()).unwrap();
+                    print_value(buf, indent + 1, v);
+                    buf.push('\n');
+                }
+            }
+            ReflectFieldRef::Repeated(repeated) => {
+                if repeated.is_empty() {
+                    continue;
+                }
+                do_indent(buf, indent + 1);
+                writeln!(buf, "{}: [", f.name()).unwrap();
+                for v in repeated {
+                    do_indent(buf, indent + 2);
+                    print_value(buf, indent + 2, v);
+                    buf.push_str(",\n");
+                }
+                do_indent(buf, indent + 1);
+                buf.push_str("]\n");
+            }
+            ReflectFieldRef::Map(map) => {
+                if map.is_empty() {
+                    continue;
+                }
+                do_indent(buf, indent + 1);
+                writeln!(buf, "{}: {{", f.name()).unwrap();
+                for (k, v) in &map {
+                    do_indent(buf, indent + 2);
+                    print_value(buf, indent + 2, k);
+                    buf.push_str(": ");
+                    print_value(buf, indent + 2, v);
+                    buf.push_str(",\n");
+                }
+                do_indent(buf, indent + 1);
+                buf.push_str("}\n");
+            }
+        }
+    }
+    do_indent(buf, indent);
+    buf.push('}');
+}
+
+/// Human-readable representation of a message for debugging.
+///
+/// Unlike derived `Debug`, only set fields are printed,
+/// unknown fields and cached size are omitted,
+/// and long `bytes` values are truncated.
+/// Nested messages are printed indented.
+///
+/// Output format is not stable and should not be parsed.
+pub fn debug_string(m: &dyn MessageDyn) -> String {
+    let mut buf = String::new();
+    print_message(&mut buf, 0, &MessageRef::from(m));
+    buf
+}
+
+#[cfg(test)]
+mod test {
+    use super::debug_string;
+    use crate::well_known_types::struct_::ListValue;
+    use crate::well_known_types::struct_::Value;
+    use crate::well_known_types::wrappers::BytesValue;
+
+    #[test]
+    fn test_nested_indented() {
+        let mut list = ListValue::new();
+        let mut value = Value::new();
+        value.set_string_value("foo".to_owned());
+        list.values.push(value);
+        let mut value = Value::new();
+        value.set_bool_value(true);
+        list.values.push(value);
+
+        assert_eq!(
+            "ListValue {\n  values: [\n    Value {\n      string_value: \"foo\"\n    },\n    Value {\n      bool_value: true\n    },\n  ]\n}",
+            debug_string(&list)
+        );
+    }
+
+    #[test]
+    fn test_no_special_fields() {
+        let mut value = Value::new();
+        value.set_number_value(1.5);
+        value
+            .special_fields
+            .mut_unknown_fields()
+            .add_varint(100, 17);
+        let s = debug_string(&value);
+        assert_eq!("Value {\n  number_value: 1.5\n}", s);
+        assert!(!s.contains("unknown_fields"));
+        assert!(!s.contains("cached_size"));
+    }
+
+    #[test]
+    fn test_bytes_truncated() {
+        let mut value = BytesValue::new();
+        value.value = vec![b'a'; 100];
+        assert_eq!(
+            format!(
+                "BytesValue {{\n  value: \"{}\"... (100 bytes)\n}}",
+                "a".repeat(32)
+            ),
+            debug_string(&value)
+        );
+    }
+}
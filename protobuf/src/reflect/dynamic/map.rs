@@ -7,6 +7,7 @@ use crate::reflect::map::ReflectMap;
 use crate::reflect::map::ReflectMapIter;
 use crate::reflect::map::ReflectMapIterTrait;
 use crate::reflect::runtime_types::RuntimeTypeTrait;
+use crate::reflect::value::value_ref::ReflectValueMut;
 use crate::reflect::ProtobufValue;
 use crate::reflect::ReflectValueBox;
 use crate::reflect::ReflectValueRef;
@@ -47,6 +48,17 @@ impl Maps {
         }
     }
 
+    fn capacity(&self) -> usize {
+        match self {
+            Maps::U32(m) => m.capacity(),
+            Maps::I32(m) => m.capacity(),
+            Maps::U64(m) => m.capacity(),
+            Maps::I64(m) => m.capacity(),
+            Maps::Bool(m) => m.capacity(),
+            Maps::String(m) => m.capacity(),
+        }
+    }
+
     fn is_empty(&self) -> bool {
         match self {
             Maps::U32(m) => m.is_empty(),
@@ -170,6 +182,10 @@ impl ReflectMap for DynamicMap {
         self.maps.len()
     }
 
+    fn capacity(&self) -> usize {
+        self.maps.capacity()
+    }
+
     fn is_empty(&self) -> bool {
         self.maps.is_empty()
     }
@@ -203,6 +219,17 @@ impl ReflectMap for DynamicMap {
         };
     }
 
+    fn values_mut<'a>(&'a mut self) -> Box<dyn Iterator<Item = ReflectValueMut<'a>> + 'a> {
+        match &mut self.maps {
+            Maps::U32(m) => Box::new(m.values_mut().map(|v| v.as_value_mut())),
+            Maps::I32(m) => Box::new(m.values_mut().map(|v| v.as_value_mut())),
+            Maps::U64(m) => Box::new(m.values_mut().map(|v| v.as_value_mut())),
+            Maps::I64(m) => Box::new(m.values_mut().map(|v| v.as_value_mut())),
+            Maps::Bool(m) => Box::new(m.values_mut().map(|v| v.as_value_mut())),
+            Maps::String(m) => Box::new(m.values_mut().map(|v| v.as_value_mut())),
+        }
+    }
+
     fn clear(&mut self) {
         self.maps.clear()
     }
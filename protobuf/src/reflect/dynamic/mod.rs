@@ -3,6 +3,7 @@ use std::any::TypeId;
 use std::fmt;
 
 use crate::descriptor::field_descriptor_proto::Type;
+use crate::error::WireError;
 use crate::message_dyn::MessageDyn;
 use crate::reflect::dynamic::map::DynamicMap;
 use crate::reflect::dynamic::optional::DynamicOptional;
@@ -356,6 +357,17 @@ impl MessageDyn for DynamicMessage {
                 }
             };
             match field_desc.runtime_field_type() {
+                RuntimeFieldType::Singular(..)
+                    if field_desc.proto().type_() == Type::TYPE_MESSAGE =>
+                {
+                    if wire_type != WireType::LengthDelimited {
+                        return Err(WireError::UnexpectedWireType(wire_type).into());
+                    }
+                    // Repeated occurrences of singular message field are merged.
+                    match self.mut_singular_field_or_default(&field_desc) {
+                        ReflectValueMut::Message(m) => is.merge_message_dyn(m)?,
+                    }
+                }
                 RuntimeFieldType::Singular(rtb) => {
                     let pt = ProtobufType::new(rtb, field_desc.proto().type_())?;
                     let value = pt.read(is, wire_type)?;
@@ -1,6 +1,7 @@
 use crate::reflect::repeated::drain_iter::ReflectRepeatedDrainIter;
 use crate::reflect::repeated::iter::ReflectRepeatedIter;
 use crate::reflect::repeated::ReflectRepeated;
+use crate::reflect::value::value_ref::ReflectValueMut;
 use crate::reflect::EnumDescriptor;
 use crate::reflect::MessageDescriptor;
 use crate::reflect::MessageRef;
@@ -90,6 +91,22 @@ impl ReflectRepeated for DynamicRepeated {
         }
     }
 
+    fn capacity(&self) -> usize {
+        match self {
+            DynamicRepeated::U32(v) => v.capacity(),
+            DynamicRepeated::U64(v) => v.capacity(),
+            DynamicRepeated::I32(v) => v.capacity(),
+            DynamicRepeated::I64(v) => v.capacity(),
+            DynamicRepeated::F32(v) => v.capacity(),
+            DynamicRepeated::F64(v) => v.capacity(),
+            DynamicRepeated::Bool(v) => v.capacity(),
+            DynamicRepeated::String(v) => v.capacity(),
+            DynamicRepeated::Bytes(v) => v.capacity(),
+            DynamicRepeated::Enum(.., v) => v.capacity(),
+            DynamicRepeated::Message(.., v) => v.capacity(),
+        }
+    }
+
     fn get(&self, index: usize) -> ReflectValueRef {
         match self {
             DynamicRepeated::U32(v) => ReflectValueRef::U32(v[index]),
@@ -110,6 +127,13 @@ impl ReflectRepeated for DynamicRepeated {
         }
     }
 
+    fn get_mut(&mut self, index: usize) -> ReflectValueMut<'_> {
+        match self {
+            DynamicRepeated::Message(_descriptor, v) => ReflectValueMut::Message(&mut *v[index]),
+            _ => panic!("not a message: {}", self.element_type()),
+        }
+    }
+
     fn set(&mut self, index: usize, value: ReflectValueBox) {
         match self {
             DynamicRepeated::U32(v) => v.set(index, value),
@@ -184,6 +208,54 @@ impl ReflectRepeated for DynamicRepeated {
         }
     }
 
+    fn insert(&mut self, index: usize, value: ReflectValueBox) {
+        match self {
+            DynamicRepeated::U32(vs) => ReflectRepeated::insert(vs, index, value),
+            DynamicRepeated::U64(vs) => ReflectRepeated::insert(vs, index, value),
+            DynamicRepeated::I32(vs) => ReflectRepeated::insert(vs, index, value),
+            DynamicRepeated::I64(vs) => ReflectRepeated::insert(vs, index, value),
+            DynamicRepeated::F32(vs) => ReflectRepeated::insert(vs, index, value),
+            DynamicRepeated::F64(vs) => ReflectRepeated::insert(vs, index, value),
+            DynamicRepeated::Bool(vs) => ReflectRepeated::insert(vs, index, value),
+            DynamicRepeated::String(vs) => ReflectRepeated::insert(vs, index, value),
+            DynamicRepeated::Bytes(vs) => ReflectRepeated::insert(vs, index, value),
+            DynamicRepeated::Enum(descriptor, vs) => match value {
+                ReflectValueBox::Enum(value_description, v) => {
+                    assert_eq!(*descriptor, value_description);
+                    vs.insert(index, v);
+                }
+                _ => panic!("Expected enum value"),
+            },
+            DynamicRepeated::Message(descriptor, vs) => match value {
+                ReflectValueBox::Message(message) => {
+                    assert_eq!(*descriptor, message.descriptor_dyn());
+                    vs.insert(index, message);
+                }
+                _ => panic!("Expected message value"),
+            },
+        }
+    }
+
+    fn swap_remove(&mut self, index: usize) -> ReflectValueBox {
+        match self {
+            DynamicRepeated::U32(vs) => ReflectRepeated::swap_remove(vs, index),
+            DynamicRepeated::U64(vs) => ReflectRepeated::swap_remove(vs, index),
+            DynamicRepeated::I32(vs) => ReflectRepeated::swap_remove(vs, index),
+            DynamicRepeated::I64(vs) => ReflectRepeated::swap_remove(vs, index),
+            DynamicRepeated::F32(vs) => ReflectRepeated::swap_remove(vs, index),
+            DynamicRepeated::F64(vs) => ReflectRepeated::swap_remove(vs, index),
+            DynamicRepeated::Bool(vs) => ReflectRepeated::swap_remove(vs, index),
+            DynamicRepeated::String(vs) => ReflectRepeated::swap_remove(vs, index),
+            DynamicRepeated::Bytes(vs) => ReflectRepeated::swap_remove(vs, index),
+            DynamicRepeated::Enum(descriptor, vs) => {
+                ReflectValueBox::Enum(descriptor.clone(), vs.swap_remove(index))
+            }
+            DynamicRepeated::Message(_descriptor, vs) => {
+                ReflectValueBox::Message(vs.swap_remove(index))
+            }
+        }
+    }
+
     fn clear(&mut self) {
         match self {
             DynamicRepeated::U32(vs) => vs.clear(),
@@ -0,0 +1,185 @@
+use crate::descriptor::FileDescriptorProto;
+use crate::descriptor::FileDescriptorSet;
+use crate::reflect::EnumDescriptor;
+use crate::reflect::FileDescriptor;
+use crate::reflect::MessageDescriptor;
+use crate::well_known_types;
+use crate::Message;
+
+/// Set of dynamic file descriptors loaded from a [`FileDescriptorSet`].
+///
+/// Messages and enums defined in any of the files can be looked up by name
+/// and then used to parse and print messages without generated code.
+#[derive(Clone, Debug)]
+pub struct DynamicSchema {
+    files: Vec<FileDescriptor>,
+}
+
+impl DynamicSchema {
+    /// Build file descriptors from a descriptor set
+    /// (for example, produced with `protoc --descriptor_set_out`).
+    ///
+    /// Files may be listed in any order, references between them are resolved.
+    /// Well-known types (`google/protobuf/*.proto`) not included in the set
+    /// are resolved to the descriptors generated in this crate.
+    pub fn from_file_descriptor_set(set: FileDescriptorSet) -> crate::Result<DynamicSchema> {
+        let deps: Vec<FileDescriptor> = missing_deps(&set.file)
+            .filter_map(well_known_file_descriptor)
+            .collect();
+        let files = FileDescriptor::new_dynamic_fds(set.file, &deps)?;
+        Ok(DynamicSchema { files })
+    }
+
+    /// Files of the descriptor set, in the order they are listed in the set.
+    pub fn files(&self) -> &[FileDescriptor] {
+        &self.files
+    }
+
+    /// Find message by fully-qualified name (with or without leading dot).
+    pub fn message_by_full_name(&self, name: &str) -> Option<MessageDescriptor> {
+        let name = format!(".{}", name.strip_prefix('.').unwrap_or(name));
+        self.files
+            .iter()
+            .find_map(|f| f.message_by_full_name(&name))
+    }
+
+    /// Find enum by fully-qualified name (with or without leading dot).
+    pub fn enum_by_full_name(&self, name: &str) -> Option<EnumDescriptor> {
+        let name = format!(".{}", name.strip_prefix('.').unwrap_or(name));
+        self.files.iter().find_map(|f| f.enum_by_full_name(&name))
+    }
+}
+
+/// Parse serialized [`FileDescriptorSet`] and build [`DynamicSchema`] from it.
+pub fn load_descriptor_set(bytes: &[u8]) -> crate::Result<DynamicSchema> {
+    DynamicSchema::from_file_descriptor_set(FileDescriptorSet::parse_from_bytes(bytes)?)
+}
+
+/// Dependencies of files not present in the list.
+fn missing_deps(files: &[FileDescriptorProto]) -> impl Iterator<Item = &str> {
+    let mut missing: Vec<&str> = files
+        .iter()
+        .flat_map(|f| f.dependency.iter().map(|d| d.as_str()))
+        .filter(|d| !files.iter().any(|f| f.name() == *d))
+        .collect();
+    missing.sort();
+    missing.dedup();
+    missing.into_iter()
+}
+
+fn well_known_file_descriptor(name: &str) -> Option<FileDescriptor> {
+    Some(match name {
+        "google/protobuf/any.proto" => well_known_types::any::file_descriptor().clone(),
+        "google/protobuf/api.proto" => well_known_types::api::file_descriptor().clone(),
+        "google/protobuf/descriptor.proto" => crate::descriptor::file_descriptor().clone(),
+        "google/protobuf/duration.proto" => well_known_types::duration::file_descriptor().clone(),
+        "google/protobuf/empty.proto" => well_known_types::empty::file_descriptor().clone(),
+        "google/protobuf/field_mask.proto" => {
+            well_known_types::field_mask::file_descriptor().clone()
+        }
+        "google/protobuf/source_context.proto" => {
+            well_known_types::source_context::file_descriptor().clone()
+        }
+        "google/protobuf/struct.proto" => well_known_types::struct_::file_descriptor().clone(),
+        "google/protobuf/timestamp.proto" => well_known_types::timestamp::file_descriptor().clone(),
+        "google/protobuf/type.proto" => well_known_types::type_::file_descriptor().clone(),
+        "google/protobuf/wrappers.proto" => well_known_types::wrappers::file_descriptor().clone(),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::load_descriptor_set;
+    use crate::descriptor::FileDescriptorProto;
+    use crate::descriptor::FileDescriptorSet;
+    use crate::text_format;
+    use crate::Message;
+
+    fn file(text: &str) -> FileDescriptorProto {
+        text_format::parse_from_str(text).unwrap()
+    }
+
+    fn descriptor_set_bytes() -> Vec<u8> {
+        let mut set = FileDescriptorSet::new();
+        // Dependent file goes first: order must not matter.
+        set.file.push(file(
+            r#"
+            name: "a.proto"
+            package: "pa"
+            dependency: "b.proto"
+            dependency: "google/protobuf/timestamp.proto"
+            message_type {
+                name: "A"
+                field { name: "b" number: 1 label: LABEL_OPTIONAL type: TYPE_MESSAGE type_name: ".pb.B" }
+                field { name: "e" number: 2 label: LABEL_OPTIONAL type: TYPE_ENUM type_name: ".pb.E" }
+                field { name: "t" number: 3 label: LABEL_OPTIONAL type: TYPE_MESSAGE type_name: ".google.protobuf.Timestamp" }
+            }
+            "#,
+        ));
+        set.file.push(file(
+            r#"
+            name: "b.proto"
+            package: "pb"
+            message_type {
+                name: "B"
+                field { name: "x" number: 1 label: LABEL_OPTIONAL type: TYPE_INT32 }
+                nested_type { name: "Nested" }
+            }
+            enum_type {
+                name: "E"
+                value { name: "E0" number: 0 }
+                value { name: "E1" number: 1 }
+            }
+            "#,
+        ));
+        set.write_to_bytes().unwrap()
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_lookup() {
+        let schema = load_descriptor_set(&descriptor_set_bytes()).unwrap();
+        assert_eq!(2, schema.files().len());
+        assert_eq!("a.proto", schema.files()[0].name());
+        assert_eq!(
+            "pa.A",
+            schema.message_by_full_name("pa.A").unwrap().full_name()
+        );
+        assert_eq!(
+            "pb.B",
+            schema.message_by_full_name(".pb.B").unwrap().full_name()
+        );
+        assert!(schema.message_by_full_name("pb.B.Nested").is_some());
+        assert!(schema.message_by_full_name("pb.A").is_none());
+        assert_eq!(
+            "pb.E",
+            schema.enum_by_full_name("pb.E").unwrap().full_name()
+        );
+        assert!(schema.enum_by_full_name("pa.E").is_none());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_parse_message() {
+        let schema = load_descriptor_set(&descriptor_set_bytes()).unwrap();
+        let a = schema.message_by_full_name("pa.A").unwrap();
+        // b { x: 10 } e: E1 t { seconds: 20 }
+        let m = a
+            .parse_from_bytes(&[0x0a, 0x02, 0x08, 0x0a, 0x10, 0x01, 0x1a, 0x02, 0x08, 0x14])
+            .unwrap();
+        assert_eq!(
+            "b {x: 10} e: E1 t {seconds: 20}",
+            text_format::print_to_string(&*m)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_unresolved_dependency() {
+        let mut set = FileDescriptorSet::new();
+        set.file
+            .push(file(r#"name: "a.proto" dependency: "b.proto""#));
+        assert!(load_descriptor_set(&set.write_to_bytes().unwrap()).is_err());
+    }
+}
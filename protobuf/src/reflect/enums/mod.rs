@@ -162,6 +162,11 @@ impl EnumDescriptor {
         &self.index_entry().name_to_package
     }
 
+    /// Package of the file where the enum is declared, empty if no package.
+    pub fn package(&self) -> &str {
+        self.file_descriptor.package()
+    }
+
     /// Get `EnumDescriptor` object for given enum type
     pub fn for_type<E: EnumFull>() -> EnumDescriptor {
         E::enum_descriptor()
@@ -290,4 +295,13 @@ mod test {
             Label::LABEL_REPEATED.descriptor().full_name()
         );
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Too slow on Miri.
+    fn names() {
+        let d = Label::enum_descriptor();
+        assert_eq!("Label", d.name());
+        assert_eq!("FieldDescriptorProto.Label", d.name_to_package());
+        assert_eq!("google.protobuf", d.package());
+    }
 }
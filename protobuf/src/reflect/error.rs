@@ -1,3 +1,5 @@
+use protobuf_support::base64::FromBase64Error;
+
 #[derive(Debug, thiserror::Error)]
 pub(crate) enum ReflectError {
     #[error("Message `{}` not found in files: {}", .0, .1)]
@@ -23,4 +25,10 @@ pub(crate) enum ReflectError {
     MapEntryIncorrectFields,
     #[error("Could not parse default value for field {0}")]
     CouldNotParseDefaultValueForField(String),
+    #[error("Could not parse `{1}` as value of field {0}")]
+    CouldNotParseValueForField(String, String),
+    #[error("Unknown enum value name `{1}` for field {0}")]
+    UnknownEnumValueNameForField(String, String),
+    #[error("Invalid base64 value of field {0}")]
+    InvalidBase64ForField(String, #[source] FromBase64Error),
 }
@@ -0,0 +1,20 @@
+use crate::descriptor::field_descriptor_proto::Type;
+
+/// Static field metadata, generated in `FIELDS` constant of messages
+/// when `emit_field_meta` codegen option is enabled.
+///
+/// This is a lightweight alternative to [`FieldDescriptor`](crate::reflect::FieldDescriptor),
+/// which does not require building a descriptor and is available in `const` context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FieldMeta {
+    /// Field name as specified in `.proto` file.
+    pub name: &'static str,
+    /// Field number.
+    pub number: u32,
+    /// Field type as specified in `.proto` file; map fields are `TYPE_MESSAGE`.
+    pub type_: Type,
+    /// Field is repeated or map.
+    pub repeated: bool,
+    /// Field is a member of oneof (synthetic oneofs of proto3 `optional` are not counted).
+    pub in_oneof: bool,
+}
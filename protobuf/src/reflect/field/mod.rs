@@ -1,5 +1,7 @@
 use std::fmt;
 
+use protobuf_support::base64;
+
 use crate::descriptor::field_descriptor_proto;
 use crate::descriptor::FieldDescriptorProto;
 use crate::message_dyn::MessageDyn;
@@ -9,6 +11,7 @@ use crate::reflect::acc::v2::singular::SingularFieldAccessorHolder;
 use crate::reflect::acc::v2::AccessorV2;
 use crate::reflect::acc::GeneratedFieldAccessor;
 use crate::reflect::dynamic::DynamicMessage;
+use crate::reflect::error::ReflectError;
 use crate::reflect::field::dynamic::DynamicFieldDescriptorRef;
 use crate::reflect::field::index::FieldIndex;
 use crate::reflect::field::index::FieldKind;
@@ -31,9 +34,11 @@ use crate::reflect::ReflectOptionalRef;
 use crate::reflect::ReflectValueBox;
 use crate::reflect::ReflectValueRef;
 use crate::reflect::RuntimeType;
+use crate::reflect::Syntax;
 
 pub(crate) mod dynamic;
 pub(crate) mod index;
+pub(crate) mod meta;
 pub(crate) mod protobuf_field_type;
 pub(crate) mod runtime_field_type;
 
@@ -293,8 +298,33 @@ impl FieldDescriptor {
         }
     }
 
+    /// Does this field track presence?
+    ///
+    /// Singular message fields, oneof members (including proto3 `optional` fields),
+    /// extensions and all singular proto2 fields have presence:
+    /// [`has_field`](Self::has_field) returns `true` for them if the field is set,
+    /// even to the default value, and they are omitted from JSON when not set.
+    /// Repeated, map and plain proto3 scalar fields do not have presence.
+    pub fn has_presence(&self) -> bool {
+        if !self.is_singular() {
+            return false;
+        }
+        if let RuntimeFieldType::Singular(RuntimeType::Message(_)) = self.runtime_field_type() {
+            return true;
+        }
+        if let FieldKind::Extension(..) = self.index().kind {
+            return true;
+        }
+        self.proto().has_oneof_index() || self.file_descriptor().syntax() == Syntax::Proto2
+    }
+
     /// Check if field is set in given message.
     ///
+    /// For fields with presence (proto2 optional, proto3 `optional`,
+    /// message and oneof fields) return `true` if the field is set,
+    /// even if it is set to the default value.
+    /// For proto3 fields without presence return `true`
+    /// if the value is not the default.
     /// For repeated field or map field return `true` if
     /// collection is not empty.
     ///
@@ -438,6 +468,148 @@ impl FieldDescriptor {
         }
     }
 
+    /// Parse string into value of this field type, and set singular field
+    /// or append the value to repeated field.
+    ///
+    /// Enum values are accepted by name or by number,
+    /// `bytes` fields are set to UTF-8 bytes of the string.
+    ///
+    /// # Errors
+    ///
+    /// If string cannot be parsed, or field is a message or a map field.
+    ///
+    /// # Panics
+    ///
+    /// If this field belongs to a different message type.
+    pub fn set_from_str(&self, m: &mut dyn MessageDyn, s: &str) -> crate::Result<()> {
+        let value = match self.runtime_field_type() {
+            RuntimeFieldType::Singular(t) | RuntimeFieldType::Repeated(t) => t.parse_from_str(s),
+            RuntimeFieldType::Map(..) => None,
+        };
+        let value = match value {
+            Some(value) => value,
+            None => {
+                return Err(ReflectError::CouldNotParseValueForField(
+                    self.full_name(),
+                    s.to_owned(),
+                )
+                .into())
+            }
+        };
+        if self.is_singular() {
+            self.set_singular_field(m, value);
+        } else {
+            self.mut_repeated(m).push(value);
+        }
+        Ok(())
+    }
+
+    /// Get singular `bytes` field value encoded as base64, like in JSON.
+    ///
+    /// Return base64 of field default value if field is unset.
+    ///
+    /// # Panics
+    ///
+    /// If this field belongs to a different message type or
+    /// field is not a singular `bytes` field.
+    pub fn get_bytes_as_base64(&self, m: &dyn MessageDyn) -> String {
+        match self.get_singular_field_or_default(m) {
+            ReflectValueRef::Bytes(b) => base64::encode(b),
+            _ => panic!("Not a bytes field: {}", self),
+        }
+    }
+
+    /// Decode base64 string (as in JSON) and set singular `bytes` field.
+    ///
+    /// # Errors
+    ///
+    /// If string is not valid base64.
+    ///
+    /// # Panics
+    ///
+    /// If this field belongs to a different message type or
+    /// field is not a singular `bytes` field.
+    pub fn set_bytes_from_base64(&self, m: &mut dyn MessageDyn, s: &str) -> crate::Result<()> {
+        if self.singular_runtime_type() != RuntimeType::VecU8 {
+            panic!("Not a bytes field: {}", self);
+        }
+        let bytes = base64::decode(s)
+            .map_err(|e| ReflectError::InvalidBase64ForField(self.full_name(), e))?;
+        self.set_singular_field(m, ReflectValueBox::Bytes(bytes));
+        Ok(())
+    }
+
+    /// Compare singular `bytes` field values of two messages.
+    ///
+    /// Return the offset of the first differing byte, or the length of
+    /// the shorter value if it is a prefix of the other,
+    /// or `None` if values are equal. Unset field is compared as its default value.
+    ///
+    /// # Panics
+    ///
+    /// If this field belongs to a different message type or
+    /// field is not a singular `bytes` field.
+    pub fn bytes_first_diff(&self, a: &dyn MessageDyn, b: &dyn MessageDyn) -> Option<usize> {
+        let (a, b) = match (
+            self.get_singular_field_or_default(a),
+            self.get_singular_field_or_default(b),
+        ) {
+            (ReflectValueRef::Bytes(a), ReflectValueRef::Bytes(b)) => (a, b),
+            _ => panic!("Not a bytes field: {}", self),
+        };
+        match a.iter().zip(b).position(|(a, b)| a != b) {
+            Some(pos) => Some(pos),
+            None if a.len() != b.len() => Some(a.len().min(b.len())),
+            None => None,
+        }
+    }
+
+    /// Get name of singular enum field value.
+    ///
+    /// Return name of field default value if field is unset,
+    /// and `None` if the value is not known to the field enum
+    /// (e. g. an unknown value preserved in proto3).
+    ///
+    /// # Panics
+    ///
+    /// If this field belongs to a different message type or
+    /// field is not a singular enum field.
+    pub fn get_enum_name(&self, m: &dyn MessageDyn) -> Option<String> {
+        match self.get_singular_field_or_default(m) {
+            ReflectValueRef::Enum(d, v) => d.value_by_number(v).map(|v| v.name().to_owned()),
+            _ => panic!("Not an enum field: {}", self),
+        }
+    }
+
+    /// Set singular enum field to the value with given name.
+    ///
+    /// # Errors
+    ///
+    /// If field enum has no value with this name.
+    ///
+    /// # Panics
+    ///
+    /// If this field belongs to a different message type or
+    /// field is not a singular enum field.
+    pub fn set_enum_by_name(&self, m: &mut dyn MessageDyn, name: &str) -> crate::Result<()> {
+        let descriptor = match self.singular_runtime_type() {
+            RuntimeType::Enum(d) => d,
+            _ => panic!("Not an enum field: {}", self),
+        };
+        let value = match descriptor.value_by_name(name) {
+            Some(value) => value,
+            None => {
+                return Err(ReflectError::UnknownEnumValueNameForField(
+                    self.full_name(),
+                    name.to_owned(),
+                )
+                .into())
+            }
+        };
+        self.set_singular_field(m, ReflectValueBox::from(value));
+        Ok(())
+    }
+
     /// Clear a field.
     pub fn clear_field(&self, m: &mut dyn MessageDyn) {
         if self.is_singular() {
@@ -565,10 +737,15 @@ pub(crate) enum FieldDescriptorImplRef<'a> {
 mod test {
     use std::collections::HashMap;
 
+    use crate::descriptor::field_descriptor_proto::Type;
     use crate::descriptor::DescriptorProto;
+    use crate::descriptor::FieldDescriptorProto;
     use crate::reflect::ReflectValueBox;
+    use crate::well_known_types::duration::Duration;
     use crate::well_known_types::struct_::Struct;
     use crate::well_known_types::struct_::Value;
+    use crate::well_known_types::type_::Type as ProtoType;
+    use crate::well_known_types::wrappers::BytesValue;
     use crate::MessageFull;
 
     #[test]
@@ -582,4 +759,102 @@ mod test {
             field.to_string()
         );
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn enum_by_name() {
+        let field = FieldDescriptorProto::descriptor()
+            .field_by_name("type")
+            .unwrap();
+        let mut m = FieldDescriptorProto::new();
+        // Default value of the enum.
+        assert_eq!(Some("TYPE_DOUBLE".to_owned()), field.get_enum_name(&m));
+
+        field.set_enum_by_name(&mut m, "TYPE_STRING").unwrap();
+        assert_eq!(Type::TYPE_STRING, m.type_());
+        assert_eq!(Some("TYPE_STRING".to_owned()), field.get_enum_name(&m));
+
+        let e = field.set_enum_by_name(&mut m, "TYPE_FOO").unwrap_err();
+        assert_eq!(
+            "Unknown enum value name `TYPE_FOO` for field google.protobuf.FieldDescriptorProto.type",
+            e.to_string()
+        );
+        assert_eq!(Type::TYPE_STRING, m.type_());
+    }
+
+    #[test]
+    #[should_panic(expected = "Not an enum field")]
+    #[cfg_attr(miri, ignore)]
+    fn enum_by_name_not_enum() {
+        let field = FieldDescriptorProto::descriptor()
+            .field_by_name("name")
+            .unwrap();
+        field.get_enum_name(&FieldDescriptorProto::new());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn has_presence() {
+        let has_presence = |d: crate::reflect::MessageDescriptor, name| {
+            d.field_by_name(name).unwrap().has_presence()
+        };
+        // proto2
+        assert!(has_presence(FieldDescriptorProto::descriptor(), "name"));
+        assert!(has_presence(FieldDescriptorProto::descriptor(), "options"));
+        assert!(!has_presence(DescriptorProto::descriptor(), "field"));
+        // proto3
+        assert!(has_presence(Value::descriptor(), "number_value"));
+        assert!(has_presence(Value::descriptor(), "struct_value"));
+        assert!(!has_presence(Struct::descriptor(), "fields"));
+        assert!(!has_presence(Duration::descriptor(), "seconds"));
+        assert!(has_presence(ProtoType::descriptor(), "source_context"));
+
+        // Unset message field is not set, empty message field is set.
+        let field = ProtoType::descriptor()
+            .field_by_name("source_context")
+            .unwrap();
+        let mut m = ProtoType::new();
+        assert!(!field.has_field(&m));
+        m.source_context = crate::MessageField::some(Default::default());
+        assert!(field.has_field(&m));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn bytes_first_diff() {
+        let field = BytesValue::descriptor().field_by_name("value").unwrap();
+        let bytes = |b: &[u8]| {
+            let mut m = BytesValue::new();
+            m.value = b.to_vec();
+            m
+        };
+        let a = bytes(b"\x00\x01\x02\x03\x04\x05");
+        assert_eq!(None, field.bytes_first_diff(&a, &a));
+        assert_eq!(
+            Some(3),
+            field.bytes_first_diff(&a, &bytes(b"\x00\x01\x02\xff\x04\x05"))
+        );
+        assert_eq!(Some(0), field.bytes_first_diff(&a, &bytes(b"\xff")));
+        // Prefix.
+        assert_eq!(
+            Some(4),
+            field.bytes_first_diff(&a, &bytes(b"\x00\x01\x02\x03"))
+        );
+        assert_eq!(Some(0), field.bytes_first_diff(&BytesValue::new(), &a));
+        assert_eq!(
+            None,
+            field.bytes_first_diff(&BytesValue::new(), &BytesValue::new())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Not a bytes field")]
+    #[cfg_attr(miri, ignore)]
+    fn bytes_first_diff_not_bytes() {
+        let field = FieldDescriptorProto::descriptor()
+            .field_by_name("name")
+            .unwrap();
+        let m = FieldDescriptorProto::new();
+        field.bytes_first_diff(&m, &m);
+    }
 }
@@ -0,0 +1,183 @@
+use std::mem;
+
+use crate::message_dyn::MessageDyn;
+use crate::reflect::ReflectFieldRef;
+use crate::reflect::ReflectValueRef;
+use crate::reflect::RuntimeType;
+
+/// Approximate number of heap bytes used by message field values.
+///
+/// Sums lengths of strings and byte arrays, buffers of repeated
+/// and map fields, and sizes of nested messages (recursively).
+/// This is memory use, not the serialized size
+/// (see [`compute_size_dyn`](crate::MessageDyn::compute_size_dyn) for that).
+///
+/// Container sizes are computed from the number of elements,
+/// use [`heap_size_with_capacity`] to include reserved capacity.
+/// The size is approximate: allocator overhead, hash table control bytes
+/// and unknown fields are not counted.
+pub fn heap_size(m: &dyn MessageDyn) -> usize {
+    message_heap_size(m, false)
+}
+
+/// Like [`heap_size`], but repeated field and map buffers are counted
+/// by their capacity instead of length.
+///
+/// Capacity of strings and byte arrays is not available via reflection,
+/// so their lengths are counted.
+pub fn heap_size_with_capacity(m: &dyn MessageDyn) -> usize {
+    message_heap_size(m, true)
+}
+
+fn message_heap_size(m: &dyn MessageDyn, capacity: bool) -> usize {
+    // Generated messages store messages of repeated fields, maps and oneofs inline,
+    // dynamic messages box all message values.
+    let generated = m.descriptor_dyn().default_instance().is_some();
+    let mut size = 0;
+    for field in m.descriptor_dyn().fields() {
+        match field.get_reflect(m) {
+            ReflectFieldRef::Optional(v) => {
+                if let Some(v) = v.value() {
+                    let boxed = !generated || field.containing_oneof().is_none();
+                    size += box_size(&v, boxed);
+                    size += value_heap_size(&v, capacity);
+                }
+            }
+            ReflectFieldRef::Repeated(r) => {
+                let len = if capacity { r.capacity() } else { r.len() };
+                size += len * stored_size(&r.element_type(), generated);
+                for v in &r {
+                    size += box_size(&v, !generated);
+                    size += value_heap_size(&v, capacity);
+                }
+            }
+            ReflectFieldRef::Map(map) => {
+                let len = if capacity { map.capacity() } else { map.len() };
+                size += len
+                    * (stored_size(&map.key_type(), generated)
+                        + stored_size(&map.value_type(), generated));
+                for (k, v) in &map {
+                    size += value_heap_size(&k, capacity);
+                    size += box_size(&v, !generated);
+                    size += value_heap_size(&v, capacity);
+                }
+            }
+        }
+    }
+    size
+}
+
+/// Heap size of the value, not including the value itself.
+fn value_heap_size(v: &ReflectValueRef, capacity: bool) -> usize {
+    match v {
+        ReflectValueRef::String(s) => s.len(),
+        ReflectValueRef::Bytes(b) => b.len(),
+        ReflectValueRef::Message(m) => message_heap_size(&**m, capacity),
+        _ => 0,
+    }
+}
+
+/// Size of the box holding the message value, if it is boxed.
+fn box_size(v: &ReflectValueRef, boxed: bool) -> usize {
+    match v {
+        ReflectValueRef::Message(m) if boxed => mem::size_of_val(&**m),
+        _ => 0,
+    }
+}
+
+/// Size of value stored in a container.
+fn stored_size(t: &RuntimeType, generated: bool) -> usize {
+    match t {
+        RuntimeType::I32 | RuntimeType::U32 | RuntimeType::F32 | RuntimeType::Enum(..) => 4,
+        RuntimeType::I64 | RuntimeType::U64 | RuntimeType::F64 => 8,
+        RuntimeType::Bool => 1,
+        RuntimeType::String => mem::size_of::<String>(),
+        RuntimeType::VecU8 => mem::size_of::<Vec<u8>>(),
+        RuntimeType::Message(d) => match d.default_instance() {
+            Some(m) if generated => mem::size_of_val(m),
+            _ => mem::size_of::<Box<dyn MessageDyn>>(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::heap_size;
+    use super::heap_size_with_capacity;
+    use crate::descriptor::DescriptorProto;
+    use crate::descriptor::FieldDescriptorProto;
+    use crate::reflect::FileDescriptor;
+    use crate::well_known_types::duration::Duration;
+    use crate::well_known_types::struct_;
+    use crate::well_known_types::struct_::ListValue;
+    use crate::well_known_types::struct_::Struct;
+    use crate::well_known_types::struct_::Value;
+    use crate::Message;
+
+    #[test]
+    fn test_scalars_only() {
+        let mut d = Duration::new();
+        d.seconds = 10;
+        assert_eq!(0, heap_size(&d));
+    }
+
+    #[test]
+    fn test_large_string() {
+        let mut d = DescriptorProto::new();
+        d.set_name("x".repeat(10_000));
+        assert!(heap_size(&d) >= 10_000);
+    }
+
+    #[test]
+    fn test_nested_and_capacity() {
+        let mut d = DescriptorProto::new();
+        let mut field = FieldDescriptorProto::new();
+        field.set_name("y".repeat(1000));
+        d.field.push(field);
+        let size = heap_size(&d);
+        assert!(size >= 1000 + std::mem::size_of::<FieldDescriptorProto>());
+
+        d.field.reserve(100);
+        assert_eq!(size, heap_size(&d));
+        assert!(
+            heap_size_with_capacity(&d) >= size + 99 * std::mem::size_of::<FieldDescriptorProto>()
+        );
+    }
+
+    #[test]
+    fn test_map() {
+        let mut s = Struct::new();
+        let mut v = Value::new();
+        v.set_string_value("z".repeat(1000));
+        s.fields.insert("k".repeat(100), v);
+        assert!(heap_size(&s) >= 1100);
+    }
+
+    #[test]
+    fn test_oneof_inline() {
+        // Oneof message members are stored inline in generated messages.
+        let mut v = Value::new();
+        v.set_list_value(ListValue::new());
+        assert_eq!(0, heap_size(&v));
+        let mut list = ListValue::new();
+        list.values.push(Value::new());
+        v.set_list_value(list);
+        assert_eq!(std::mem::size_of::<Value>(), heap_size(&v));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_dynamic_boxed() {
+        let mut v = Value::new();
+        v.set_list_value(ListValue::new());
+        let file =
+            FileDescriptor::new_dynamic(struct_::file_descriptor().proto().clone(), &[]).unwrap();
+        let dynamic = file
+            .message_by_package_relative_name("Value")
+            .unwrap()
+            .parse_from_bytes(&v.write_to_bytes().unwrap())
+            .unwrap();
+        // Dynamic messages box message values.
+        assert!(heap_size(&*dynamic) > 0);
+    }
+}
@@ -1,4 +1,6 @@
+use std::collections::btree_map;
 use std::collections::hash_map;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::hash::Hash;
 
@@ -7,6 +9,7 @@ use crate::reflect::map::ReflectMapIter;
 use crate::reflect::map::ReflectMapIterTrait;
 use crate::reflect::runtime_types::RuntimeTypeHashable;
 use crate::reflect::runtime_types::RuntimeTypeTrait;
+use crate::reflect::value::value_ref::ReflectValueMut;
 use crate::reflect::ProtobufValue;
 use crate::reflect::ReflectValueBox;
 use crate::reflect::ReflectValueRef;
@@ -26,6 +29,10 @@ where
         HashMap::len(self)
     }
 
+    fn capacity(&self) -> usize {
+        HashMap::capacity(self)
+    }
+
     fn is_empty(&self) -> bool {
         self.is_empty()
     }
@@ -40,6 +47,10 @@ where
         self.insert(key, value);
     }
 
+    fn values_mut<'a>(&'a mut self) -> Box<dyn Iterator<Item = ReflectValueMut<'a>> + 'a> {
+        Box::new(self.values_mut().map(V::RuntimeType::as_mut))
+    }
+
     fn clear(&mut self) {
         self.clear();
     }
@@ -75,3 +86,76 @@ impl<'a, K: ProtobufValue + Eq + Hash, V: ProtobufValue> ReflectMapIterTrait<'a>
         V::RuntimeType::runtime_type_box()
     }
 }
+
+impl<K, V> ReflectMap for BTreeMap<K, V>
+where
+    K: ProtobufValue + Ord,
+    V: ProtobufValue,
+    K::RuntimeType: RuntimeTypeHashable,
+{
+    fn reflect_iter<'a>(&'a self) -> ReflectMapIter<'a> {
+        ReflectMapIter::new(GeneratedBTreeMapIterImpl::<'a, K, V> { iter: self.iter() })
+    }
+
+    fn len(&self) -> usize {
+        BTreeMap::len(self)
+    }
+
+    fn capacity(&self) -> usize {
+        // Nodes are allocated as entries are inserted.
+        BTreeMap::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+
+    fn get<'a>(&'a self, key: ReflectValueRef) -> Option<ReflectValueRef<'a>> {
+        <K::RuntimeType as RuntimeTypeHashable>::btree_map_get(self, key)
+            .map(V::RuntimeType::as_ref)
+    }
+
+    fn insert(&mut self, key: ReflectValueBox, value: ReflectValueBox) {
+        let key: K = key.downcast().expect("wrong key type");
+        let value: V = value.downcast().expect("wrong value type");
+        self.insert(key, value);
+    }
+
+    fn values_mut<'a>(&'a mut self) -> Box<dyn Iterator<Item = ReflectValueMut<'a>> + 'a> {
+        Box::new(self.values_mut().map(V::RuntimeType::as_mut))
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+
+    fn key_type(&self) -> RuntimeType {
+        K::RuntimeType::runtime_type_box()
+    }
+
+    fn value_type(&self) -> RuntimeType {
+        V::RuntimeType::runtime_type_box()
+    }
+}
+
+struct GeneratedBTreeMapIterImpl<'a, K: 'static, V: 'static> {
+    iter: btree_map::Iter<'a, K, V>,
+}
+
+impl<'a, K: ProtobufValue, V: ProtobufValue> ReflectMapIterTrait<'a>
+    for GeneratedBTreeMapIterImpl<'a, K, V>
+{
+    fn next(&mut self) -> Option<(ReflectValueRef<'a>, ReflectValueRef<'a>)> {
+        self.iter
+            .next()
+            .map(|(k, v)| (K::RuntimeType::as_ref(k), V::RuntimeType::as_ref(v)))
+    }
+
+    fn key_type(&self) -> RuntimeType {
+        K::RuntimeType::runtime_type_box()
+    }
+
+    fn value_type(&self) -> RuntimeType {
+        V::RuntimeType::runtime_type_box()
+    }
+}
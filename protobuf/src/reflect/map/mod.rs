@@ -5,6 +5,7 @@ use std::fmt::Formatter;
 use crate::reflect::map::empty::DynamicEmptyMap;
 use crate::reflect::reflect_eq::ReflectEq;
 use crate::reflect::reflect_eq::ReflectEqMode;
+use crate::reflect::value::value_ref::ReflectValueMut;
 use crate::reflect::ReflectValueBox;
 use crate::reflect::ReflectValueRef;
 use crate::reflect::RuntimeType;
@@ -20,10 +21,18 @@ pub(crate) trait ReflectMap: Debug + Send + Sync + 'static {
 
     fn is_empty(&self) -> bool;
 
+    /// Number of entries the map can hold without reallocation.
+    fn capacity(&self) -> usize;
+
     fn get<'a>(&'a self, key: ReflectValueRef) -> Option<ReflectValueRef<'a>>;
 
     fn insert(&mut self, key: ReflectValueBox, value: ReflectValueBox);
 
+    /// Mutable references to message values, in unspecified order.
+    ///
+    /// Panics when iterated if the value type is not a message.
+    fn values_mut<'a>(&'a mut self) -> Box<dyn Iterator<Item = ReflectValueMut<'a>> + 'a>;
+
     fn clear(&mut self);
 
     fn key_type(&self) -> RuntimeType;
@@ -131,6 +140,13 @@ impl<'a> ReflectMapRef<'a> {
         }
     }
 
+    pub(crate) fn capacity(&self) -> usize {
+        match &self.imp {
+            ReflectMapRefImpl::Generated(map) => map.capacity(),
+            ReflectMapRefImpl::DynamicEmpty(..) => 0,
+        }
+    }
+
     /// Find a value by given key.
     pub fn get(&self, key: ReflectValueRef) -> Option<ReflectValueRef> {
         match &self.imp {
@@ -235,6 +251,15 @@ impl<'a> ReflectMapMut<'a> {
         self.map.insert(key, value)
     }
 
+    /// Mutable references to message values, in unspecified order.
+    ///
+    /// # Panics
+    ///
+    /// If the value type is not a message.
+    pub(crate) fn values_mut(&mut self) -> impl Iterator<Item = ReflectValueMut<'_>> {
+        self.map.values_mut()
+    }
+
     /// Clear
     pub fn clear(&mut self) {
         self.map.clear();
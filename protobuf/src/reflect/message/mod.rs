@@ -69,7 +69,10 @@ impl MessageDescriptor {
         M::descriptor()
     }
 
-    /// Messages declared in this messages.
+    /// Messages declared in this message.
+    ///
+    /// Only direct children are returned (including map entry messages),
+    /// call this function recursively to traverse all nested messages.
     pub fn nested_messages(&self) -> impl Iterator<Item = MessageDescriptor> + '_ {
         self.index_entry()
             .nested_messages
@@ -78,6 +81,8 @@ impl MessageDescriptor {
     }
 
     /// Get enums declared in this message.
+    ///
+    /// Enums declared in nested messages are not included.
     pub fn nested_enums(&self) -> impl Iterator<Item = EnumDescriptor> + '_ {
         self.index_entry()
             .nested_enums
@@ -218,6 +223,7 @@ impl MessageDescriptor {
     }
 
     /// Fully qualified protobuf message name
+    /// (package followed by `name_to_package`, without leading dot).
     pub fn full_name(&self) -> &str {
         &self.index_entry().full_name
     }
@@ -227,6 +233,11 @@ impl MessageDescriptor {
         &self.index_entry().name_to_package
     }
 
+    /// Package of the file where the message is declared, empty if no package.
+    pub fn package(&self) -> &str {
+        self.file_descriptor.package()
+    }
+
     /// Nested oneofs including synthetic.
     pub fn all_oneofs<'a>(&'a self) -> impl Iterator<Item = OneofDescriptor> + 'a {
         self.index_entry()
@@ -248,7 +259,9 @@ impl MessageDescriptor {
         self.all_oneofs().find(|oneof| oneof.name() == name)
     }
 
-    /// Message field descriptors.
+    /// Message field descriptors in the order they are declared in `.proto` file.
+    ///
+    /// Same as [`fields_in_declaration_order`](Self::fields_in_declaration_order).
     pub fn fields<'a>(&'a self) -> impl Iterator<Item = FieldDescriptor> + 'a {
         self.index()
             .message_index
@@ -259,6 +272,20 @@ impl MessageDescriptor {
             })
     }
 
+    /// Message field descriptors in the order they are declared in `.proto` file.
+    pub fn fields_in_declaration_order<'a>(&'a self) -> impl Iterator<Item = FieldDescriptor> + 'a {
+        self.fields()
+    }
+
+    /// Message field descriptors ordered by field number.
+    ///
+    /// This is the order in which fields are serialized.
+    pub fn fields_by_number(&self) -> impl Iterator<Item = FieldDescriptor> {
+        let mut fields: Vec<FieldDescriptor> = self.fields().collect();
+        fields.sort_by_key(|f| f.number());
+        fields.into_iter()
+    }
+
     /// Extension fields.
     pub fn extensions(&self) -> impl Iterator<Item = FieldDescriptor> + '_ {
         self.index()
@@ -346,7 +373,10 @@ mod test {
     use crate::descriptor::descriptor_proto::ExtensionRange;
     use crate::descriptor::field_descriptor_proto::Type;
     use crate::descriptor::DescriptorProto;
+    use crate::descriptor::EnumDescriptorProto;
     use crate::descriptor::FieldDescriptorProto;
+    use crate::descriptor::FileDescriptorProto;
+    use crate::reflect::FileDescriptor;
     use crate::EnumFull;
     use crate::MessageFull;
 
@@ -368,6 +398,45 @@ mod test {
             .contains(&Type::enum_descriptor()));
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)] // Too slow on Miri.
+    fn nested_dynamic() {
+        let mut deep = DescriptorProto::new();
+        deep.set_name("Deep".to_owned());
+        let mut inner = DescriptorProto::new();
+        inner.set_name("Inner".to_owned());
+        inner.nested_type.push(deep);
+        let mut inner_enum = EnumDescriptorProto::new();
+        inner_enum.set_name("InnerEnum".to_owned());
+        let mut outer = DescriptorProto::new();
+        outer.set_name("Outer".to_owned());
+        outer.nested_type.push(inner);
+        outer.enum_type.push(inner_enum);
+        let mut file = FileDescriptorProto::new();
+        file.set_name("nested.proto".to_owned());
+        file.message_type.push(outer);
+
+        let file = FileDescriptor::new_dynamic(file, &[]).unwrap();
+        let outer = file.message_by_package_relative_name("Outer").unwrap();
+
+        let messages: Vec<_> = outer.nested_messages().collect();
+        assert_eq!(
+            vec!["Outer.Inner"],
+            messages.iter().map(|m| m.full_name()).collect::<Vec<_>>()
+        );
+        let enums: Vec<_> = outer.nested_enums().collect();
+        assert_eq!(
+            vec!["Outer.InnerEnum"],
+            enums.iter().map(|e| e.full_name()).collect::<Vec<_>>()
+        );
+
+        let deep: Vec<_> = messages[0].nested_messages().collect();
+        assert_eq!(1, deep.len());
+        assert_eq!("Outer.Inner.Deep", deep[0].full_name());
+        assert_eq!(Some(messages[0].clone()), deep[0].enclosing_message());
+        assert_eq!(0, messages[0].nested_enums().count());
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)] // Too slow on Miri.
     fn enclosing_message() {
@@ -377,4 +446,104 @@ mod test {
         );
         assert_eq!(None, DescriptorProto::descriptor().enclosing_message());
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Too slow on Miri.
+    fn names() {
+        let d = ExtensionRange::descriptor();
+        assert_eq!("ExtensionRange", d.name());
+        assert_eq!("DescriptorProto.ExtensionRange", d.name_to_package());
+        assert_eq!(
+            "google.protobuf.DescriptorProto.ExtensionRange",
+            d.full_name()
+        );
+        assert_eq!("google.protobuf", d.package());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Too slow on Miri.
+    fn names_dynamic() {
+        for (package, full_name) in [("", "Outer.Inner"), ("foo.bar", "foo.bar.Outer.Inner")] {
+            let mut inner = DescriptorProto::new();
+            inner.set_name("Inner".to_owned());
+            let mut outer = DescriptorProto::new();
+            outer.set_name("Outer".to_owned());
+            outer.nested_type.push(inner);
+            let mut file = FileDescriptorProto::new();
+            file.set_name("names.proto".to_owned());
+            if !package.is_empty() {
+                file.set_package(package.to_owned());
+            }
+            file.message_type.push(outer);
+
+            let file = FileDescriptor::new_dynamic(file, &[]).unwrap();
+            let inner = file
+                .message_by_full_name(&format!(".{}", full_name))
+                .unwrap();
+            assert_eq!("Inner", inner.name());
+            assert_eq!("Outer.Inner", inner.name_to_package());
+            assert_eq!(full_name, inner.full_name());
+            assert_eq!(package, inner.package());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Too slow on Miri.
+    fn fields_order() {
+        let d = FieldDescriptorProto::descriptor();
+        let declared: Vec<i32> = d
+            .fields_in_declaration_order()
+            .map(|f| f.number())
+            .collect();
+        assert_eq!(vec![1, 3, 4, 5, 6, 2, 7, 9, 10, 8, 17], declared);
+        assert_eq!(declared, d.fields().map(|f| f.number()).collect::<Vec<_>>());
+        let by_number: Vec<String> = d.fields_by_number().map(|f| f.name().to_owned()).collect();
+        assert_eq!(
+            vec![
+                "name",
+                "extendee",
+                "number",
+                "label",
+                "type",
+                "type_name",
+                "default_value",
+                "options",
+                "oneof_index",
+                "json_name",
+                "proto3_optional",
+            ],
+            by_number
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Too slow on Miri.
+    fn fields_order_dynamic() {
+        let mut message = DescriptorProto::new();
+        message.set_name("M".to_owned());
+        for (name, number) in [("c", 3), ("a", 1), ("b", 2)] {
+            let mut field = FieldDescriptorProto::new();
+            field.set_name(name.to_owned());
+            field.set_number(number);
+            field.set_type(Type::TYPE_INT32);
+            message.field.push(field);
+        }
+        let mut file = FileDescriptorProto::new();
+        file.set_name("order.proto".to_owned());
+        file.message_type.push(message);
+
+        let file = FileDescriptor::new_dynamic(file, &[]).unwrap();
+        let m = file.message_by_package_relative_name("M").unwrap();
+        let names = |fields: Vec<crate::reflect::FieldDescriptor>| {
+            fields
+                .iter()
+                .map(|f| f.name().to_owned())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            vec!["c", "a", "b"],
+            names(m.fields_in_declaration_order().collect())
+        );
+        assert_eq!(vec!["a", "b", "c"], names(m.fields_by_number().collect()));
+    }
 }
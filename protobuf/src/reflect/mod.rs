@@ -14,12 +14,17 @@
 //! Some minor adjustements are made to make code more idiomatic to rust.
 
 mod acc;
+mod clone_without_unknowns;
+mod copy_compatible;
+mod debug_string;
 mod dynamic;
+mod dynamic_schema;
 mod enums;
 pub(crate) mod error;
 mod field;
 mod file;
 mod find_message_or_enum;
+mod heap_size;
 mod map;
 pub(crate) mod message;
 mod oneof;
@@ -28,8 +33,10 @@ mod protobuf_type_box;
 mod repeated;
 mod runtime_type_box;
 mod service;
+mod set_fields;
 mod type_dynamic;
 pub(crate) mod value;
+mod walk_mut;
 
 // Runtime type types are public, but not visible in public API.
 pub(crate) mod runtime_types;
@@ -42,10 +49,16 @@ pub mod rt;
 
 pub(crate) mod name;
 
+pub use self::clone_without_unknowns::clone_without_unknowns;
+pub use self::copy_compatible::copy_compatible;
+pub use self::debug_string::debug_string;
+pub use self::dynamic_schema::load_descriptor_set;
+pub use self::dynamic_schema::DynamicSchema;
 #[doc(hidden)]
 pub use self::enums::generated::GeneratedEnumDescriptorData;
 pub use self::enums::EnumDescriptor;
 pub use self::enums::EnumValueDescriptor;
+pub use self::field::meta::FieldMeta;
 pub use self::field::runtime_field_type::RuntimeFieldType;
 pub use self::field::FieldDescriptor;
 pub use self::field::ReflectFieldRef;
@@ -53,6 +66,8 @@ pub use self::field::ReflectFieldRef;
 pub use self::file::generated::GeneratedFileDescriptor;
 pub use self::file::syntax::Syntax;
 pub use self::file::FileDescriptor;
+pub use self::heap_size::heap_size;
+pub use self::heap_size::heap_size_with_capacity;
 pub use self::map::ReflectMapMut;
 pub use self::map::ReflectMapRef;
 #[doc(hidden)]
@@ -70,6 +85,9 @@ pub use self::repeated::ReflectRepeatedRef;
 pub use self::runtime_type_box::RuntimeType;
 pub use self::service::MethodDescriptor;
 pub use self::service::ServiceDescriptor;
+pub use self::set_fields::set_fields;
 pub use self::value::value_box::ReflectValueBox;
 pub use self::value::value_ref::ReflectValueRef;
 pub use self::value::ProtobufValue;
+pub use self::walk_mut::walk_mut;
+pub use self::walk_mut::WalkMutAction;
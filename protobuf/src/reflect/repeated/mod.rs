@@ -14,6 +14,7 @@ use crate::reflect::repeated::iter::ReflectRepeatedIter;
 use crate::reflect::repeated::transmute::transmute_ref_if_eq;
 use crate::reflect::repeated::vec_downcast::VecMutVariant;
 use crate::reflect::runtime_types::RuntimeTypeTrait;
+use crate::reflect::value::value_ref::ReflectValueMut;
 use crate::reflect::value::value_ref::ReflectValueRef;
 use crate::reflect::ProtobufValue;
 use crate::reflect::ReflectValueBox;
@@ -23,7 +24,16 @@ pub(crate) trait ReflectRepeated: Sync + 'static + fmt::Debug {
     fn reflect_iter(&self) -> ReflectRepeatedIter;
     fn reflect_drain_iter(&mut self) -> ReflectRepeatedDrainIter;
     fn len(&self) -> usize;
+    /// Number of elements the container can hold without reallocation.
+    fn capacity(&self) -> usize;
     fn get(&self, index: usize) -> ReflectValueRef;
+    /// Mutable reference to message element at index.
+    ///
+    /// # Panics
+    ///
+    /// * if index is out of bounds
+    /// * if the element type is not a message
+    fn get_mut(&mut self, index: usize) -> ReflectValueMut<'_>;
     /// Set element at index.
     ///
     /// # Panics
@@ -37,6 +47,19 @@ pub(crate) trait ReflectRepeated: Sync + 'static + fmt::Debug {
     ///
     /// * if the element type does not match the collection element type
     fn push(&mut self, value: ReflectValueBox);
+    /// Insert element at index, shifting all elements after it to the right.
+    ///
+    /// # Panics
+    ///
+    /// * if `index > len`
+    /// * if the element type does not match the collection element type
+    fn insert(&mut self, index: usize, value: ReflectValueBox);
+    /// Remove element at index, replacing it with the last element.
+    ///
+    /// # Panics
+    ///
+    /// * if index is out of bounds
+    fn swap_remove(&mut self, index: usize) -> ReflectValueBox;
 
     fn reflect_extend(&mut self, values: ReflectRepeatedMut);
 
@@ -87,10 +110,18 @@ impl<V: ProtobufValue> ReflectRepeated for Vec<V> {
         Vec::len(self)
     }
 
+    fn capacity(&self) -> usize {
+        Vec::capacity(self)
+    }
+
     fn get(&self, index: usize) -> ReflectValueRef {
         V::RuntimeType::as_ref(&self[index])
     }
 
+    fn get_mut(&mut self, index: usize) -> ReflectValueMut<'_> {
+        V::RuntimeType::as_mut(&mut self[index])
+    }
+
     fn set(&mut self, index: usize, value: ReflectValueBox) {
         let value = value.downcast().expect("wrong type");
         self[index] = value;
@@ -101,6 +132,15 @@ impl<V: ProtobufValue> ReflectRepeated for Vec<V> {
         self.push(value)
     }
 
+    fn insert(&mut self, index: usize, value: ReflectValueBox) {
+        let value = value.downcast().expect("wrong type");
+        self.insert(index, value)
+    }
+
+    fn swap_remove(&mut self, index: usize) -> ReflectValueBox {
+        V::RuntimeType::into_value_box(self.swap_remove(index))
+    }
+
     fn reflect_extend(&mut self, values: ReflectRepeatedMut) {
         match VecMutVariant::downcast(self) {
             Some(VecMutVariant::U32(v)) => v.extend(values.repeated.data_u32()),
@@ -221,6 +261,13 @@ impl<'a> ReflectRepeatedRef<'a> {
         self.len() == 0
     }
 
+    pub(crate) fn capacity(&self) -> usize {
+        match &self.imp {
+            ReflectRepeatedRefImpl::Generated(g) => g.capacity(),
+            ReflectRepeatedRefImpl::DynamicEmpty(..) => 0,
+        }
+    }
+
     /// Get item by index
     // TODO: replace with index
     pub fn get(&self, index: usize) -> ReflectValueRef<'a> {
@@ -390,6 +437,15 @@ impl<'a> ReflectRepeatedMut<'a> {
         self.repeated.element_type()
     }
 
+    /// Mutable reference to message element at index.
+    ///
+    /// # Panics
+    ///
+    /// If index is out of range or element type is not a message.
+    pub(crate) fn get_mut(&mut self, index: usize) -> ReflectValueMut<'_> {
+        self.repeated.get_mut(index)
+    }
+
     /// Set a value at given index.
     ///
     /// # Panics
@@ -408,6 +464,26 @@ impl<'a> ReflectRepeatedMut<'a> {
         self.repeated.push(value);
     }
 
+    /// Insert an item at given index, shifting all items after it to the right,
+    /// like [`Vec::insert`].
+    ///
+    /// # Panics
+    ///
+    /// If `index > len` or value type does not match container element type
+    pub fn insert(&mut self, index: usize, value: ReflectValueBox) {
+        self.repeated.insert(index, value);
+    }
+
+    /// Remove an item at given index and return it, replacing it with the last item,
+    /// like [`Vec::swap_remove`].
+    ///
+    /// # Panics
+    ///
+    /// If index is out of range
+    pub fn swap_remove(&mut self, index: usize) -> ReflectValueBox {
+        self.repeated.swap_remove(index)
+    }
+
     pub(crate) fn extend(&mut self, values: ReflectRepeatedMut) {
         self.repeated.reflect_extend(values);
     }
@@ -519,3 +595,130 @@ impl<'a> PartialEq<ReflectRepeatedMut<'a>> for Vec<ReflectValueBox> {
         self.as_slice() == other
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::descriptor::FileDescriptorProto;
+    use crate::reflect::FileDescriptor;
+    use crate::reflect::ReflectValueBox;
+    use crate::reflect::RuntimeFieldType;
+    use crate::reflect::RuntimeType;
+    use crate::MessageDyn;
+
+    fn generated() -> Box<dyn MessageDyn> {
+        Box::new(FileDescriptorProto::new())
+    }
+
+    fn dynamic() -> Box<dyn MessageDyn> {
+        let file =
+            FileDescriptor::new_dynamic(crate::descriptor::file_descriptor().proto().clone(), &[])
+                .unwrap();
+        file.message_by_package_relative_name("FileDescriptorProto")
+            .unwrap()
+            .new_instance()
+    }
+
+    fn dependency(m: &dyn MessageDyn) -> Vec<String> {
+        let field = m.descriptor_dyn().field_by_name("dependency").unwrap();
+        field
+            .get_repeated(m)
+            .into_iter()
+            .map(|v| v.to_str().unwrap().to_owned())
+            .collect()
+    }
+
+    fn s(s: &str) -> ReflectValueBox {
+        ReflectValueBox::String(s.to_owned())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_insert_swap_remove_like_vec() {
+        for mut m in [generated(), dynamic()] {
+            let field = m.descriptor_dyn().field_by_name("dependency").unwrap();
+            let mut expected: Vec<String> = Vec::new();
+            for (index, value) in [(0, "b"), (0, "a"), (2, "d"), (2, "c"), (1, "e")] {
+                field.mut_repeated(&mut *m).insert(index, s(value));
+                expected.insert(index, value.to_owned());
+                assert_eq!(expected, dependency(&*m));
+            }
+
+            let removed = field.mut_repeated(&mut *m).swap_remove(0);
+            assert_eq!(s(&expected.swap_remove(0)), removed);
+            assert_eq!(expected, dependency(&*m));
+
+            let removed = field.mut_repeated(&mut *m).swap_remove(2);
+            assert_eq!(s(&expected.swap_remove(2)), removed);
+            assert_eq!(expected, dependency(&*m));
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_swap_remove_message() {
+        for mut m in [generated(), dynamic()] {
+            let field = m.descriptor_dyn().field_by_name("message_type").unwrap();
+            let element = match field.runtime_field_type() {
+                RuntimeFieldType::Repeated(RuntimeType::Message(d)) => d,
+                _ => panic!("expecting repeated message field"),
+            };
+            let mut repeated = field.mut_repeated(&mut *m);
+            for name in ["x", "y"] {
+                let mut value = element.new_instance();
+                let name_field = value.descriptor_dyn().field_by_name("name").unwrap();
+                name_field.set_singular_field(&mut *value, s(name));
+                repeated.push(ReflectValueBox::Message(value));
+            }
+            let removed = match repeated.swap_remove(0) {
+                ReflectValueBox::Message(m) => m,
+                v => panic!("{:?}", v),
+            };
+            let name_field = removed.descriptor_dyn().field_by_name("name").unwrap();
+            assert_eq!(
+                "x",
+                name_field
+                    .get_singular(&*removed)
+                    .unwrap()
+                    .to_str()
+                    .unwrap()
+            );
+            assert_eq!(1, repeated.len());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg_attr(miri, ignore)]
+    fn test_insert_out_of_bounds() {
+        let mut m = generated();
+        let field = m.descriptor_dyn().field_by_name("dependency").unwrap();
+        field.mut_repeated(&mut *m).insert(1, s("a"));
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg_attr(miri, ignore)]
+    fn test_insert_out_of_bounds_dynamic() {
+        let mut m = dynamic();
+        let field = m.descriptor_dyn().field_by_name("dependency").unwrap();
+        field.mut_repeated(&mut *m).insert(1, s("a"));
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg_attr(miri, ignore)]
+    fn test_swap_remove_out_of_bounds() {
+        let mut m = generated();
+        let field = m.descriptor_dyn().field_by_name("dependency").unwrap();
+        field.mut_repeated(&mut *m).swap_remove(0);
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg_attr(miri, ignore)]
+    fn test_swap_remove_out_of_bounds_dynamic() {
+        let mut m = dynamic();
+        let field = m.descriptor_dyn().field_by_name("dependency").unwrap();
+        field.mut_repeated(&mut *m).swap_remove(0);
+    }
+}
@@ -132,6 +132,27 @@ impl RuntimeType {
     }
 }
 
+impl RuntimeType {
+    /// Parse value from user-provided string, e. g. command line argument.
+    ///
+    /// Enums are accepted by name or by number,
+    /// bytes are taken as UTF-8 bytes of the string.
+    pub(crate) fn parse_from_str(&self, value: &str) -> Option<ReflectValueBox> {
+        match self {
+            RuntimeType::Enum(e) => {
+                let n = match e.value_by_name(value) {
+                    Some(v) => v.value(),
+                    None => value.parse().ok()?,
+                };
+                Some(ReflectValueBox::Enum(e.clone(), n))
+            }
+            RuntimeType::VecU8 => Some(ReflectValueBox::Bytes(value.as_bytes().to_vec())),
+            RuntimeType::Message(_) => None,
+            t => t.parse_proto_default_value(value).ok(),
+        }
+    }
+}
+
 impl fmt::Display for RuntimeType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
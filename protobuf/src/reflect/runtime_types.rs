@@ -1,5 +1,6 @@
 //! Implementations of `RuntimeType` for all types.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::fmt;
 use std::marker;
@@ -123,6 +124,12 @@ pub trait RuntimeTypeHashable: RuntimeTypeTrait {
     /// Query hash map with a given key.
     fn hash_map_get<'a, V>(map: &'a HashMap<Self::Value, V>, key: ReflectValueRef)
         -> Option<&'a V>;
+
+    /// Query btree map with a given key.
+    fn btree_map_get<'a, V>(
+        map: &'a BTreeMap<Self::Value, V>,
+        key: ReflectValueRef,
+    ) -> Option<&'a V>;
 }
 
 /// Implementation for `f32`
@@ -318,6 +325,13 @@ impl RuntimeTypeHashable for RuntimeTypeI32 {
             _ => None,
         }
     }
+
+    fn btree_map_get<'a, V>(map: &'a BTreeMap<i32, V>, key: ReflectValueRef) -> Option<&'a V> {
+        match key {
+            ReflectValueRef::I32(i) => map.get(&i),
+            _ => None,
+        }
+    }
 }
 
 impl RuntimeTypeTrait for RuntimeTypeI64 {
@@ -377,6 +391,13 @@ impl RuntimeTypeHashable for RuntimeTypeI64 {
             _ => None,
         }
     }
+
+    fn btree_map_get<'a, V>(map: &'a BTreeMap<i64, V>, key: ReflectValueRef) -> Option<&'a V> {
+        match key {
+            ReflectValueRef::I64(i) => map.get(&i),
+            _ => None,
+        }
+    }
 }
 
 impl RuntimeTypeTrait for RuntimeTypeU32 {
@@ -435,6 +456,13 @@ impl RuntimeTypeHashable for RuntimeTypeU32 {
             _ => None,
         }
     }
+
+    fn btree_map_get<'a, V>(map: &'a BTreeMap<u32, V>, key: ReflectValueRef) -> Option<&'a V> {
+        match key {
+            ReflectValueRef::U32(i) => map.get(&i),
+            _ => None,
+        }
+    }
 }
 
 impl RuntimeTypeTrait for RuntimeTypeU64 {
@@ -493,6 +521,13 @@ impl RuntimeTypeHashable for RuntimeTypeU64 {
             _ => None,
         }
     }
+
+    fn btree_map_get<'a, V>(map: &'a BTreeMap<u64, V>, key: ReflectValueRef) -> Option<&'a V> {
+        match key {
+            ReflectValueRef::U64(i) => map.get(&i),
+            _ => None,
+        }
+    }
 }
 
 impl RuntimeTypeTrait for RuntimeTypeBool {
@@ -548,6 +583,13 @@ impl RuntimeTypeHashable for RuntimeTypeBool {
             _ => None,
         }
     }
+
+    fn btree_map_get<'a, V>(map: &'a BTreeMap<bool, V>, key: ReflectValueRef) -> Option<&'a V> {
+        match key {
+            ReflectValueRef::Bool(i) => map.get(&i),
+            _ => None,
+        }
+    }
 }
 
 impl RuntimeTypeTrait for RuntimeTypeString {
@@ -606,6 +648,13 @@ impl RuntimeTypeHashable for RuntimeTypeString {
             _ => None,
         }
     }
+
+    fn btree_map_get<'a, V>(map: &'a BTreeMap<String, V>, key: ReflectValueRef) -> Option<&'a V> {
+        match key {
+            ReflectValueRef::String(s) => map.get(s),
+            _ => None,
+        }
+    }
 }
 
 impl RuntimeTypeTrait for RuntimeTypeVecU8 {
@@ -770,6 +819,13 @@ impl RuntimeTypeHashable for RuntimeTypeTokioChars {
             _ => None,
         }
     }
+
+    fn btree_map_get<'a, V>(map: &'a BTreeMap<Chars, V>, key: ReflectValueRef) -> Option<&'a V> {
+        match key {
+            ReflectValueRef::String(s) => map.get(s),
+            _ => None,
+        }
+    }
 }
 
 impl<E> RuntimeTypeTrait for RuntimeTypeEnumOrUnknown<E>
@@ -36,6 +36,29 @@ impl ServiceDescriptor {
         &self.file_descriptor.proto().service[self.index]
     }
 
+    /// [`FileDescriptor`] containing this service.
+    pub fn file_descriptor(&self) -> &FileDescriptor {
+        &self.file_descriptor
+    }
+
+    /// Service name as specified in `.proto` file.
+    pub fn name(&self) -> &str {
+        self.proto().name()
+    }
+
+    /// Fully qualified service name, e. g. `foo.bar.Baz`.
+    pub fn full_name(&self) -> String {
+        match self.file_descriptor.package() {
+            "" => self.name().to_owned(),
+            package => format!("{}.{}", package, self.name()),
+        }
+    }
+
+    /// Find method by name.
+    pub fn method_by_name(&self, name: &str) -> Option<MethodDescriptor> {
+        self.methods().find(|m| m.name() == name)
+    }
+
     /// Method descriptors of this service.
     pub fn methods(&self) -> impl Iterator<Item = MethodDescriptor> + '_ {
         let value_len = self.proto().method.len();
@@ -62,6 +85,26 @@ impl MethodDescriptor {
         &self.service_descriptor.proto().method[self.index]
     }
 
+    /// Service containing this method.
+    pub fn service_descriptor(&self) -> &ServiceDescriptor {
+        &self.service_descriptor
+    }
+
+    /// Method name as specified in `.proto` file.
+    pub fn name(&self) -> &str {
+        self.proto().name()
+    }
+
+    /// Client sends a stream of requests.
+    pub fn client_streaming(&self) -> bool {
+        self.proto().client_streaming()
+    }
+
+    /// Server returns a stream of responses.
+    pub fn server_streaming(&self) -> bool {
+        self.proto().server_streaming()
+    }
+
     /// Method input type.
     pub fn input_type(&self) -> MessageDescriptor {
         self.index()
@@ -0,0 +1,92 @@
+use crate::message_dyn::MessageDyn;
+use crate::reflect::FieldDescriptor;
+use crate::reflect::ReflectFieldRef;
+
+/// Iterate over fields set in a message, in field number order.
+///
+/// A field is yielded if [`FieldDescriptor::has_field`] returns `true` for it:
+/// singular fields which are set (or not default for proto3 fields without presence),
+/// and non-empty repeated and map fields.
+///
+/// Repeated and map fields cannot be represented by a single
+/// [`ReflectValueRef`](crate::reflect::ReflectValueRef), so the value is
+/// a [`ReflectFieldRef`]; for singular fields it is always
+/// [`ReflectFieldRef::Optional`] with a value.
+///
+/// Unknown fields are not included.
+pub fn set_fields<'a>(
+    m: &'a dyn MessageDyn,
+) -> impl Iterator<Item = (FieldDescriptor, ReflectFieldRef<'a>)> + 'a {
+    let mut fields: Vec<FieldDescriptor> = m.descriptor_dyn().fields().collect();
+    fields.sort_by_key(|f| f.number());
+    fields
+        .into_iter()
+        .filter(move |f| f.has_field(m))
+        .map(move |f| {
+            let v = f.get_reflect(m);
+            (f, v)
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::set_fields;
+    use crate::descriptor::field_descriptor_proto::Type;
+    use crate::descriptor::FieldDescriptorProto;
+    use crate::reflect::ReflectFieldRef;
+    use crate::reflect::ReflectValueRef;
+    use crate::well_known_types::struct_::ListValue;
+    use crate::well_known_types::struct_::Value;
+    use crate::EnumFull;
+
+    #[test]
+    fn test_only_set_fields_in_number_order() {
+        let mut field = FieldDescriptorProto::new();
+        // `extendee` is field 2, declared after `type_name` (field 6).
+        field.set_type_name("Foo".to_owned());
+        field.set_extendee("Bar".to_owned());
+        field.set_name("foo".to_owned());
+        field.set_type(Type::TYPE_MESSAGE);
+
+        let fields: Vec<_> = set_fields(&field)
+            .map(|(f, v)| {
+                let v = match v {
+                    ReflectFieldRef::Optional(v) => v.value().unwrap().to_box(),
+                    _ => panic!("singular field expected: {}", f),
+                };
+                (f.number(), v)
+            })
+            .collect();
+        assert_eq!(
+            vec![
+                (1, ReflectValueRef::String("foo").to_box()),
+                (2, ReflectValueRef::String("Bar").to_box()),
+                (
+                    5,
+                    ReflectValueRef::Enum(Type::enum_descriptor(), 11).to_box()
+                ),
+                (6, ReflectValueRef::String("Foo").to_box()),
+            ],
+            fields
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(0, set_fields(&FieldDescriptorProto::new()).count());
+        assert_eq!(0, set_fields(&ListValue::new()).count());
+    }
+
+    #[test]
+    fn test_repeated() {
+        let mut list = ListValue::new();
+        list.values.push(Value::new());
+        let fields: Vec<_> = set_fields(&list).collect();
+        assert_eq!(1, fields.len());
+        assert_eq!("values", fields[0].0.name());
+        match &fields[0].1 {
+            ReflectFieldRef::Repeated(r) => assert_eq!(1, r.len()),
+            _ => panic!("repeated field expected"),
+        }
+    }
+}
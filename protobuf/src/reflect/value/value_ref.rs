@@ -368,3 +368,37 @@ impl<'a> From<&'a [u8]> for ReflectValueRef<'a> {
         ReflectValueRef::Bytes(v)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::descriptor::field_descriptor_proto::Type;
+    use crate::EnumFull;
+
+    #[test]
+    fn display_scalar() {
+        assert_eq!("17", ReflectValueRef::U32(17).to_string());
+        assert_eq!("18", ReflectValueRef::U64(18).to_string());
+        assert_eq!("-19", ReflectValueRef::I32(-19).to_string());
+        assert_eq!("-20", ReflectValueRef::I64(-20).to_string());
+        assert_eq!("1.5", ReflectValueRef::F32(1.5).to_string());
+        assert_eq!("-2.5", ReflectValueRef::F64(-2.5).to_string());
+        assert_eq!("true", ReflectValueRef::Bool(true).to_string());
+        assert_eq!("abc", ReflectValueRef::String("abc").to_string());
+    }
+
+    #[test]
+    fn display_enum() {
+        let d = Type::enum_descriptor();
+        assert_eq!(
+            "TYPE_STRING",
+            ReflectValueRef::Enum(d.clone(), Type::TYPE_STRING as i32).to_string()
+        );
+        // Unknown value is displayed as number.
+        assert_eq!("100", ReflectValueRef::Enum(d.clone(), 100).to_string());
+        assert_eq!(
+            RuntimeType::Enum(d.clone()),
+            ReflectValueRef::Enum(d, 100).get_type()
+        );
+    }
+}
@@ -0,0 +1,210 @@
+use crate::message_dyn::MessageDyn;
+use crate::reflect::value::value_ref::ReflectValueMut;
+use crate::reflect::FieldDescriptor;
+use crate::reflect::ReflectValueBox;
+use crate::reflect::ReflectValueRef;
+use crate::reflect::RuntimeFieldType;
+use crate::reflect::RuntimeType;
+
+/// What [`walk_mut`] should do with a visited scalar value.
+#[derive(Debug)]
+pub enum WalkMutAction {
+    /// Leave the value as is.
+    Keep,
+    /// Replace the value with the given one.
+    ///
+    /// The value must have the same type as the field, otherwise `walk_mut` panics.
+    Replace(ReflectValueBox),
+}
+
+/// Recursively visit all scalar (non-message) values of a message,
+/// optionally replacing them in place.
+///
+/// `visitor` is called with the field descriptor and the current value
+/// for each set singular field, each element of a repeated field
+/// and each value of a map field (map keys are not visited).
+/// Enums are passed as [`ReflectValueRef::Enum`].
+///
+/// Set message fields (including repeated elements and map values)
+/// are walked recursively; unset fields are not visited.
+///
+/// # Panics
+///
+/// If the visitor returns a replacement value of a type different from the field type.
+pub fn walk_mut<F>(message: &mut dyn MessageDyn, mut visitor: F)
+where
+    F: FnMut(&FieldDescriptor, ReflectValueRef) -> WalkMutAction,
+{
+    walk_mut_impl(message, &mut visitor);
+}
+
+type Visitor<'a> = dyn FnMut(&FieldDescriptor, ReflectValueRef) -> WalkMutAction + 'a;
+
+fn walk_mut_impl(message: &mut dyn MessageDyn, visitor: &mut Visitor) {
+    for field in message.descriptor_dyn().fields() {
+        if !field.has_field(message) {
+            continue;
+        }
+        match field.runtime_field_type() {
+            RuntimeFieldType::Singular(RuntimeType::Message(_)) => {
+                walk_mut_impl(field.mut_message(message), visitor);
+            }
+            RuntimeFieldType::Singular(_) => {
+                let value = match field.get_singular(message) {
+                    Some(value) => value,
+                    None => continue,
+                };
+                if let WalkMutAction::Replace(value) = visitor(&field, value) {
+                    field.set_singular_field(message, value);
+                }
+            }
+            RuntimeFieldType::Repeated(RuntimeType::Message(_)) => {
+                let mut repeated = field.mut_repeated(message);
+                for i in 0..repeated.len() {
+                    let ReflectValueMut::Message(m) = repeated.get_mut(i);
+                    walk_mut_impl(m, visitor);
+                }
+            }
+            RuntimeFieldType::Repeated(_) => {
+                for i in 0..field.get_repeated(message).len() {
+                    let value = field.get_repeated(message).get(i);
+                    if let WalkMutAction::Replace(value) = visitor(&field, value) {
+                        field.mut_repeated(message).set(i, value);
+                    }
+                }
+            }
+            RuntimeFieldType::Map(_, RuntimeType::Message(_)) => {
+                for value in field.mut_map(message).values_mut() {
+                    let ReflectValueMut::Message(m) = value;
+                    walk_mut_impl(m, visitor);
+                }
+            }
+            RuntimeFieldType::Map(..) => {
+                let map = field.get_map(message);
+                let replaced: Vec<(ReflectValueBox, ReflectValueBox)> = map
+                    .into_iter()
+                    .filter_map(|(k, v)| match visitor(&field, v) {
+                        WalkMutAction::Keep => None,
+                        WalkMutAction::Replace(v) => Some((k.to_box(), v)),
+                    })
+                    .collect();
+                let mut map = field.mut_map(message);
+                for (k, v) in replaced {
+                    map.insert(k, v);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::walk_mut;
+    use super::WalkMutAction;
+    use crate::descriptor::DescriptorProto;
+    use crate::descriptor::FieldDescriptorProto;
+    use crate::reflect::FileDescriptor;
+    use crate::reflect::ReflectValueBox;
+    use crate::reflect::ReflectValueRef;
+    use crate::well_known_types::struct_;
+    use crate::well_known_types::struct_::ListValue;
+    use crate::well_known_types::struct_::Struct;
+    use crate::well_known_types::struct_::Value;
+    use crate::Message;
+
+    fn uppercase(v: ReflectValueRef) -> WalkMutAction {
+        match v {
+            ReflectValueRef::String(s) => {
+                WalkMutAction::Replace(ReflectValueBox::String(s.to_uppercase()))
+            }
+            _ => WalkMutAction::Keep,
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_uppercase_nested() {
+        let mut field = FieldDescriptorProto::new();
+        field.set_name("foo".to_owned());
+        field.set_number(1);
+        field.options.mut_or_insert_default();
+        let mut m = DescriptorProto::new();
+        m.set_name("bar".to_owned());
+        m.field.push(field);
+        m.reserved_name = vec!["baz".to_owned(), "qux".to_owned()];
+        let mut nested = DescriptorProto::new();
+        nested.set_name("nested".to_owned());
+        m.nested_type.push(nested);
+
+        walk_mut(&mut m, |_, v| uppercase(v));
+
+        assert_eq!("BAR", m.name());
+        assert_eq!("FOO", m.field[0].name());
+        assert_eq!(1, m.field[0].number());
+        assert!(m.field[0].options.is_some());
+        assert_eq!(vec!["BAZ".to_owned(), "QUX".to_owned()], m.reserved_name);
+        assert_eq!("NESTED", m.nested_type[0].name());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_unset_not_visited() {
+        let mut m = FieldDescriptorProto::new();
+        m.set_name("foo".to_owned());
+        let mut visited = Vec::new();
+        walk_mut(&mut m, |f, _| {
+            visited.push(f.name().to_owned());
+            WalkMutAction::Keep
+        });
+        assert_eq!(vec!["name".to_owned()], visited);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_clamp_and_map_values() {
+        let mut s = Struct::new();
+        let mut v = Value::new();
+        v.set_number_value(100.0);
+        s.fields.insert("a".to_owned(), v);
+        let mut v = Value::new();
+        v.set_string_value("b".to_owned());
+        s.fields.insert("b".to_owned(), v);
+
+        walk_mut(&mut s, |_, v| match v {
+            ReflectValueRef::F64(n) if n > 10.0 => {
+                WalkMutAction::Replace(ReflectValueBox::F64(10.0))
+            }
+            v => uppercase(v),
+        });
+
+        assert_eq!(10.0, s.fields["a"].number_value());
+        assert_eq!("B", s.fields["b"].string_value());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_dynamic() {
+        let mut s = Struct::new();
+        let mut v = Value::new();
+        v.set_string_value("a".to_owned());
+        let mut list = ListValue::new();
+        list.values.push(v.clone());
+        s.fields.insert("a".to_owned(), v);
+        let mut v = Value::new();
+        v.set_list_value(list);
+        s.fields.insert("l".to_owned(), v);
+
+        let file =
+            FileDescriptor::new_dynamic(struct_::file_descriptor().proto().clone(), &[]).unwrap();
+        let mut dynamic = file
+            .message_by_package_relative_name("Struct")
+            .unwrap()
+            .parse_from_bytes(&s.write_to_bytes().unwrap())
+            .unwrap();
+        walk_mut(&mut *dynamic, |_, v| uppercase(v));
+
+        let s = Struct::parse_from_bytes(&dynamic.write_to_bytes_dyn().unwrap()).unwrap();
+        assert_eq!("A", s.fields["a"].string_value());
+        assert_eq!("A", s.fields["l"].list_value().values[0].string_value());
+    }
+}
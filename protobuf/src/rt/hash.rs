@@ -0,0 +1,151 @@
+//! Hashing of message fields in `Hash` implementations
+//! generated with `derive_hash` codegen option.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+#[cfg(feature = "bytes")]
+use bytes::Bytes;
+
+#[cfg(feature = "bytes")]
+use crate::chars::Chars;
+use crate::Enum;
+use crate::EnumOrUnknown;
+use crate::Message;
+use crate::MessageField;
+
+/// Hash of field value, consistent with `PartialEq` of the field type.
+///
+/// Unlike `Hash`, implemented for floats and maps.
+pub trait ProtobufHash {
+    /// Feed this value into the hasher.
+    fn protobuf_hash<H: Hasher>(&self, state: &mut H);
+}
+
+macro_rules! impl_protobuf_hash_with_hash {
+    ($($t:ty),*) => {
+        $(
+            impl ProtobufHash for $t {
+                fn protobuf_hash<H: Hasher>(&self, state: &mut H) {
+                    Hash::hash(self, state);
+                }
+            }
+        )*
+    };
+}
+
+impl_protobuf_hash_with_hash!(i32, i64, u32, u64, bool, String, Vec<u8>);
+
+#[cfg(feature = "bytes")]
+impl_protobuf_hash_with_hash!(Bytes, Chars);
+
+impl ProtobufHash for f32 {
+    fn protobuf_hash<H: Hasher>(&self, state: &mut H) {
+        // `0.0 == -0.0`, so they must have the same hash.
+        let v = if *self == 0.0 { 0.0f32 } else { *self };
+        v.to_bits().hash(state);
+    }
+}
+
+impl ProtobufHash for f64 {
+    fn protobuf_hash<H: Hasher>(&self, state: &mut H) {
+        let v = if *self == 0.0 { 0.0f64 } else { *self };
+        v.to_bits().hash(state);
+    }
+}
+
+impl<E: Enum> ProtobufHash for EnumOrUnknown<E> {
+    fn protobuf_hash<H: Hasher>(&self, state: &mut H) {
+        self.value().hash(state);
+    }
+}
+
+impl<M: Message + Hash> ProtobufHash for M {
+    fn protobuf_hash<H: Hasher>(&self, state: &mut H) {
+        self.hash(state);
+    }
+}
+
+impl<M: Message + Hash> ProtobufHash for MessageField<M> {
+    fn protobuf_hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<T: ProtobufHash> ProtobufHash for Option<T> {
+    fn protobuf_hash<H: Hasher>(&self, state: &mut H) {
+        self.is_some().hash(state);
+        if let Some(v) = self {
+            v.protobuf_hash(state);
+        }
+    }
+}
+
+impl<T: ProtobufHash> ProtobufHash for Vec<T> {
+    fn protobuf_hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        for v in self {
+            v.protobuf_hash(state);
+        }
+    }
+}
+
+/// Entries are hashed in key order, so hash does not depend on insertion order.
+impl<K: ProtobufHash + Ord, V: ProtobufHash, S> ProtobufHash for HashMap<K, V, S> {
+    fn protobuf_hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        let mut entries: Vec<(&K, &V)> = self.iter().collect();
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+        for (k, v) in entries {
+            k.protobuf_hash(state);
+            v.protobuf_hash(state);
+        }
+    }
+}
+
+impl<K: ProtobufHash, V: ProtobufHash> ProtobufHash for BTreeMap<K, V> {
+    fn protobuf_hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        for (k, v) in self {
+            k.protobuf_hash(state);
+            v.protobuf_hash(state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashMap;
+    use std::hash::Hasher;
+
+    use super::ProtobufHash;
+
+    fn hash<T: ProtobufHash>(v: &T) -> u64 {
+        let mut state = DefaultHasher::new();
+        v.protobuf_hash(&mut state);
+        state.finish()
+    }
+
+    #[test]
+    fn test_zero() {
+        assert_eq!(hash(&0.0f32), hash(&-0.0f32));
+        assert_eq!(hash(&0.0f64), hash(&-0.0f64));
+        assert_ne!(hash(&0.0f64), hash(&1.0f64));
+    }
+
+    #[test]
+    fn test_map_order() {
+        let mut a = HashMap::new();
+        let mut b = HashMap::new();
+        for i in 0..100u32 {
+            a.insert(i, i.to_string());
+            b.insert(99 - i, (99 - i).to_string());
+        }
+        assert_eq!(hash(&a), hash(&b));
+        b.insert(100, "100".to_owned());
+        assert_ne!(hash(&a), hash(&b));
+    }
+}
@@ -5,6 +5,9 @@ use crate::Message;
 use crate::MessageField;
 
 /// Read singular `message` field.
+///
+/// If the field is already set, read message is merged into it,
+/// as protobuf spec requires for repeated occurrences of the field on the wire.
 pub fn read_singular_message_into_field<M>(
     is: &mut CodedInputStream,
     target: &mut MessageField<M>,
@@ -12,10 +15,7 @@ pub fn read_singular_message_into_field<M>(
 where
     M: Message,
 {
-    let mut m = M::new();
-    is.merge_message(&mut m)?;
-    *target = MessageField::some(m);
-    Ok(())
+    is.merge_message(target.mut_or_insert_default())
 }
 
 /// Write message with field number and length to the stream.
@@ -4,11 +4,15 @@
 //! so they can be changed any time (provided compatibility with
 //! previously generated code is preserved).
 
+pub mod hash;
 pub(crate) mod map;
 mod message;
 pub(crate) mod packed;
+mod parse_error;
 pub(crate) mod repeated;
 pub(crate) mod singular;
+pub mod total_float_eq;
+pub mod typestate;
 pub(crate) mod unknown_or_group;
 
 pub use message::read_singular_message_into_field;
@@ -27,6 +31,7 @@ pub use packed::vec_packed_sint32_size;
 pub use packed::vec_packed_sint64_size;
 pub use packed::vec_packed_uint32_size;
 pub use packed::vec_packed_uint64_size;
+pub use parse_error::parse_error_in_field;
 pub use repeated::read_repeated_packed_enum_or_unknown_into;
 pub use singular::bytes_size;
 pub use singular::int32_size;
@@ -41,10 +46,38 @@ pub use unknown_or_group::skip_field_for_tag;
 pub use unknown_or_group::unknown_fields_size;
 
 pub use crate::cached_size::CachedSize;
+use crate::error::ProtobufError;
 pub use crate::lazy::Lazy;
 use crate::varint::encode::encoded_varint64_len;
 pub use crate::wire_format::WireType;
 
+/// Check if value is equal to the default value of its type.
+///
+/// Used in `skip_serializing_if` serde attribute generated
+/// with `serde_omit_defaults` codegen option.
+pub fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+/// Error returned from `try_get_` getters of unset required fields,
+/// generated with `result_required_getters` codegen option.
+#[cold]
+pub fn required_field_not_set(field_full_name: &str) -> crate::Error {
+    ProtobufError::RequiredFieldNotSet(field_full_name.to_owned()).into()
+}
+
+/// Called by getters generated with `instrument_getters` codegen option.
+///
+/// Logs field name with `log::debug!` if `with-log` feature is enabled,
+/// and does nothing otherwise.
+#[inline]
+pub fn log_getter(field_full_name: &'static str) {
+    #[cfg(feature = "with-log")]
+    log::debug!("get `{}`", field_full_name);
+    #[cfg(not(feature = "with-log"))]
+    let _ = field_full_name;
+}
+
 /// Given `u64` value compute varint encoded length.
 pub fn compute_raw_varint64_size(value: u64) -> u64 {
     encoded_varint64_len(value) as u64
@@ -0,0 +1,45 @@
+//! Logging of parse errors in code generated with `log_parse_errors` option.
+
+use crate::CodedInputStream;
+
+/// Called by generated `merge_from` when parsing fails.
+///
+/// Error in nested message is wrapped with the path to the field
+/// (message name and field number for each nesting level),
+/// and when the outermost message is reached, the error is logged
+/// with `log::warn!` and original error is returned.
+///
+/// If the outermost message is not generated with `log_parse_errors`
+/// option (or is read with [`CodedInputStream::read_message`]), error is not logged,
+/// and the path is included in error message.
+///
+/// Without `with-log` feature errors are returned unchanged.
+#[cold]
+pub fn parse_error_in_field(
+    message: &'static str,
+    field_number: u32,
+    is: &CodedInputStream,
+    e: crate::Error,
+) -> crate::Error {
+    #[cfg(feature = "with-log")]
+    {
+        use crate::error::ProtobufError;
+
+        let this = format!("{}#{}", message, field_number);
+        let (path, e) = match *e.0 {
+            ProtobufError::FieldPath(path, e) => (format!("{}/{}", this, path), e),
+            e => (this, crate::Error::from(e)),
+        };
+        if is.recursion_level() == 0 {
+            log::warn!("parse error at `{}`: {}", path, e);
+            e
+        } else {
+            ProtobufError::FieldPath(path, e).into()
+        }
+    }
+    #[cfg(not(feature = "with-log"))]
+    {
+        let _ = (message, field_number, is);
+        e
+    }
+}
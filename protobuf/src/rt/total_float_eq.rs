@@ -0,0 +1,139 @@
+//! Comparison of message fields in `PartialEq` implementations
+//! generated with `total_float_eq` codegen option.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+use std::hash::Hash;
+
+#[cfg(feature = "bytes")]
+use bytes::Bytes;
+
+#[cfg(feature = "bytes")]
+use crate::chars::Chars;
+use crate::Enum;
+use crate::EnumOrUnknown;
+use crate::Message;
+use crate::MessageField;
+
+/// Equality of field values where floats are compared by bit pattern.
+///
+/// So `NaN` is equal to `NaN` with the same bits, and `0.0` is not equal to `-0.0`.
+pub trait TotalFloatEq {
+    /// Compare values.
+    fn total_float_eq(&self, other: &Self) -> bool;
+}
+
+macro_rules! impl_total_float_eq_with_eq {
+    ($($t:ty),*) => {
+        $(
+            impl TotalFloatEq for $t {
+                fn total_float_eq(&self, other: &Self) -> bool {
+                    self == other
+                }
+            }
+        )*
+    };
+}
+
+impl_total_float_eq_with_eq!(i32, i64, u32, u64, bool, String, Vec<u8>);
+
+#[cfg(feature = "bytes")]
+impl_total_float_eq_with_eq!(Bytes, Chars);
+
+impl TotalFloatEq for f32 {
+    fn total_float_eq(&self, other: &Self) -> bool {
+        self.to_bits() == other.to_bits()
+    }
+}
+
+impl TotalFloatEq for f64 {
+    fn total_float_eq(&self, other: &Self) -> bool {
+        self.to_bits() == other.to_bits()
+    }
+}
+
+impl<E: Enum> TotalFloatEq for EnumOrUnknown<E> {
+    fn total_float_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+/// Messages are compared with their `PartialEq`.
+impl<M: Message + PartialEq> TotalFloatEq for M {
+    fn total_float_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl<M: Message + PartialEq> TotalFloatEq for MessageField<M> {
+    fn total_float_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl<T: TotalFloatEq> TotalFloatEq for Option<T> {
+    fn total_float_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Some(a), Some(b)) => a.total_float_eq(b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<T: TotalFloatEq> TotalFloatEq for Vec<T> {
+    fn total_float_eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a.total_float_eq(b))
+    }
+}
+
+impl<K: Eq + Hash, V: TotalFloatEq, S: BuildHasher> TotalFloatEq for HashMap<K, V, S> {
+    fn total_float_eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(k, a)| other.get(k).is_some_and(|b| a.total_float_eq(b)))
+    }
+}
+
+impl<K: Ord, V: TotalFloatEq> TotalFloatEq for BTreeMap<K, V> {
+    fn total_float_eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|((ka, a), (kb, b))| ka == kb && a.total_float_eq(b))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::TotalFloatEq;
+
+    #[test]
+    fn test_floats() {
+        assert!(f64::NAN.total_float_eq(&f64::NAN));
+        assert!(f32::NAN.total_float_eq(&f32::NAN));
+        assert!(!f64::NAN.total_float_eq(&-f64::NAN));
+        assert!(!0.0f64.total_float_eq(&-0.0f64));
+        assert!(1.5f32.total_float_eq(&1.5f32));
+        assert!(!1.5f32.total_float_eq(&2.5f32));
+    }
+
+    #[test]
+    fn test_containers() {
+        assert!(vec![f64::NAN].total_float_eq(&vec![f64::NAN]));
+        assert!(!vec![f64::NAN].total_float_eq(&vec![f64::NAN, 1.0]));
+        assert!(Some(f32::NAN).total_float_eq(&Some(f32::NAN)));
+        assert!(!Some(f32::NAN).total_float_eq(&None));
+
+        let a: HashMap<u32, f64> = [(1, f64::NAN), (2, 1.0)].into_iter().collect();
+        let mut b: HashMap<u32, f64> = [(2, 1.0), (1, f64::NAN)].into_iter().collect();
+        assert!(a.total_float_eq(&b));
+        b.insert(3, 1.0);
+        assert!(!a.total_float_eq(&b));
+    }
+}
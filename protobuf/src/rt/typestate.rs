@@ -0,0 +1,10 @@
+//! Markers of required field state in builders
+//! generated with `typestate_builder` codegen option.
+
+/// Required field is set.
+#[derive(Debug)]
+pub struct Set;
+
+/// Required field is not set yet.
+#[derive(Debug)]
+pub struct Unset;
@@ -34,6 +34,60 @@ pub mod exts {
 
     pub const lite_runtime_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, bool> = crate::ext::ExtFieldOptional::new(17035, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
 
+    pub const emit_field_count_assert_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, bool> = crate::ext::ExtFieldOptional::new(17041, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const derive_eq_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, bool> = crate::ext::ExtFieldOptional::new(17042, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const emit_field_meta_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, bool> = crate::ext::ExtFieldOptional::new(17043, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const typestate_builder_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, bool> = crate::ext::ExtFieldOptional::new(17044, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const derive_hash_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, bool> = crate::ext::ExtFieldOptional::new(17045, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const prost_compat_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, bool> = crate::ext::ExtFieldOptional::new(17046, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const enum_try_from_str_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, bool> = crate::ext::ExtFieldOptional::new(17047, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const serde_omit_defaults_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, bool> = crate::ext::ExtFieldOptional::new(17048, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const btreemap_fields_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, bool> = crate::ext::ExtFieldOptional::new(17049, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const emit_enum_all_const_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, bool> = crate::ext::ExtFieldOptional::new(17050, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const clean_enum_twin_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, bool> = crate::ext::ExtFieldOptional::new(17051, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const inline_accessors_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, bool> = crate::ext::ExtFieldOptional::new(17052, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const log_parse_errors_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, bool> = crate::ext::ExtFieldOptional::new(17053, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const emit_full_name_const_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, bool> = crate::ext::ExtFieldOptional::new(17054, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const iter_accessors_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, bool> = crate::ext::ExtFieldOptional::new(17056, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const tuple_conversion_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, bool> = crate::ext::ExtFieldOptional::new(17057, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const gen_json_methods_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, bool> = crate::ext::ExtFieldOptional::new(17058, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const track_caller_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, bool> = crate::ext::ExtFieldOptional::new(17059, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const force_unpacked_repeated_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, bool> = crate::ext::ExtFieldOptional::new(17060, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const oneof_from_members_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, bool> = crate::ext::ExtFieldOptional::new(17061, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const serde_flatten_oneofs_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, bool> = crate::ext::ExtFieldOptional::new(17062, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const result_required_getters_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, bool> = crate::ext::ExtFieldOptional::new(17063, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const instrument_getters_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, bool> = crate::ext::ExtFieldOptional::new(17064, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const enum_iter_and_str_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, bool> = crate::ext::ExtFieldOptional::new(17065, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const derive_copy_where_possible_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, bool> = crate::ext::ExtFieldOptional::new(17066, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const fromstr_text_format_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, bool> = crate::ext::ExtFieldOptional::new(17067, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const total_float_eq_all: crate::ext::ExtFieldOptional<crate::descriptor::FileOptions, bool> = crate::ext::ExtFieldOptional::new(17068, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
     pub const generate_accessors: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, bool> = crate::ext::ExtFieldOptional::new(17004, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
 
     pub const generate_getter: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, bool> = crate::ext::ExtFieldOptional::new(17005, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
@@ -42,6 +96,50 @@ pub mod exts {
 
     pub const tokio_bytes_for_string: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, bool> = crate::ext::ExtFieldOptional::new(17012, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
 
+    pub const emit_field_count_assert: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, bool> = crate::ext::ExtFieldOptional::new(17041, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const emit_field_meta: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, bool> = crate::ext::ExtFieldOptional::new(17043, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const typestate_builder: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, bool> = crate::ext::ExtFieldOptional::new(17044, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const derive_hash: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, bool> = crate::ext::ExtFieldOptional::new(17045, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const prost_compat: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, bool> = crate::ext::ExtFieldOptional::new(17046, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const serde_omit_defaults: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, bool> = crate::ext::ExtFieldOptional::new(17048, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const btreemap_fields: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, bool> = crate::ext::ExtFieldOptional::new(17049, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const inline_accessors: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, bool> = crate::ext::ExtFieldOptional::new(17052, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const log_parse_errors: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, bool> = crate::ext::ExtFieldOptional::new(17053, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const emit_full_name_const: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, bool> = crate::ext::ExtFieldOptional::new(17054, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const iter_accessors: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, bool> = crate::ext::ExtFieldOptional::new(17056, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const tuple_conversion: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, bool> = crate::ext::ExtFieldOptional::new(17057, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const gen_json_methods: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, bool> = crate::ext::ExtFieldOptional::new(17058, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const track_caller: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, bool> = crate::ext::ExtFieldOptional::new(17059, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const force_unpacked_repeated: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, bool> = crate::ext::ExtFieldOptional::new(17060, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const oneof_from_members: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, bool> = crate::ext::ExtFieldOptional::new(17061, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const serde_flatten_oneofs: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, bool> = crate::ext::ExtFieldOptional::new(17062, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const result_required_getters: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, bool> = crate::ext::ExtFieldOptional::new(17063, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const instrument_getters: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, bool> = crate::ext::ExtFieldOptional::new(17064, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const derive_copy_where_possible: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, bool> = crate::ext::ExtFieldOptional::new(17066, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const fromstr_text_format: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, bool> = crate::ext::ExtFieldOptional::new(17067, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const total_float_eq: crate::ext::ExtFieldOptional<crate::descriptor::MessageOptions, bool> = crate::ext::ExtFieldOptional::new(17068, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
     pub const generate_accessors_field: crate::ext::ExtFieldOptional<crate::descriptor::FieldOptions, bool> = crate::ext::ExtFieldOptional::new(17004, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
 
     pub const generate_getter_field: crate::ext::ExtFieldOptional<crate::descriptor::FieldOptions, bool> = crate::ext::ExtFieldOptional::new(17005, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
@@ -49,6 +147,26 @@ pub mod exts {
     pub const tokio_bytes_field: crate::ext::ExtFieldOptional<crate::descriptor::FieldOptions, bool> = crate::ext::ExtFieldOptional::new(17011, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
 
     pub const tokio_bytes_for_string_field: crate::ext::ExtFieldOptional<crate::descriptor::FieldOptions, bool> = crate::ext::ExtFieldOptional::new(17012, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const redact_debug_field: crate::ext::ExtFieldOptional<crate::descriptor::FieldOptions, bool> = crate::ext::ExtFieldOptional::new(17040, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const serde_omit_defaults_field: crate::ext::ExtFieldOptional<crate::descriptor::FieldOptions, bool> = crate::ext::ExtFieldOptional::new(17048, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const btreemap_fields_field: crate::ext::ExtFieldOptional<crate::descriptor::FieldOptions, bool> = crate::ext::ExtFieldOptional::new(17049, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const inline_accessors_field: crate::ext::ExtFieldOptional<crate::descriptor::FieldOptions, bool> = crate::ext::ExtFieldOptional::new(17052, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const identity_field: crate::ext::ExtFieldOptional<crate::descriptor::FieldOptions, bool> = crate::ext::ExtFieldOptional::new(17055, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const iter_accessors_field: crate::ext::ExtFieldOptional<crate::descriptor::FieldOptions, bool> = crate::ext::ExtFieldOptional::new(17056, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const track_caller_field: crate::ext::ExtFieldOptional<crate::descriptor::FieldOptions, bool> = crate::ext::ExtFieldOptional::new(17059, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const force_unpacked_repeated_field: crate::ext::ExtFieldOptional<crate::descriptor::FieldOptions, bool> = crate::ext::ExtFieldOptional::new(17060, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const result_required_getters_field: crate::ext::ExtFieldOptional<crate::descriptor::FieldOptions, bool> = crate::ext::ExtFieldOptional::new(17063, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
+
+    pub const instrument_getters_field: crate::ext::ExtFieldOptional<crate::descriptor::FieldOptions, bool> = crate::ext::ExtFieldOptional::new(17064, crate::descriptor::field_descriptor_proto::Type::TYPE_BOOL);
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
@@ -61,83 +179,443 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     tes_for_string_all\x18\xf4\x84\x01\x20\x01(\x08\x12\x1c.google.protobuf.\
     FileOptionsR\x16tokioBytesForStringAll:H\n\x10lite_runtime_all\x18\x8b\
     \x85\x01\x20\x01(\x08\x12\x1c.google.protobuf.FileOptionsR\x0eliteRuntim\
-    eAll:P\n\x12generate_accessors\x18\xec\x84\x01\x20\x01(\x08\x12\x1f.goog\
-    le.protobuf.MessageOptionsR\x11generateAccessors:J\n\x0fgenerate_getter\
-    \x18\xed\x84\x01\x20\x01(\x08\x12\x1f.google.protobuf.MessageOptionsR\
-    \x0egenerateGetter:B\n\x0btokio_bytes\x18\xf3\x84\x01\x20\x01(\x08\x12\
-    \x1f.google.protobuf.MessageOptionsR\ntokioBytes:V\n\x16tokio_bytes_for_\
-    string\x18\xf4\x84\x01\x20\x01(\x08\x12\x1f.google.protobuf.MessageOptio\
-    nsR\x13tokioBytesForString:Y\n\x18generate_accessors_field\x18\xec\x84\
-    \x01\x20\x01(\x08\x12\x1d.google.protobuf.FieldOptionsR\x16generateAcces\
-    sorsField:S\n\x15generate_getter_field\x18\xed\x84\x01\x20\x01(\x08\x12\
-    \x1d.google.protobuf.FieldOptionsR\x13generateGetterField:K\n\x11tokio_b\
-    ytes_field\x18\xf3\x84\x01\x20\x01(\x08\x12\x1d.google.protobuf.FieldOpt\
-    ionsR\x0ftokioBytesField:_\n\x1ctokio_bytes_for_string_field\x18\xf4\x84\
-    \x01\x20\x01(\x08\x12\x1d.google.protobuf.FieldOptionsR\x18tokioBytesFor\
-    StringFieldJ\x9d\x0f\n\x06\x12\x04\0\0.\x01\n\x08\n\x01\x0c\x12\x03\0\0\
-    \x12\n\t\n\x02\x03\0\x12\x03\x02\0*\n\xe5\x01\n\x01\x02\x12\x03\n\0\x122\
-    ^\x20see\x20https://github.com/gogo/protobuf/blob/master/gogoproto/gogo.\
-    proto\n\x20for\x20the\x20original\x20idea\n2{\x20Generated\x20files\x20c\
-    an\x20be\x20customized\x20using\x20this\x20proto\n\x20or\x20using\x20`Cu\
-    stomize`\x20struct\x20when\x20codegen\x20is\x20invoked\x20programmatical\
-    ly.\n\n\t\n\x01\x07\x12\x04\x0c\0\x18\x01\nP\n\x02\x07\0\x12\x03\x0e\x04\
-    1\x1aE\x20When\x20false,\x20`get_`,\x20`set_`,\x20`mut_`\x20etc.\x20acce\
-    ssors\x20are\x20not\x20generated\n\n\n\n\x03\x07\0\x02\x12\x03\x0c\x07\"\
-    \n\n\n\x03\x07\0\x04\x12\x03\x0e\x04\x0c\n\n\n\x03\x07\0\x05\x12\x03\x0e\
-    \r\x11\n\n\n\x03\x07\0\x01\x12\x03\x0e\x12(\n\n\n\x03\x07\0\x03\x12\x03\
-    \x0e+0\nL\n\x02\x07\x01\x12\x03\x10\x04.\x1aA\x20When\x20false,\x20`get_\
-    `\x20is\x20not\x20generated\x20even\x20if\x20`syntax\x20=\x20\"proto2\"`\
-    \n\n\n\n\x03\x07\x01\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x01\x04\x12\x03\
-    \x10\x04\x0c\n\n\n\x03\x07\x01\x05\x12\x03\x10\r\x11\n\n\n\x03\x07\x01\
-    \x01\x12\x03\x10\x12%\n\n\n\x03\x07\x01\x03\x12\x03\x10(-\n2\n\x02\x07\
-    \x02\x12\x03\x12\x04*\x1a'\x20Use\x20`bytes::Bytes`\x20for\x20`bytes`\
-    \x20fields\n\n\n\n\x03\x07\x02\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x02\
-    \x04\x12\x03\x12\x04\x0c\n\n\n\x03\x07\x02\x05\x12\x03\x12\r\x11\n\n\n\
-    \x03\x07\x02\x01\x12\x03\x12\x12!\n\n\n\x03\x07\x02\x03\x12\x03\x12$)\n3\
-    \n\x02\x07\x03\x12\x03\x14\x045\x1a(\x20Use\x20`bytes::Bytes`\x20for\x20\
-    `string`\x20fields\n\n\n\n\x03\x07\x03\x02\x12\x03\x0c\x07\"\n\n\n\x03\
-    \x07\x03\x04\x12\x03\x14\x04\x0c\n\n\n\x03\x07\x03\x05\x12\x03\x14\r\x11\
-    \n\n\n\x03\x07\x03\x01\x12\x03\x14\x12,\n\n\n\x03\x07\x03\x03\x12\x03\
-    \x14/4\nN\n\x02\x07\x04\x12\x03\x17\x04+\x1aC\x20When\x20true,\x20will\
-    \x20only\x20generate\x20codes\x20that\x20works\x20with\x20lite\x20runtim\
-    e.\n\n\n\n\x03\x07\x04\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x04\x04\x12\
-    \x03\x17\x04\x0c\n\n\n\x03\x07\x04\x05\x12\x03\x17\r\x11\n\n\n\x03\x07\
-    \x04\x01\x12\x03\x17\x12\"\n\n\n\x03\x07\x04\x03\x12\x03\x17%*\n\t\n\x01\
-    \x07\x12\x04\x1a\0#\x01\nP\n\x02\x07\x05\x12\x03\x1c\x04-\x1aE\x20When\
-    \x20false,\x20`get_`,\x20`set_`,\x20`mut_`\x20etc.\x20accessors\x20are\
-    \x20not\x20generated\n\n\n\n\x03\x07\x05\x02\x12\x03\x1a\x07%\n\n\n\x03\
-    \x07\x05\x04\x12\x03\x1c\x04\x0c\n\n\n\x03\x07\x05\x05\x12\x03\x1c\r\x11\
-    \n\n\n\x03\x07\x05\x01\x12\x03\x1c\x12$\n\n\n\x03\x07\x05\x03\x12\x03\
-    \x1c',\nL\n\x02\x07\x06\x12\x03\x1e\x04*\x1aA\x20When\x20false,\x20`get_\
-    `\x20is\x20not\x20generated\x20even\x20if\x20`syntax\x20=\x20\"proto2\"`\
-    \n\n\n\n\x03\x07\x06\x02\x12\x03\x1a\x07%\n\n\n\x03\x07\x06\x04\x12\x03\
-    \x1e\x04\x0c\n\n\n\x03\x07\x06\x05\x12\x03\x1e\r\x11\n\n\n\x03\x07\x06\
-    \x01\x12\x03\x1e\x12!\n\n\n\x03\x07\x06\x03\x12\x03\x1e$)\n2\n\x02\x07\
-    \x07\x12\x03\x20\x04&\x1a'\x20Use\x20`bytes::Bytes`\x20for\x20`bytes`\
-    \x20fields\n\n\n\n\x03\x07\x07\x02\x12\x03\x1a\x07%\n\n\n\x03\x07\x07\
-    \x04\x12\x03\x20\x04\x0c\n\n\n\x03\x07\x07\x05\x12\x03\x20\r\x11\n\n\n\
-    \x03\x07\x07\x01\x12\x03\x20\x12\x1d\n\n\n\x03\x07\x07\x03\x12\x03\x20\
-    \x20%\n3\n\x02\x07\x08\x12\x03\"\x041\x1a(\x20Use\x20`bytes::Bytes`\x20f\
-    or\x20`string`\x20fields\n\n\n\n\x03\x07\x08\x02\x12\x03\x1a\x07%\n\n\n\
-    \x03\x07\x08\x04\x12\x03\"\x04\x0c\n\n\n\x03\x07\x08\x05\x12\x03\"\r\x11\
-    \n\n\n\x03\x07\x08\x01\x12\x03\"\x12(\n\n\n\x03\x07\x08\x03\x12\x03\"+0\
-    \n\t\n\x01\x07\x12\x04%\0.\x01\nP\n\x02\x07\t\x12\x03'\x043\x1aE\x20When\
-    \x20false,\x20`get_`,\x20`set_`,\x20`mut_`\x20etc.\x20accessors\x20are\
-    \x20not\x20generated\n\n\n\n\x03\x07\t\x02\x12\x03%\x07#\n\n\n\x03\x07\t\
-    \x04\x12\x03'\x04\x0c\n\n\n\x03\x07\t\x05\x12\x03'\r\x11\n\n\n\x03\x07\t\
-    \x01\x12\x03'\x12*\n\n\n\x03\x07\t\x03\x12\x03'-2\nL\n\x02\x07\n\x12\x03\
-    )\x040\x1aA\x20When\x20false,\x20`get_`\x20is\x20not\x20generated\x20eve\
-    n\x20if\x20`syntax\x20=\x20\"proto2\"`\n\n\n\n\x03\x07\n\x02\x12\x03%\
-    \x07#\n\n\n\x03\x07\n\x04\x12\x03)\x04\x0c\n\n\n\x03\x07\n\x05\x12\x03)\
-    \r\x11\n\n\n\x03\x07\n\x01\x12\x03)\x12'\n\n\n\x03\x07\n\x03\x12\x03)*/\
-    \n2\n\x02\x07\x0b\x12\x03+\x04,\x1a'\x20Use\x20`bytes::Bytes`\x20for\x20\
-    `bytes`\x20fields\n\n\n\n\x03\x07\x0b\x02\x12\x03%\x07#\n\n\n\x03\x07\
-    \x0b\x04\x12\x03+\x04\x0c\n\n\n\x03\x07\x0b\x05\x12\x03+\r\x11\n\n\n\x03\
-    \x07\x0b\x01\x12\x03+\x12#\n\n\n\x03\x07\x0b\x03\x12\x03+&+\n3\n\x02\x07\
-    \x0c\x12\x03-\x047\x1a(\x20Use\x20`bytes::Bytes`\x20for\x20`string`\x20f\
-    ields\n\n\n\n\x03\x07\x0c\x02\x12\x03%\x07#\n\n\n\x03\x07\x0c\x04\x12\
-    \x03-\x04\x0c\n\n\n\x03\x07\x0c\x05\x12\x03-\r\x11\n\n\n\x03\x07\x0c\x01\
-    \x12\x03-\x12.\n\n\n\x03\x07\x0c\x03\x12\x03-16\
+    eAll:\\\n\x1bemit_field_count_assert_all\x18\x91\x85\x01\x20\x01(\x08\
+    \x12\x1c.google.protobuf.FileOptionsR\x17emitFieldCountAssertAll:B\n\rde\
+    rive_eq_all\x18\x92\x85\x01\x20\x01(\x08\x12\x1c.google.protobuf.FileOpt\
+    ionsR\x0bderiveEqAll:M\n\x13emit_field_meta_all\x18\x93\x85\x01\x20\x01(\
+    \x08\x12\x1c.google.protobuf.FileOptionsR\x10emitFieldMetaAll:R\n\x15typ\
+    estate_builder_all\x18\x94\x85\x01\x20\x01(\x08\x12\x1c.google.protobuf.\
+    FileOptionsR\x13typestateBuilderAll:F\n\x0fderive_hash_all\x18\x95\x85\
+    \x01\x20\x01(\x08\x12\x1c.google.protobuf.FileOptionsR\rderiveHashAll:H\
+    \n\x10prost_compat_all\x18\x96\x85\x01\x20\x01(\x08\x12\x1c.google.proto\
+    buf.FileOptionsR\x0eprostCompatAll:P\n\x15enum_try_from_str_all\x18\x97\
+    \x85\x01\x20\x01(\x08\x12\x1c.google.protobuf.FileOptionsR\x11enumTryFro\
+    mStrAll:U\n\x17serde_omit_defaults_all\x18\x98\x85\x01\x20\x01(\x08\x12\
+    \x1c.google.protobuf.FileOptionsR\x14serdeOmitDefaultsAll:N\n\x13btreema\
+    p_fields_all\x18\x99\x85\x01\x20\x01(\x08\x12\x1c.google.protobuf.FileOp\
+    tionsR\x11btreemapFieldsAll:T\n\x17emit_enum_all_const_all\x18\x9a\x85\
+    \x01\x20\x01(\x08\x12\x1c.google.protobuf.FileOptionsR\x13emitEnumAllCon\
+    stAll:M\n\x13clean_enum_twin_all\x18\x9b\x85\x01\x20\x01(\x08\x12\x1c.go\
+    ogle.protobuf.FileOptionsR\x10cleanEnumTwinAll:P\n\x14inline_accessors_a\
+    ll\x18\x9c\x85\x01\x20\x01(\x08\x12\x1c.google.protobuf.FileOptionsR\x12\
+    inlineAccessorsAll:O\n\x14log_parse_errors_all\x18\x9d\x85\x01\x20\x01(\
+    \x08\x12\x1c.google.protobuf.FileOptionsR\x11logParseErrorsAll:V\n\x18em\
+    it_full_name_const_all\x18\x9e\x85\x01\x20\x01(\x08\x12\x1c.google.proto\
+    buf.FileOptionsR\x14emitFullNameConstAll:L\n\x12iter_accessors_all\x18\
+    \xa0\x85\x01\x20\x01(\x08\x12\x1c.google.protobuf.FileOptionsR\x10iterAc\
+    cessorsAll:P\n\x14tuple_conversion_all\x18\xa1\x85\x01\x20\x01(\x08\x12\
+    \x1c.google.protobuf.FileOptionsR\x12tupleConversionAll:O\n\x14gen_json_\
+    methods_all\x18\xa2\x85\x01\x20\x01(\x08\x12\x1c.google.protobuf.FileOpt\
+    ionsR\x11genJsonMethodsAll:H\n\x10track_caller_all\x18\xa3\x85\x01\x20\
+    \x01(\x08\x12\x1c.google.protobuf.FileOptionsR\x0etrackCallerAll:]\n\x1b\
+    force_unpacked_repeated_all\x18\xa4\x85\x01\x20\x01(\x08\x12\x1c.google.\
+    protobuf.FileOptionsR\x18forceUnpackedRepeatedAll:S\n\x16oneof_from_memb\
+    ers_all\x18\xa5\x85\x01\x20\x01(\x08\x12\x1c.google.protobuf.FileOptions\
+    R\x13oneofFromMembersAll:W\n\x18serde_flatten_oneofs_all\x18\xa6\x85\x01\
+    \x20\x01(\x08\x12\x1c.google.protobuf.FileOptionsR\x15serdeFlattenOneofs\
+    All:]\n\x1bresult_required_getters_all\x18\xa7\x85\x01\x20\x01(\x08\x12\
+    \x1c.google.protobuf.FileOptionsR\x18resultRequiredGettersAll:T\n\x16ins\
+    trument_getters_all\x18\xa8\x85\x01\x20\x01(\x08\x12\x1c.google.protobuf\
+    .FileOptionsR\x14instrumentGettersAll:P\n\x15enum_iter_and_str_all\x18\
+    \xa9\x85\x01\x20\x01(\x08\x12\x1c.google.protobuf.FileOptionsR\x11enumIt\
+    erAndStrAll:b\n\x1ederive_copy_where_possible_all\x18\xaa\x85\x01\x20\
+    \x01(\x08\x12\x1c.google.protobuf.FileOptionsR\x1aderiveCopyWherePossibl\
+    eAll:U\n\x17fromstr_text_format_all\x18\xab\x85\x01\x20\x01(\x08\x12\x1c\
+    .google.protobuf.FileOptionsR\x14fromstrTextFormatAll:K\n\x12total_float\
+    _eq_all\x18\xac\x85\x01\x20\x01(\x08\x12\x1c.google.protobuf.FileOptions\
+    R\x0ftotalFloatEqAll:P\n\x12generate_accessors\x18\xec\x84\x01\x20\x01(\
+    \x08\x12\x1f.google.protobuf.MessageOptionsR\x11generateAccessors:J\n\
+    \x0fgenerate_getter\x18\xed\x84\x01\x20\x01(\x08\x12\x1f.google.protobuf\
+    .MessageOptionsR\x0egenerateGetter:B\n\x0btokio_bytes\x18\xf3\x84\x01\
+    \x20\x01(\x08\x12\x1f.google.protobuf.MessageOptionsR\ntokioBytes:V\n\
+    \x16tokio_bytes_for_string\x18\xf4\x84\x01\x20\x01(\x08\x12\x1f.google.p\
+    rotobuf.MessageOptionsR\x13tokioBytesForString:X\n\x17emit_field_count_a\
+    ssert\x18\x91\x85\x01\x20\x01(\x08\x12\x1f.google.protobuf.MessageOption\
+    sR\x14emitFieldCountAssert:I\n\x0femit_field_meta\x18\x93\x85\x01\x20\
+    \x01(\x08\x12\x1f.google.protobuf.MessageOptionsR\remitFieldMeta:N\n\x11\
+    typestate_builder\x18\x94\x85\x01\x20\x01(\x08\x12\x1f.google.protobuf.M\
+    essageOptionsR\x10typestateBuilder:B\n\x0bderive_hash\x18\x95\x85\x01\
+    \x20\x01(\x08\x12\x1f.google.protobuf.MessageOptionsR\nderiveHash:D\n\
+    \x0cprost_compat\x18\x96\x85\x01\x20\x01(\x08\x12\x1f.google.protobuf.Me\
+    ssageOptionsR\x0bprostCompat:Q\n\x13serde_omit_defaults\x18\x98\x85\x01\
+    \x20\x01(\x08\x12\x1f.google.protobuf.MessageOptionsR\x11serdeOmitDefaul\
+    ts:J\n\x0fbtreemap_fields\x18\x99\x85\x01\x20\x01(\x08\x12\x1f.google.pr\
+    otobuf.MessageOptionsR\x0ebtreemapFields:L\n\x10inline_accessors\x18\x9c\
+    \x85\x01\x20\x01(\x08\x12\x1f.google.protobuf.MessageOptionsR\x0finlineA\
+    ccessors:K\n\x10log_parse_errors\x18\x9d\x85\x01\x20\x01(\x08\x12\x1f.go\
+    ogle.protobuf.MessageOptionsR\x0elogParseErrors:R\n\x14emit_full_name_co\
+    nst\x18\x9e\x85\x01\x20\x01(\x08\x12\x1f.google.protobuf.MessageOptionsR\
+    \x11emitFullNameConst:H\n\x0eiter_accessors\x18\xa0\x85\x01\x20\x01(\x08\
+    \x12\x1f.google.protobuf.MessageOptionsR\riterAccessors:L\n\x10tuple_con\
+    version\x18\xa1\x85\x01\x20\x01(\x08\x12\x1f.google.protobuf.MessageOpti\
+    onsR\x0ftupleConversion:K\n\x10gen_json_methods\x18\xa2\x85\x01\x20\x01(\
+    \x08\x12\x1f.google.protobuf.MessageOptionsR\x0egenJsonMethods:D\n\x0ctr\
+    ack_caller\x18\xa3\x85\x01\x20\x01(\x08\x12\x1f.google.protobuf.MessageO\
+    ptionsR\x0btrackCaller:Y\n\x17force_unpacked_repeated\x18\xa4\x85\x01\
+    \x20\x01(\x08\x12\x1f.google.protobuf.MessageOptionsR\x15forceUnpackedRe\
+    peated:O\n\x12oneof_from_members\x18\xa5\x85\x01\x20\x01(\x08\x12\x1f.go\
+    ogle.protobuf.MessageOptionsR\x10oneofFromMembers:S\n\x14serde_flatten_o\
+    neofs\x18\xa6\x85\x01\x20\x01(\x08\x12\x1f.google.protobuf.MessageOption\
+    sR\x12serdeFlattenOneofs:Y\n\x17result_required_getters\x18\xa7\x85\x01\
+    \x20\x01(\x08\x12\x1f.google.protobuf.MessageOptionsR\x15resultRequiredG\
+    etters:P\n\x12instrument_getters\x18\xa8\x85\x01\x20\x01(\x08\x12\x1f.go\
+    ogle.protobuf.MessageOptionsR\x11instrumentGetters:^\n\x1aderive_copy_wh\
+    ere_possible\x18\xaa\x85\x01\x20\x01(\x08\x12\x1f.google.protobuf.Messag\
+    eOptionsR\x17deriveCopyWherePossible:Q\n\x13fromstr_text_format\x18\xab\
+    \x85\x01\x20\x01(\x08\x12\x1f.google.protobuf.MessageOptionsR\x11fromstr\
+    TextFormat:G\n\x0etotal_float_eq\x18\xac\x85\x01\x20\x01(\x08\x12\x1f.go\
+    ogle.protobuf.MessageOptionsR\x0ctotalFloatEq:Y\n\x18generate_accessors_\
+    field\x18\xec\x84\x01\x20\x01(\x08\x12\x1d.google.protobuf.FieldOptionsR\
+    \x16generateAccessorsField:S\n\x15generate_getter_field\x18\xed\x84\x01\
+    \x20\x01(\x08\x12\x1d.google.protobuf.FieldOptionsR\x13generateGetterFie\
+    ld:K\n\x11tokio_bytes_field\x18\xf3\x84\x01\x20\x01(\x08\x12\x1d.google.\
+    protobuf.FieldOptionsR\x0ftokioBytesField:_\n\x1ctokio_bytes_for_string_\
+    field\x18\xf4\x84\x01\x20\x01(\x08\x12\x1d.google.protobuf.FieldOptionsR\
+    \x18tokioBytesForStringField:M\n\x12redact_debug_field\x18\x90\x85\x01\
+    \x20\x01(\x08\x12\x1d.google.protobuf.FieldOptionsR\x10redactDebugField:\
+    Z\n\x19serde_omit_defaults_field\x18\x98\x85\x01\x20\x01(\x08\x12\x1d.go\
+    ogle.protobuf.FieldOptionsR\x16serdeOmitDefaultsField:S\n\x15btreemap_fi\
+    elds_field\x18\x99\x85\x01\x20\x01(\x08\x12\x1d.google.protobuf.FieldOpt\
+    ionsR\x13btreemapFieldsField:U\n\x16inline_accessors_field\x18\x9c\x85\
+    \x01\x20\x01(\x08\x12\x1d.google.protobuf.FieldOptionsR\x14inlineAccesso\
+    rsField:F\n\x0eidentity_field\x18\x9f\x85\x01\x20\x01(\x08\x12\x1d.googl\
+    e.protobuf.FieldOptionsR\ridentityField:Q\n\x14iter_accessors_field\x18\
+    \xa0\x85\x01\x20\x01(\x08\x12\x1d.google.protobuf.FieldOptionsR\x12iterA\
+    ccessorsField:M\n\x12track_caller_field\x18\xa3\x85\x01\x20\x01(\x08\x12\
+    \x1d.google.protobuf.FieldOptionsR\x10trackCallerField:b\n\x1dforce_unpa\
+    cked_repeated_field\x18\xa4\x85\x01\x20\x01(\x08\x12\x1d.google.protobuf\
+    .FieldOptionsR\x1aforceUnpackedRepeatedField:b\n\x1dresult_required_gett\
+    ers_field\x18\xa7\x85\x01\x20\x01(\x08\x12\x1d.google.protobuf.FieldOpti\
+    onsR\x1aresultRequiredGettersField:Y\n\x18instrument_getters_field\x18\
+    \xa8\x85\x01\x20\x01(\x08\x12\x1d.google.protobuf.FieldOptionsR\x16instr\
+    umentGettersFieldJ\xceM\n\x07\x12\x05\0\0\xdf\x01\x01\n\x08\n\x01\x0c\
+    \x12\x03\0\0\x12\n\t\n\x02\x03\0\x12\x03\x02\0*\n\xe5\x01\n\x01\x02\x12\
+    \x03\n\0\x122^\x20see\x20https://github.com/gogo/protobuf/blob/master/go\
+    goproto/gogo.proto\n\x20for\x20the\x20original\x20idea\n2{\x20Generated\
+    \x20files\x20can\x20be\x20customized\x20using\x20this\x20proto\n\x20or\
+    \x20using\x20`Customize`\x20struct\x20when\x20codegen\x20is\x20invoked\
+    \x20programmatically.\n\n\t\n\x01\x07\x12\x04\x0c\0i\x01\nP\n\x02\x07\0\
+    \x12\x03\x0e\x041\x1aE\x20When\x20false,\x20`get_`,\x20`set_`,\x20`mut_`\
+    \x20etc.\x20accessors\x20are\x20not\x20generated\n\n\n\n\x03\x07\0\x02\
+    \x12\x03\x0c\x07\"\n\n\n\x03\x07\0\x04\x12\x03\x0e\x04\x0c\n\n\n\x03\x07\
+    \0\x05\x12\x03\x0e\r\x11\n\n\n\x03\x07\0\x01\x12\x03\x0e\x12(\n\n\n\x03\
+    \x07\0\x03\x12\x03\x0e+0\nL\n\x02\x07\x01\x12\x03\x10\x04.\x1aA\x20When\
+    \x20false,\x20`get_`\x20is\x20not\x20generated\x20even\x20if\x20`syntax\
+    \x20=\x20\"proto2\"`\n\n\n\n\x03\x07\x01\x02\x12\x03\x0c\x07\"\n\n\n\x03\
+    \x07\x01\x04\x12\x03\x10\x04\x0c\n\n\n\x03\x07\x01\x05\x12\x03\x10\r\x11\
+    \n\n\n\x03\x07\x01\x01\x12\x03\x10\x12%\n\n\n\x03\x07\x01\x03\x12\x03\
+    \x10(-\n2\n\x02\x07\x02\x12\x03\x12\x04*\x1a'\x20Use\x20`bytes::Bytes`\
+    \x20for\x20`bytes`\x20fields\n\n\n\n\x03\x07\x02\x02\x12\x03\x0c\x07\"\n\
+    \n\n\x03\x07\x02\x04\x12\x03\x12\x04\x0c\n\n\n\x03\x07\x02\x05\x12\x03\
+    \x12\r\x11\n\n\n\x03\x07\x02\x01\x12\x03\x12\x12!\n\n\n\x03\x07\x02\x03\
+    \x12\x03\x12$)\n3\n\x02\x07\x03\x12\x03\x14\x045\x1a(\x20Use\x20`bytes::\
+    Bytes`\x20for\x20`string`\x20fields\n\n\n\n\x03\x07\x03\x02\x12\x03\x0c\
+    \x07\"\n\n\n\x03\x07\x03\x04\x12\x03\x14\x04\x0c\n\n\n\x03\x07\x03\x05\
+    \x12\x03\x14\r\x11\n\n\n\x03\x07\x03\x01\x12\x03\x14\x12,\n\n\n\x03\x07\
+    \x03\x03\x12\x03\x14/4\nN\n\x02\x07\x04\x12\x03\x17\x04+\x1aC\x20When\
+    \x20true,\x20will\x20only\x20generate\x20codes\x20that\x20works\x20with\
+    \x20lite\x20runtime.\n\n\n\n\x03\x07\x04\x02\x12\x03\x0c\x07\"\n\n\n\x03\
+    \x07\x04\x04\x12\x03\x17\x04\x0c\n\n\n\x03\x07\x04\x05\x12\x03\x17\r\x11\
+    \n\n\n\x03\x07\x04\x01\x12\x03\x17\x12\"\n\n\n\x03\x07\x04\x03\x12\x03\
+    \x17%*\n9\n\x02\x07\x05\x12\x03\x1a\x046\x1a.\x20Generate\x20`FIELD_COUN\
+    T`\x20constant\x20for\x20messages\n\n\n\n\x03\x07\x05\x02\x12\x03\x0c\
+    \x07\"\n\n\n\x03\x07\x05\x04\x12\x03\x1a\x04\x0c\n\n\n\x03\x07\x05\x05\
+    \x12\x03\x1a\r\x11\n\n\n\x03\x07\x05\x01\x12\x03\x1a\x12-\n\n\n\x03\x07\
+    \x05\x03\x12\x03\x1a05\nI\n\x02\x07\x06\x12\x03\x1d\x04(\x1a>\x20Derive\
+    \x20`Eq`\x20for\x20messages\x20which\x20have\x20no\x20floating\x20point\
+    \x20fields\n\n\n\n\x03\x07\x06\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x06\
+    \x04\x12\x03\x1d\x04\x0c\n\n\n\x03\x07\x06\x05\x12\x03\x1d\r\x11\n\n\n\
+    \x03\x07\x06\x01\x12\x03\x1d\x12\x1f\n\n\n\x03\x07\x06\x03\x12\x03\x1d\"\
+    '\n;\n\x02\x07\x07\x12\x03\x20\x04.\x1a0\x20Generate\x20`FIELDS`\x20cons\
+    tant\x20with\x20field\x20metadata\n\n\n\n\x03\x07\x07\x02\x12\x03\x0c\
+    \x07\"\n\n\n\x03\x07\x07\x04\x12\x03\x20\x04\x0c\n\n\n\x03\x07\x07\x05\
+    \x12\x03\x20\r\x11\n\n\n\x03\x07\x07\x01\x12\x03\x20\x12%\n\n\n\x03\x07\
+    \x07\x03\x12\x03\x20(-\nK\n\x02\x07\x08\x12\x03#\x040\x1a@\x20Generate\
+    \x20type-state\x20builders\x20for\x20messages\x20with\x20required\x20fie\
+    lds\n\n\n\n\x03\x07\x08\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x08\x04\x12\
+    \x03#\x04\x0c\n\n\n\x03\x07\x08\x05\x12\x03#\r\x11\n\n\n\x03\x07\x08\x01\
+    \x12\x03#\x12'\n\n\n\x03\x07\x08\x03\x12\x03#*/\nC\n\x02\x07\t\x12\x03&\
+    \x04*\x1a8\x20Implement\x20`Hash`\x20for\x20messages,\x20ignoring\x20spe\
+    cial\x20fields\n\n\n\n\x03\x07\t\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\t\
+    \x04\x12\x03&\x04\x0c\n\n\n\x03\x07\t\x05\x12\x03&\r\x11\n\n\n\x03\x07\t\
+    \x01\x12\x03&\x12!\n\n\n\x03\x07\t\x03\x12\x03&$)\n;\n\x02\x07\n\x12\x03\
+    )\x04+\x1a0\x20Generate\x20API\x20resembling\x20`prost`\x20generated\x20\
+    code\n\n\n\n\x03\x07\n\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\n\x04\x12\x03\
+    )\x04\x0c\n\n\n\x03\x07\n\x05\x12\x03)\r\x11\n\n\n\x03\x07\n\x01\x12\x03\
+    )\x12\"\n\n\n\x03\x07\n\x03\x12\x03)%*\n0\n\x02\x07\x0b\x12\x03,\x040\
+    \x1a%\x20Implement\x20`TryFrom<&str>`\x20for\x20enums\n\n\n\n\x03\x07\
+    \x0b\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x0b\x04\x12\x03,\x04\x0c\n\n\n\
+    \x03\x07\x0b\x05\x12\x03,\r\x11\n\n\n\x03\x07\x0b\x01\x12\x03,\x12'\n\n\
+    \n\x03\x07\x0b\x03\x12\x03,*/\nI\n\x02\x07\x0c\x12\x03/\x042\x1a>\x20Gen\
+    erate\x20serde\x20attributes\x20to\x20omit\x20fields\x20with\x20default\
+    \x20values\n\n\n\n\x03\x07\x0c\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x0c\
+    \x04\x12\x03/\x04\x0c\n\n\n\x03\x07\x0c\x05\x12\x03/\r\x11\n\n\n\x03\x07\
+    \x0c\x01\x12\x03/\x12)\n\n\n\x03\x07\x0c\x03\x12\x03/,1\n?\n\x02\x07\r\
+    \x12\x032\x04.\x1a4\x20Use\x20`BTreeMap`\x20instead\x20of\x20`HashMap`\
+    \x20for\x20map\x20fields\n\n\n\n\x03\x07\r\x02\x12\x03\x0c\x07\"\n\n\n\
+    \x03\x07\r\x04\x12\x032\x04\x0c\n\n\n\x03\x07\r\x05\x12\x032\r\x11\n\n\n\
+    \x03\x07\r\x01\x12\x032\x12%\n\n\n\x03\x07\r\x03\x12\x032(-\n>\n\x02\x07\
+    \x0e\x12\x035\x042\x1a3\x20Generate\x20`ALL`\x20constant\x20with\x20all\
+    \x20values\x20for\x20enums\n\n\n\n\x03\x07\x0e\x02\x12\x03\x0c\x07\"\n\n\
+    \n\x03\x07\x0e\x04\x12\x035\x04\x0c\n\n\n\x03\x07\x0e\x05\x12\x035\r\x11\
+    \n\n\n\x03\x07\x0e\x01\x12\x035\x12)\n\n\n\x03\x07\x0e\x03\x12\x035,1\nO\
+    \n\x02\x07\x0f\x12\x038\x04.\x1aD\x20Implement\x20`TryFrom<i32>`\x20and\
+    \x20`TryFrom<EnumOrUnknown<E>>`\x20for\x20enums\n\n\n\n\x03\x07\x0f\x02\
+    \x12\x03\x0c\x07\"\n\n\n\x03\x07\x0f\x04\x12\x038\x04\x0c\n\n\n\x03\x07\
+    \x0f\x05\x12\x038\r\x11\n\n\n\x03\x07\x0f\x01\x12\x038\x12%\n\n\n\x03\
+    \x07\x0f\x03\x12\x038(-\n3\n\x02\x07\x10\x12\x03;\x04/\x1a(\x20Add\x20`#\
+    [inline]`\x20to\x20generated\x20accessors\n\n\n\n\x03\x07\x10\x02\x12\
+    \x03\x0c\x07\"\n\n\n\x03\x07\x10\x04\x12\x03;\x04\x0c\n\n\n\x03\x07\x10\
+    \x05\x12\x03;\r\x11\n\n\n\x03\x07\x10\x01\x12\x03;\x12&\n\n\n\x03\x07\
+    \x10\x03\x12\x03;).\nM\n\x02\x07\x11\x12\x03>\x04/\x1aB\x20Wrap\x20parse\
+    \x20errors\x20with\x20field\x20path\x20and\x20log\x20them\x20with\x20`lo\
+    g::warn!`\n\n\n\n\x03\x07\x11\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x11\
+    \x04\x12\x03>\x04\x0c\n\n\n\x03\x07\x11\x05\x12\x03>\r\x11\n\n\n\x03\x07\
+    \x11\x01\x12\x03>\x12&\n\n\n\x03\x07\x11\x03\x12\x03>).\nA\n\x02\x07\x12\
+    \x12\x03A\x043\x1a6\x20Generate\x20`FULL_NAME`\x20constant\x20with\x20me\
+    ssage\x20full\x20name\n\n\n\n\x03\x07\x12\x02\x12\x03\x0c\x07\"\n\n\n\
+    \x03\x07\x12\x04\x12\x03A\x04\x0c\n\n\n\x03\x07\x12\x05\x12\x03A\r\x11\n\
+    \n\n\x03\x07\x12\x01\x12\x03A\x12*\n\n\n\x03\x07\x12\x03\x12\x03A-2\nU\n\
+    \x02\x07\x13\x12\x03D\x04-\x1aJ\x20Generate\x20`xxx_iter()`\x20accessors\
+    \x20returning\x20iterators\x20over\x20repeated\x20fields\n\n\n\n\x03\x07\
+    \x13\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x13\x04\x12\x03D\x04\x0c\n\n\n\
+    \x03\x07\x13\x05\x12\x03D\r\x11\n\n\n\x03\x07\x13\x01\x12\x03D\x12$\n\n\
+    \n\x03\x07\x13\x03\x12\x03D',\nN\n\x02\x07\x14\x12\x03G\x04/\x1aC\x20Imp\
+    lement\x20conversions\x20between\x20messages\x20and\x20tuples\x20of\x20t\
+    heir\x20fields\n\n\n\n\x03\x07\x14\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\
+    \x14\x04\x12\x03G\x04\x0c\n\n\n\x03\x07\x14\x05\x12\x03G\r\x11\n\n\n\x03\
+    \x07\x14\x01\x12\x03G\x12&\n\n\n\x03\x07\x14\x03\x12\x03G).\na\n\x02\x07\
+    \x15\x12\x03J\x04/\x1aV\x20Generate\x20`merge_from_json_str`\x20function\
+    s\x20which\x20do\x20not\x20use\x20reflection\x20for\x20most\x20fields\n\
+    \n\n\n\x03\x07\x15\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x15\x04\x12\x03J\
+    \x04\x0c\n\n\n\x03\x07\x15\x05\x12\x03J\r\x11\n\n\n\x03\x07\x15\x01\x12\
+    \x03J\x12&\n\n\n\x03\x07\x15\x03\x12\x03J).\nQ\n\x02\x07\x16\x12\x03M\
+    \x04+\x1aF\x20Add\x20`#[track_caller]`\x20to\x20generated\x20accessors\
+    \x20which\x20unwrap\x20internally\n\n\n\n\x03\x07\x16\x02\x12\x03\x0c\
+    \x07\"\n\n\n\x03\x07\x16\x04\x12\x03M\x04\x0c\n\n\n\x03\x07\x16\x05\x12\
+    \x03M\r\x11\n\n\n\x03\x07\x16\x01\x12\x03M\x12\"\n\n\n\x03\x07\x16\x03\
+    \x12\x03M%*\nN\n\x02\x07\x17\x12\x03P\x046\x1aC\x20Serialize\x20repeated\
+    \x20fields\x20unpacked\x20even\x20if\x20`packed`\x20option\x20is\x20set\
+    \n\n\n\n\x03\x07\x17\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x17\x04\x12\x03\
+    P\x04\x0c\n\n\n\x03\x07\x17\x05\x12\x03P\r\x11\n\n\n\x03\x07\x17\x01\x12\
+    \x03P\x12-\n\n\n\x03\x07\x17\x03\x12\x03P05\nI\n\x02\x07\x18\x12\x03S\
+    \x041\x1a>\x20Generate\x20`From`\x20conversions\x20from\x20member\x20typ\
+    es\x20to\x20oneof\x20enums\n\n\n\n\x03\x07\x18\x02\x12\x03\x0c\x07\"\n\n\
+    \n\x03\x07\x18\x04\x12\x03S\x04\x0c\n\n\n\x03\x07\x18\x05\x12\x03S\r\x11\
+    \n\n\n\x03\x07\x18\x01\x12\x03S\x12(\n\n\n\x03\x07\x18\x03\x12\x03S+0\nQ\
+    \n\x02\x07\x19\x12\x03V\x043\x1aF\x20Flatten\x20oneof\x20members\x20into\
+    \x20the\x20parent\x20object\x20in\x20serde\x20representation\n\n\n\n\x03\
+    \x07\x19\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x19\x04\x12\x03V\x04\x0c\n\
+    \n\n\x03\x07\x19\x05\x12\x03V\r\x11\n\n\n\x03\x07\x19\x01\x12\x03V\x12*\
+    \n\n\n\x03\x07\x19\x03\x12\x03V-2\nO\n\x02\x07\x1a\x12\x03Y\x046\x1aD\
+    \x20Generate\x20`try_get_`\x20getters\x20returning\x20`Result`\x20for\
+    \x20required\x20fields\n\n\n\n\x03\x07\x1a\x02\x12\x03\x0c\x07\"\n\n\n\
+    \x03\x07\x1a\x04\x12\x03Y\x04\x0c\n\n\n\x03\x07\x1a\x05\x12\x03Y\r\x11\n\
+    \n\n\x03\x07\x1a\x01\x12\x03Y\x12-\n\n\n\x03\x07\x1a\x03\x12\x03Y05\n#\n\
+    \x02\x07\x1b\x12\x03\\\x041\x1a\x18\x20Log\x20getter\x20invocations\n\n\
+    \n\n\x03\x07\x1b\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x1b\x04\x12\x03\\\
+    \x04\x0c\n\n\n\x03\x07\x1b\x05\x12\x03\\\r\x11\n\n\n\x03\x07\x1b\x01\x12\
+    \x03\\\x12(\n\n\n\x03\x07\x1b\x03\x12\x03\\+0\nA\n\x02\x07\x1c\x12\x03_\
+    \x040\x1a6\x20Generate\x20`iter()`\x20and\x20`as_str()`\x20functions\x20\
+    for\x20enums\n\n\n\n\x03\x07\x1c\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x1c\
+    \x04\x12\x03_\x04\x0c\n\n\n\x03\x07\x1c\x05\x12\x03_\r\x11\n\n\n\x03\x07\
+    \x1c\x01\x12\x03_\x12'\n\n\n\x03\x07\x1c\x03\x12\x03_*/\nF\n\x02\x07\x1d\
+    \x12\x03b\x049\x1a;\x20Generate\x20`Copy`\x20twins\x20of\x20messages\x20\
+    with\x20only\x20scalar\x20fields\n\n\n\n\x03\x07\x1d\x02\x12\x03\x0c\x07\
+    \"\n\n\n\x03\x07\x1d\x04\x12\x03b\x04\x0c\n\n\n\x03\x07\x1d\x05\x12\x03b\
+    \r\x11\n\n\n\x03\x07\x1d\x01\x12\x03b\x120\n\n\n\x03\x07\x1d\x03\x12\x03\
+    b38\nA\n\x02\x07\x1e\x12\x03e\x042\x1a6\x20Implement\x20`FromStr`\x20for\
+    \x20messages\x20parsing\x20text\x20format\n\n\n\n\x03\x07\x1e\x02\x12\
+    \x03\x0c\x07\"\n\n\n\x03\x07\x1e\x04\x12\x03e\x04\x0c\n\n\n\x03\x07\x1e\
+    \x05\x12\x03e\r\x11\n\n\n\x03\x07\x1e\x01\x12\x03e\x12)\n\n\n\x03\x07\
+    \x1e\x03\x12\x03e,1\nQ\n\x02\x07\x1f\x12\x03h\x04-\x1aF\x20Implement\x20\
+    `PartialEq`\x20comparing\x20floating\x20point\x20fields\x20by\x20bit\x20\
+    pattern\n\n\n\n\x03\x07\x1f\x02\x12\x03\x0c\x07\"\n\n\n\x03\x07\x1f\x04\
+    \x12\x03h\x04\x0c\n\n\n\x03\x07\x1f\x05\x12\x03h\r\x11\n\n\n\x03\x07\x1f\
+    \x01\x12\x03h\x12$\n\n\n\x03\x07\x1f\x03\x12\x03h',\n\n\n\x01\x07\x12\
+    \x05k\0\xb6\x01\x01\nP\n\x02\x07\x20\x12\x03m\x04-\x1aE\x20When\x20false\
+    ,\x20`get_`,\x20`set_`,\x20`mut_`\x20etc.\x20accessors\x20are\x20not\x20\
+    generated\n\n\n\n\x03\x07\x20\x02\x12\x03k\x07%\n\n\n\x03\x07\x20\x04\
+    \x12\x03m\x04\x0c\n\n\n\x03\x07\x20\x05\x12\x03m\r\x11\n\n\n\x03\x07\x20\
+    \x01\x12\x03m\x12$\n\n\n\x03\x07\x20\x03\x12\x03m',\nL\n\x02\x07!\x12\
+    \x03o\x04*\x1aA\x20When\x20false,\x20`get_`\x20is\x20not\x20generated\
+    \x20even\x20if\x20`syntax\x20=\x20\"proto2\"`\n\n\n\n\x03\x07!\x02\x12\
+    \x03k\x07%\n\n\n\x03\x07!\x04\x12\x03o\x04\x0c\n\n\n\x03\x07!\x05\x12\
+    \x03o\r\x11\n\n\n\x03\x07!\x01\x12\x03o\x12!\n\n\n\x03\x07!\x03\x12\x03o\
+    $)\n2\n\x02\x07\"\x12\x03q\x04&\x1a'\x20Use\x20`bytes::Bytes`\x20for\x20\
+    `bytes`\x20fields\n\n\n\n\x03\x07\"\x02\x12\x03k\x07%\n\n\n\x03\x07\"\
+    \x04\x12\x03q\x04\x0c\n\n\n\x03\x07\"\x05\x12\x03q\r\x11\n\n\n\x03\x07\"\
+    \x01\x12\x03q\x12\x1d\n\n\n\x03\x07\"\x03\x12\x03q\x20%\n3\n\x02\x07#\
+    \x12\x03s\x041\x1a(\x20Use\x20`bytes::Bytes`\x20for\x20`string`\x20field\
+    s\n\n\n\n\x03\x07#\x02\x12\x03k\x07%\n\n\n\x03\x07#\x04\x12\x03s\x04\x0c\
+    \n\n\n\x03\x07#\x05\x12\x03s\r\x11\n\n\n\x03\x07#\x01\x12\x03s\x12(\n\n\
+    \n\x03\x07#\x03\x12\x03s+0\n9\n\x02\x07$\x12\x03v\x042\x1a.\x20Generate\
+    \x20`FIELD_COUNT`\x20constant\x20for\x20messages\n\n\n\n\x03\x07$\x02\
+    \x12\x03k\x07%\n\n\n\x03\x07$\x04\x12\x03v\x04\x0c\n\n\n\x03\x07$\x05\
+    \x12\x03v\r\x11\n\n\n\x03\x07$\x01\x12\x03v\x12)\n\n\n\x03\x07$\x03\x12\
+    \x03v,1\n;\n\x02\x07%\x12\x03y\x04*\x1a0\x20Generate\x20`FIELDS`\x20cons\
+    tant\x20with\x20field\x20metadata\n\n\n\n\x03\x07%\x02\x12\x03k\x07%\n\n\
+    \n\x03\x07%\x04\x12\x03y\x04\x0c\n\n\n\x03\x07%\x05\x12\x03y\r\x11\n\n\n\
+    \x03\x07%\x01\x12\x03y\x12!\n\n\n\x03\x07%\x03\x12\x03y$)\nK\n\x02\x07&\
+    \x12\x03|\x04,\x1a@\x20Generate\x20type-state\x20builders\x20for\x20mess\
+    ages\x20with\x20required\x20fields\n\n\n\n\x03\x07&\x02\x12\x03k\x07%\n\
+    \n\n\x03\x07&\x04\x12\x03|\x04\x0c\n\n\n\x03\x07&\x05\x12\x03|\r\x11\n\n\
+    \n\x03\x07&\x01\x12\x03|\x12#\n\n\n\x03\x07&\x03\x12\x03|&+\nC\n\x02\x07\
+    '\x12\x03\x7f\x04&\x1a8\x20Implement\x20`Hash`\x20for\x20messages,\x20ig\
+    noring\x20special\x20fields\n\n\n\n\x03\x07'\x02\x12\x03k\x07%\n\n\n\x03\
+    \x07'\x04\x12\x03\x7f\x04\x0c\n\n\n\x03\x07'\x05\x12\x03\x7f\r\x11\n\n\n\
+    \x03\x07'\x01\x12\x03\x7f\x12\x1d\n\n\n\x03\x07'\x03\x12\x03\x7f\x20%\n<\
+    \n\x02\x07(\x12\x04\x82\x01\x04'\x1a0\x20Generate\x20API\x20resembling\
+    \x20`prost`\x20generated\x20code\n\n\n\n\x03\x07(\x02\x12\x03k\x07%\n\
+    \x0b\n\x03\x07(\x04\x12\x04\x82\x01\x04\x0c\n\x0b\n\x03\x07(\x05\x12\x04\
+    \x82\x01\r\x11\n\x0b\n\x03\x07(\x01\x12\x04\x82\x01\x12\x1e\n\x0b\n\x03\
+    \x07(\x03\x12\x04\x82\x01!&\nJ\n\x02\x07)\x12\x04\x85\x01\x04.\x1a>\x20G\
+    enerate\x20serde\x20attributes\x20to\x20omit\x20fields\x20with\x20defaul\
+    t\x20values\n\n\n\n\x03\x07)\x02\x12\x03k\x07%\n\x0b\n\x03\x07)\x04\x12\
+    \x04\x85\x01\x04\x0c\n\x0b\n\x03\x07)\x05\x12\x04\x85\x01\r\x11\n\x0b\n\
+    \x03\x07)\x01\x12\x04\x85\x01\x12%\n\x0b\n\x03\x07)\x03\x12\x04\x85\x01(\
+    -\n@\n\x02\x07*\x12\x04\x88\x01\x04*\x1a4\x20Use\x20`BTreeMap`\x20instea\
+    d\x20of\x20`HashMap`\x20for\x20map\x20fields\n\n\n\n\x03\x07*\x02\x12\
+    \x03k\x07%\n\x0b\n\x03\x07*\x04\x12\x04\x88\x01\x04\x0c\n\x0b\n\x03\x07*\
+    \x05\x12\x04\x88\x01\r\x11\n\x0b\n\x03\x07*\x01\x12\x04\x88\x01\x12!\n\
+    \x0b\n\x03\x07*\x03\x12\x04\x88\x01$)\n4\n\x02\x07+\x12\x04\x8b\x01\x04+\
+    \x1a(\x20Add\x20`#[inline]`\x20to\x20generated\x20accessors\n\n\n\n\x03\
+    \x07+\x02\x12\x03k\x07%\n\x0b\n\x03\x07+\x04\x12\x04\x8b\x01\x04\x0c\n\
+    \x0b\n\x03\x07+\x05\x12\x04\x8b\x01\r\x11\n\x0b\n\x03\x07+\x01\x12\x04\
+    \x8b\x01\x12\"\n\x0b\n\x03\x07+\x03\x12\x04\x8b\x01%*\nN\n\x02\x07,\x12\
+    \x04\x8e\x01\x04+\x1aB\x20Wrap\x20parse\x20errors\x20with\x20field\x20pa\
+    th\x20and\x20log\x20them\x20with\x20`log::warn!`\n\n\n\n\x03\x07,\x02\
+    \x12\x03k\x07%\n\x0b\n\x03\x07,\x04\x12\x04\x8e\x01\x04\x0c\n\x0b\n\x03\
+    \x07,\x05\x12\x04\x8e\x01\r\x11\n\x0b\n\x03\x07,\x01\x12\x04\x8e\x01\x12\
+    \"\n\x0b\n\x03\x07,\x03\x12\x04\x8e\x01%*\nB\n\x02\x07-\x12\x04\x91\x01\
+    \x04/\x1a6\x20Generate\x20`FULL_NAME`\x20constant\x20with\x20message\x20\
+    full\x20name\n\n\n\n\x03\x07-\x02\x12\x03k\x07%\n\x0b\n\x03\x07-\x04\x12\
+    \x04\x91\x01\x04\x0c\n\x0b\n\x03\x07-\x05\x12\x04\x91\x01\r\x11\n\x0b\n\
+    \x03\x07-\x01\x12\x04\x91\x01\x12&\n\x0b\n\x03\x07-\x03\x12\x04\x91\x01)\
+    .\nV\n\x02\x07.\x12\x04\x94\x01\x04)\x1aJ\x20Generate\x20`xxx_iter()`\
+    \x20accessors\x20returning\x20iterators\x20over\x20repeated\x20fields\n\
+    \n\n\n\x03\x07.\x02\x12\x03k\x07%\n\x0b\n\x03\x07.\x04\x12\x04\x94\x01\
+    \x04\x0c\n\x0b\n\x03\x07.\x05\x12\x04\x94\x01\r\x11\n\x0b\n\x03\x07.\x01\
+    \x12\x04\x94\x01\x12\x20\n\x0b\n\x03\x07.\x03\x12\x04\x94\x01#(\nO\n\x02\
+    \x07/\x12\x04\x97\x01\x04+\x1aC\x20Implement\x20conversions\x20between\
+    \x20messages\x20and\x20tuples\x20of\x20their\x20fields\n\n\n\n\x03\x07/\
+    \x02\x12\x03k\x07%\n\x0b\n\x03\x07/\x04\x12\x04\x97\x01\x04\x0c\n\x0b\n\
+    \x03\x07/\x05\x12\x04\x97\x01\r\x11\n\x0b\n\x03\x07/\x01\x12\x04\x97\x01\
+    \x12\"\n\x0b\n\x03\x07/\x03\x12\x04\x97\x01%*\nb\n\x02\x070\x12\x04\x9a\
+    \x01\x04+\x1aV\x20Generate\x20`merge_from_json_str`\x20functions\x20whic\
+    h\x20do\x20not\x20use\x20reflection\x20for\x20most\x20fields\n\n\n\n\x03\
+    \x070\x02\x12\x03k\x07%\n\x0b\n\x03\x070\x04\x12\x04\x9a\x01\x04\x0c\n\
+    \x0b\n\x03\x070\x05\x12\x04\x9a\x01\r\x11\n\x0b\n\x03\x070\x01\x12\x04\
+    \x9a\x01\x12\"\n\x0b\n\x03\x070\x03\x12\x04\x9a\x01%*\nR\n\x02\x071\x12\
+    \x04\x9d\x01\x04'\x1aF\x20Add\x20`#[track_caller]`\x20to\x20generated\
+    \x20accessors\x20which\x20unwrap\x20internally\n\n\n\n\x03\x071\x02\x12\
+    \x03k\x07%\n\x0b\n\x03\x071\x04\x12\x04\x9d\x01\x04\x0c\n\x0b\n\x03\x071\
+    \x05\x12\x04\x9d\x01\r\x11\n\x0b\n\x03\x071\x01\x12\x04\x9d\x01\x12\x1e\
+    \n\x0b\n\x03\x071\x03\x12\x04\x9d\x01!&\nO\n\x02\x072\x12\x04\xa0\x01\
+    \x042\x1aC\x20Serialize\x20repeated\x20fields\x20unpacked\x20even\x20if\
+    \x20`packed`\x20option\x20is\x20set\n\n\n\n\x03\x072\x02\x12\x03k\x07%\n\
+    \x0b\n\x03\x072\x04\x12\x04\xa0\x01\x04\x0c\n\x0b\n\x03\x072\x05\x12\x04\
+    \xa0\x01\r\x11\n\x0b\n\x03\x072\x01\x12\x04\xa0\x01\x12)\n\x0b\n\x03\x07\
+    2\x03\x12\x04\xa0\x01,1\nJ\n\x02\x073\x12\x04\xa3\x01\x04-\x1a>\x20Gener\
+    ate\x20`From`\x20conversions\x20from\x20member\x20types\x20to\x20oneof\
+    \x20enums\n\n\n\n\x03\x073\x02\x12\x03k\x07%\n\x0b\n\x03\x073\x04\x12\
+    \x04\xa3\x01\x04\x0c\n\x0b\n\x03\x073\x05\x12\x04\xa3\x01\r\x11\n\x0b\n\
+    \x03\x073\x01\x12\x04\xa3\x01\x12$\n\x0b\n\x03\x073\x03\x12\x04\xa3\x01'\
+    ,\nR\n\x02\x074\x12\x04\xa6\x01\x04/\x1aF\x20Flatten\x20oneof\x20members\
+    \x20into\x20the\x20parent\x20object\x20in\x20serde\x20representation\n\n\
+    \n\n\x03\x074\x02\x12\x03k\x07%\n\x0b\n\x03\x074\x04\x12\x04\xa6\x01\x04\
+    \x0c\n\x0b\n\x03\x074\x05\x12\x04\xa6\x01\r\x11\n\x0b\n\x03\x074\x01\x12\
+    \x04\xa6\x01\x12&\n\x0b\n\x03\x074\x03\x12\x04\xa6\x01).\nP\n\x02\x075\
+    \x12\x04\xa9\x01\x042\x1aD\x20Generate\x20`try_get_`\x20getters\x20retur\
+    ning\x20`Result`\x20for\x20required\x20fields\n\n\n\n\x03\x075\x02\x12\
+    \x03k\x07%\n\x0b\n\x03\x075\x04\x12\x04\xa9\x01\x04\x0c\n\x0b\n\x03\x075\
+    \x05\x12\x04\xa9\x01\r\x11\n\x0b\n\x03\x075\x01\x12\x04\xa9\x01\x12)\n\
+    \x0b\n\x03\x075\x03\x12\x04\xa9\x01,1\n$\n\x02\x076\x12\x04\xac\x01\x04-\
+    \x1a\x18\x20Log\x20getter\x20invocations\n\n\n\n\x03\x076\x02\x12\x03k\
+    \x07%\n\x0b\n\x03\x076\x04\x12\x04\xac\x01\x04\x0c\n\x0b\n\x03\x076\x05\
+    \x12\x04\xac\x01\r\x11\n\x0b\n\x03\x076\x01\x12\x04\xac\x01\x12$\n\x0b\n\
+    \x03\x076\x03\x12\x04\xac\x01',\nG\n\x02\x077\x12\x04\xaf\x01\x045\x1a;\
+    \x20Generate\x20`Copy`\x20twins\x20of\x20messages\x20with\x20only\x20sca\
+    lar\x20fields\n\n\n\n\x03\x077\x02\x12\x03k\x07%\n\x0b\n\x03\x077\x04\
+    \x12\x04\xaf\x01\x04\x0c\n\x0b\n\x03\x077\x05\x12\x04\xaf\x01\r\x11\n\
+    \x0b\n\x03\x077\x01\x12\x04\xaf\x01\x12,\n\x0b\n\x03\x077\x03\x12\x04\
+    \xaf\x01/4\nB\n\x02\x078\x12\x04\xb2\x01\x04.\x1a6\x20Implement\x20`From\
+    Str`\x20for\x20messages\x20parsing\x20text\x20format\n\n\n\n\x03\x078\
+    \x02\x12\x03k\x07%\n\x0b\n\x03\x078\x04\x12\x04\xb2\x01\x04\x0c\n\x0b\n\
+    \x03\x078\x05\x12\x04\xb2\x01\r\x11\n\x0b\n\x03\x078\x01\x12\x04\xb2\x01\
+    \x12%\n\x0b\n\x03\x078\x03\x12\x04\xb2\x01(-\nR\n\x02\x079\x12\x04\xb5\
+    \x01\x04)\x1aF\x20Implement\x20`PartialEq`\x20comparing\x20floating\x20p\
+    oint\x20fields\x20by\x20bit\x20pattern\n\n\n\n\x03\x079\x02\x12\x03k\x07\
+    %\n\x0b\n\x03\x079\x04\x12\x04\xb5\x01\x04\x0c\n\x0b\n\x03\x079\x05\x12\
+    \x04\xb5\x01\r\x11\n\x0b\n\x03\x079\x01\x12\x04\xb5\x01\x12\x20\n\x0b\n\
+    \x03\x079\x03\x12\x04\xb5\x01#(\n\x0b\n\x01\x07\x12\x06\xb8\x01\0\xdf\
+    \x01\x01\nQ\n\x02\x07:\x12\x04\xba\x01\x043\x1aE\x20When\x20false,\x20`g\
+    et_`,\x20`set_`,\x20`mut_`\x20etc.\x20accessors\x20are\x20not\x20generat\
+    ed\n\n\x0b\n\x03\x07:\x02\x12\x04\xb8\x01\x07#\n\x0b\n\x03\x07:\x04\x12\
+    \x04\xba\x01\x04\x0c\n\x0b\n\x03\x07:\x05\x12\x04\xba\x01\r\x11\n\x0b\n\
+    \x03\x07:\x01\x12\x04\xba\x01\x12*\n\x0b\n\x03\x07:\x03\x12\x04\xba\x01-\
+    2\nM\n\x02\x07;\x12\x04\xbc\x01\x040\x1aA\x20When\x20false,\x20`get_`\
+    \x20is\x20not\x20generated\x20even\x20if\x20`syntax\x20=\x20\"proto2\"`\
+    \n\n\x0b\n\x03\x07;\x02\x12\x04\xb8\x01\x07#\n\x0b\n\x03\x07;\x04\x12\
+    \x04\xbc\x01\x04\x0c\n\x0b\n\x03\x07;\x05\x12\x04\xbc\x01\r\x11\n\x0b\n\
+    \x03\x07;\x01\x12\x04\xbc\x01\x12'\n\x0b\n\x03\x07;\x03\x12\x04\xbc\x01*\
+    /\n3\n\x02\x07<\x12\x04\xbe\x01\x04,\x1a'\x20Use\x20`bytes::Bytes`\x20fo\
+    r\x20`bytes`\x20fields\n\n\x0b\n\x03\x07<\x02\x12\x04\xb8\x01\x07#\n\x0b\
+    \n\x03\x07<\x04\x12\x04\xbe\x01\x04\x0c\n\x0b\n\x03\x07<\x05\x12\x04\xbe\
+    \x01\r\x11\n\x0b\n\x03\x07<\x01\x12\x04\xbe\x01\x12#\n\x0b\n\x03\x07<\
+    \x03\x12\x04\xbe\x01&+\n4\n\x02\x07=\x12\x04\xc0\x01\x047\x1a(\x20Use\
+    \x20`bytes::Bytes`\x20for\x20`string`\x20fields\n\n\x0b\n\x03\x07=\x02\
+    \x12\x04\xb8\x01\x07#\n\x0b\n\x03\x07=\x04\x12\x04\xc0\x01\x04\x0c\n\x0b\
+    \n\x03\x07=\x05\x12\x04\xc0\x01\r\x11\n\x0b\n\x03\x07=\x01\x12\x04\xc0\
+    \x01\x12.\n\x0b\n\x03\x07=\x03\x12\x04\xc0\x0116\n[\n\x02\x07>\x12\x04\
+    \xc3\x01\x04-\x1aO\x20Print\x20`<redacted>`\x20instead\x20of\x20field\
+    \x20value\x20in\x20generated\x20`Debug`\x20implementation\n\n\x0b\n\x03\
+    \x07>\x02\x12\x04\xb8\x01\x07#\n\x0b\n\x03\x07>\x04\x12\x04\xc3\x01\x04\
+    \x0c\n\x0b\n\x03\x07>\x05\x12\x04\xc3\x01\r\x11\n\x0b\n\x03\x07>\x01\x12\
+    \x04\xc3\x01\x12$\n\x0b\n\x03\x07>\x03\x12\x04\xc3\x01',\nJ\n\x02\x07?\
+    \x12\x04\xc6\x01\x044\x1a>\x20Generate\x20serde\x20attributes\x20to\x20o\
+    mit\x20fields\x20with\x20default\x20values\n\n\x0b\n\x03\x07?\x02\x12\
+    \x04\xb8\x01\x07#\n\x0b\n\x03\x07?\x04\x12\x04\xc6\x01\x04\x0c\n\x0b\n\
+    \x03\x07?\x05\x12\x04\xc6\x01\r\x11\n\x0b\n\x03\x07?\x01\x12\x04\xc6\x01\
+    \x12+\n\x0b\n\x03\x07?\x03\x12\x04\xc6\x01.3\n@\n\x02\x07@\x12\x04\xc9\
+    \x01\x040\x1a4\x20Use\x20`BTreeMap`\x20instead\x20of\x20`HashMap`\x20for\
+    \x20map\x20fields\n\n\x0b\n\x03\x07@\x02\x12\x04\xb8\x01\x07#\n\x0b\n\
+    \x03\x07@\x04\x12\x04\xc9\x01\x04\x0c\n\x0b\n\x03\x07@\x05\x12\x04\xc9\
+    \x01\r\x11\n\x0b\n\x03\x07@\x01\x12\x04\xc9\x01\x12'\n\x0b\n\x03\x07@\
+    \x03\x12\x04\xc9\x01*/\n4\n\x02\x07A\x12\x04\xcc\x01\x041\x1a(\x20Add\
+    \x20`#[inline]`\x20to\x20generated\x20accessors\n\n\x0b\n\x03\x07A\x02\
+    \x12\x04\xb8\x01\x07#\n\x0b\n\x03\x07A\x04\x12\x04\xcc\x01\x04\x0c\n\x0b\
+    \n\x03\x07A\x05\x12\x04\xcc\x01\r\x11\n\x0b\n\x03\x07A\x01\x12\x04\xcc\
+    \x01\x12(\n\x0b\n\x03\x07A\x03\x12\x04\xcc\x01+0\nZ\n\x02\x07B\x12\x04\
+    \xcf\x01\x04)\x1aN\x20Field\x20is\x20part\x20of\x20message\x20identity\
+    \x20for\x20generated\x20`PartialEq`,\x20`Eq`\x20and\x20`Hash`\n\n\x0b\n\
+    \x03\x07B\x02\x12\x04\xb8\x01\x07#\n\x0b\n\x03\x07B\x04\x12\x04\xcf\x01\
+    \x04\x0c\n\x0b\n\x03\x07B\x05\x12\x04\xcf\x01\r\x11\n\x0b\n\x03\x07B\x01\
+    \x12\x04\xcf\x01\x12\x20\n\x0b\n\x03\x07B\x03\x12\x04\xcf\x01#(\nV\n\x02\
+    \x07C\x12\x04\xd2\x01\x04/\x1aJ\x20Generate\x20`xxx_iter()`\x20accessors\
+    \x20returning\x20iterators\x20over\x20repeated\x20fields\n\n\x0b\n\x03\
+    \x07C\x02\x12\x04\xb8\x01\x07#\n\x0b\n\x03\x07C\x04\x12\x04\xd2\x01\x04\
+    \x0c\n\x0b\n\x03\x07C\x05\x12\x04\xd2\x01\r\x11\n\x0b\n\x03\x07C\x01\x12\
+    \x04\xd2\x01\x12&\n\x0b\n\x03\x07C\x03\x12\x04\xd2\x01).\nR\n\x02\x07D\
+    \x12\x04\xd5\x01\x04-\x1aF\x20Add\x20`#[track_caller]`\x20to\x20generate\
+    d\x20accessors\x20which\x20unwrap\x20internally\n\n\x0b\n\x03\x07D\x02\
+    \x12\x04\xb8\x01\x07#\n\x0b\n\x03\x07D\x04\x12\x04\xd5\x01\x04\x0c\n\x0b\
+    \n\x03\x07D\x05\x12\x04\xd5\x01\r\x11\n\x0b\n\x03\x07D\x01\x12\x04\xd5\
+    \x01\x12$\n\x0b\n\x03\x07D\x03\x12\x04\xd5\x01',\nO\n\x02\x07E\x12\x04\
+    \xd8\x01\x048\x1aC\x20Serialize\x20repeated\x20fields\x20unpacked\x20eve\
+    n\x20if\x20`packed`\x20option\x20is\x20set\n\n\x0b\n\x03\x07E\x02\x12\
+    \x04\xb8\x01\x07#\n\x0b\n\x03\x07E\x04\x12\x04\xd8\x01\x04\x0c\n\x0b\n\
+    \x03\x07E\x05\x12\x04\xd8\x01\r\x11\n\x0b\n\x03\x07E\x01\x12\x04\xd8\x01\
+    \x12/\n\x0b\n\x03\x07E\x03\x12\x04\xd8\x0127\nP\n\x02\x07F\x12\x04\xdb\
+    \x01\x048\x1aD\x20Generate\x20`try_get_`\x20getters\x20returning\x20`Res\
+    ult`\x20for\x20required\x20fields\n\n\x0b\n\x03\x07F\x02\x12\x04\xb8\x01\
+    \x07#\n\x0b\n\x03\x07F\x04\x12\x04\xdb\x01\x04\x0c\n\x0b\n\x03\x07F\x05\
+    \x12\x04\xdb\x01\r\x11\n\x0b\n\x03\x07F\x01\x12\x04\xdb\x01\x12/\n\x0b\n\
+    \x03\x07F\x03\x12\x04\xdb\x0127\n$\n\x02\x07G\x12\x04\xde\x01\x043\x1a\
+    \x18\x20Log\x20getter\x20invocations\n\n\x0b\n\x03\x07G\x02\x12\x04\xb8\
+    \x01\x07#\n\x0b\n\x03\x07G\x04\x12\x04\xde\x01\x04\x0c\n\x0b\n\x03\x07G\
+    \x05\x12\x04\xde\x01\r\x11\n\x0b\n\x03\x07G\x01\x12\x04\xde\x01\x12*\n\
+    \x0b\n\x03\x07G\x03\x12\x04\xde\x01-2\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
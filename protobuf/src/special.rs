@@ -18,9 +18,14 @@ impl SpecialFields {
     }
 
     /// Clear.
+    ///
+    /// Cached size is reset too, so a message reused after `clear`
+    /// (e. g. taken from a pool) never exposes a size of its previous content.
+    /// Nested messages are dropped or cleared by generated `clear`,
+    /// so their cached sizes are reset as well.
     pub fn clear(&mut self) {
         self.unknown_fields.clear();
-        // No need to clear `cached_size`.
+        self.cached_size.set(0);
     }
 
     /// Getter.
@@ -0,0 +1,71 @@
+#![cfg(feature = "bytes")]
+
+use std::collections::HashSet;
+
+use crate::Chars;
+
+/// Deduplicates values of `string` fields stored as [`Chars`]
+/// (generated with `tokio_bytes_for_string` option).
+///
+/// Set with [`CodedInputStream::set_string_interner`](crate::CodedInputStream::set_string_interner).
+pub trait StringInterner {
+    /// Return `Chars` equal to `s`, sharing storage with equal values
+    /// previously returned by this interner.
+    fn intern(&mut self, s: &str) -> Chars;
+}
+
+/// [`StringInterner`] keeping all distinct strings in a hash set.
+///
+/// Strings are kept until the interner is dropped,
+/// so it should be used for data with limited set of distinct values.
+#[derive(Default, Debug)]
+pub struct HashSetStringInterner {
+    strings: HashSet<Chars>,
+}
+
+impl HashSetStringInterner {
+    /// New empty interner.
+    pub fn new() -> HashSetStringInterner {
+        HashSetStringInterner::default()
+    }
+
+    /// Number of distinct strings interned.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// No strings interned.
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+}
+
+impl StringInterner for HashSetStringInterner {
+    fn intern(&mut self, s: &str) -> Chars {
+        if let Some(chars) = self.strings.get(s) {
+            return chars.clone();
+        }
+        let chars = Chars::from(s);
+        self.strings.insert(chars.clone());
+        chars
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::HashSetStringInterner;
+    use super::StringInterner;
+
+    #[test]
+    fn test_intern() {
+        let mut interner = HashSetStringInterner::new();
+        assert!(interner.is_empty());
+        let a = interner.intern("foo");
+        let b = interner.intern(&String::from("foo"));
+        let c = interner.intern("bar");
+        assert_eq!("foo", &*a);
+        assert_eq!("bar", &*c);
+        assert_eq!(a.as_ptr(), b.as_ptr());
+        assert_eq!(2, interner.len());
+    }
+}
@@ -220,6 +220,25 @@ impl<'o> Iterator for UnknownValuesIter<'o> {
 ///
 /// For example, in this operation: load from DB, modify, store to DB,
 /// even when working with older `.proto` file, new fields won't be lost.
+///
+/// Unknown fields can also be constructed explicitly,
+/// for example, to build expected values in tests:
+///
+/// ```
+/// # use protobuf::Message;
+/// # use protobuf::UnknownFields;
+/// # use protobuf::well_known_types::empty::Empty;
+/// let mut unknown_fields = UnknownFields::new();
+/// unknown_fields.add_varint(1, 150);
+/// unknown_fields.add_length_delimited(2, b"abc".to_vec());
+///
+/// let mut message = Empty::new();
+/// *message.mut_unknown_fields() = unknown_fields.clone();
+/// let bytes = message.write_to_bytes().unwrap();
+///
+/// let parsed = Empty::parse_from_bytes(&bytes).unwrap();
+/// assert_eq!(&unknown_fields, parsed.unknown_fields());
+/// ```
 #[derive(Clone, PartialEq, Eq, Debug, Default)]
 pub struct UnknownFields {
     /// The map.
@@ -399,6 +418,9 @@ mod test {
     use std::hash::Hasher;
 
     use super::UnknownFields;
+    use crate::well_known_types::duration::Duration;
+    use crate::well_known_types::empty::Empty;
+    use crate::Message;
 
     #[test]
     fn unknown_fields_hash() {
@@ -442,4 +464,36 @@ mod test {
         let items_2: Vec<_> = u_2.iter().collect();
         assert_eq!(items_1, items_2);
     }
+
+    #[test]
+    fn unknown_fields_built_serialize_parse() {
+        let mut u = UnknownFields::new();
+        u.add_varint(1, 17);
+        u.add_fixed32(2, 18);
+        u.add_fixed64(3, 19);
+        u.add_length_delimited(4, b"abc".to_vec());
+        u.add_varint(4, 20);
+
+        let mut m = Empty::new();
+        *m.mut_unknown_fields() = u.clone();
+        let bytes = m.write_to_bytes().unwrap();
+        assert_eq!(u.write_to_bytes().len(), bytes.len());
+
+        let parsed = Empty::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(&u, parsed.unknown_fields());
+    }
+
+    #[test]
+    fn unknown_fields_built_parse_as_known() {
+        // Fields unknown to one message are known to another.
+        let mut m = Empty::new();
+        m.mut_unknown_fields().add_varint(1, 10);
+        m.mut_unknown_fields().add_varint(2, 20);
+        let bytes = m.write_to_bytes().unwrap();
+
+        let duration = Duration::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(10, duration.seconds);
+        assert_eq!(20, duration.nanos);
+        assert!(duration.unknown_fields().iter().next().is_none());
+    }
 }
@@ -1,3 +1,17 @@
+//! Varint encoding utilities.
+//!
+//! These functions can be used to read and write varints
+//! outside of protobuf messages, for example in custom framing.
+
+use std::io;
+use std::io::Read;
+use std::io::Write;
+use std::mem::MaybeUninit;
+use std::slice;
+
+use crate::varint::decode::decode_varint64;
+use crate::varint::encode::encode_varint64;
+
 pub(crate) mod decode;
 pub(crate) mod encode;
 pub(crate) mod generic;
@@ -5,3 +19,92 @@ pub(crate) mod generic;
 /// Encoded varint message is not longer than 10 bytes.
 pub(crate) const MAX_VARINT_ENCODED_LEN: usize = 10;
 pub(crate) const MAX_VARINT32_ENCODED_LEN: usize = 5;
+
+/// Read varint from reader.
+///
+/// Reader is read byte by byte, so no bytes after varint are consumed.
+/// Wrap the reader with [`BufReader`](std::io::BufReader) to avoid
+/// a syscall per byte.
+///
+/// # Errors
+///
+/// [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) is returned
+/// if reader ends before the varint is complete,
+/// [`InvalidData`](io::ErrorKind::InvalidData) is returned
+/// if varint is longer than 10 bytes or does not fit in `u64`.
+pub fn read_varint(reader: &mut impl Read) -> io::Result<u64> {
+    let mut buf = [0; MAX_VARINT_ENCODED_LEN];
+    for len in 1..=MAX_VARINT_ENCODED_LEN {
+        reader.read_exact(&mut buf[len - 1..len])?;
+        if let Some((value, _)) = decode_varint64(&buf[..len])? {
+            return Ok(value);
+        }
+    }
+    unreachable!(
+        "varint of {} bytes is either decoded or rejected",
+        MAX_VARINT_ENCODED_LEN
+    )
+}
+
+/// Write varint to writer.
+pub fn write_varint(value: u64, writer: &mut impl Write) -> io::Result<()> {
+    let buf = &mut [0u8; MAX_VARINT_ENCODED_LEN];
+    let len = encode_varint64(value, unsafe {
+        slice::from_raw_parts_mut(buf.as_mut_ptr() as *mut MaybeUninit<u8>, buf.len())
+    });
+    writer.write_all(&buf[..len])
+}
+
+/// Size of value encoded as varint.
+pub fn varint_len(value: u64) -> usize {
+    encode::encoded_varint64_len(value)
+}
+
+#[cfg(test)]
+mod test {
+    use std::io;
+
+    use super::read_varint;
+    use super::varint_len;
+    use super::write_varint;
+    use crate::hex::decode_hex;
+
+    #[test]
+    fn test_round_trip() {
+        for (value, hex) in [
+            (0, "00"),
+            (1, "01"),
+            (127, "7f"),
+            (128, "80 01"),
+            (300, "ac 02"),
+            (u32::MAX as u64, "ff ff ff ff 0f"),
+            (u64::MAX, "ff ff ff ff ff ff ff ff ff 01"),
+        ] {
+            let mut bytes = Vec::new();
+            write_varint(value, &mut bytes).unwrap();
+            assert_eq!(decode_hex(hex), bytes, "{}", value);
+            assert_eq!(bytes.len(), varint_len(value), "{}", value);
+            assert_eq!(value, read_varint(&mut &bytes[..]).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_read_does_not_consume_following_bytes() {
+        let bytes = decode_hex("80 01 17");
+        let mut reader = &bytes[..];
+        assert_eq!(128, read_varint(&mut reader).unwrap());
+        assert_eq!(&[0x17], reader);
+    }
+
+    #[test]
+    fn test_read_errors() {
+        let e = read_varint(&mut &decode_hex("80 80")[..]).unwrap_err();
+        assert_eq!(io::ErrorKind::UnexpectedEof, e.kind());
+
+        let e = read_varint(&mut &decode_hex("ff ff ff ff ff ff ff ff ff 02")[..]).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, e.kind());
+
+        let e = read_varint(&mut &decode_hex("80 80 80 80 80 80 80 80 80 80 00")[..]).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, e.kind());
+    }
+}
@@ -134,6 +134,19 @@ impl crate::Message for Any {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        if !self.type_url.is_empty() {
+            my_size += 1 + 5 + self.type_url.len();
+        }
+        if !self.value.is_empty() {
+            my_size += 1 + 5 + self.value.len();
+        }
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         if !self.type_url.is_empty() {
             os.write_string(1, &self.type_url)?;
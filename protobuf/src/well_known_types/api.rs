@@ -212,6 +212,34 @@ impl crate::Message for Api {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += 1 + 5 + self.name.len();
+        }
+        for value in &self.methods {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        for value in &self.options {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        if !self.version.is_empty() {
+            my_size += 1 + 5 + self.version.len();
+        }
+        if let Some(v) = self.source_context.as_ref() {
+            my_size += 1 + 5 + v.estimated_size();
+        }
+        for value in &self.mixins {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        if self.syntax != crate::EnumOrUnknown::new(crate::well_known_types::type_::Syntax::SYNTAX_PROTO2) {
+            my_size += 11;
+        }
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         if !self.name.is_empty() {
             os.write_string(1, &self.name)?;
@@ -451,6 +479,34 @@ impl crate::Message for Method {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += 1 + 5 + self.name.len();
+        }
+        if !self.request_type_url.is_empty() {
+            my_size += 1 + 5 + self.request_type_url.len();
+        }
+        if self.request_streaming != false {
+            my_size += 2;
+        }
+        if !self.response_type_url.is_empty() {
+            my_size += 1 + 5 + self.response_type_url.len();
+        }
+        if self.response_streaming != false {
+            my_size += 2;
+        }
+        for value in &self.options {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        if self.syntax != crate::EnumOrUnknown::new(crate::well_known_types::type_::Syntax::SYNTAX_PROTO2) {
+            my_size += 11;
+        }
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         if !self.name.is_empty() {
             os.write_string(1, &self.name)?;
@@ -619,6 +675,19 @@ impl crate::Message for Mixin {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += 1 + 5 + self.name.len();
+        }
+        if !self.root.is_empty() {
+            my_size += 1 + 5 + self.root.len();
+        }
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         if !self.name.is_empty() {
             os.write_string(1, &self.name)?;
@@ -114,6 +114,19 @@ impl crate::Message for Duration {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        if self.seconds != 0 {
+            my_size += 11;
+        }
+        if self.nanos != 0 {
+            my_size += 11;
+        }
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         if self.seconds != 0 {
             os.write_int64(1, self.seconds)?;
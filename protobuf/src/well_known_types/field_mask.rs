@@ -93,6 +93,16 @@ impl crate::Message for FieldMask {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        for value in &self.paths {
+            my_size += 1 + 5 + value.len();
+        };
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         for v in &self.paths {
             os.write_string(1, &v)?;
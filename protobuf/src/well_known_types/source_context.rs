@@ -96,6 +96,16 @@ impl crate::Message for SourceContext {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        if !self.file_name.is_empty() {
+            my_size += 1 + 5 + self.file_name.len();
+        }
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         if !self.file_name.is_empty() {
             os.write_string(1, &self.file_name)?;
@@ -117,6 +117,16 @@ impl crate::Message for Struct {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        for (k, v) in &self.fields {
+            my_size += 1 + 5 + 1 + 5 + k.len() + 1 + 5 + v.estimated_size();
+        };
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         for (k, v) in &self.fields {
             let mut entry_size = 0;
@@ -499,10 +509,16 @@ impl crate::Message for Value {
                     self.kind = ::std::option::Option::Some(value::Kind::BoolValue(is.read_bool()?));
                 },
                 42 => {
-                    self.kind = ::std::option::Option::Some(value::Kind::StructValue(is.read_message()?));
+                    match self.kind {
+                        ::std::option::Option::Some(value::Kind::StructValue(ref mut v)) => is.merge_message(v)?,
+                        _ => self.kind = ::std::option::Option::Some(value::Kind::StructValue(is.read_message()?)),
+                    };
                 },
                 50 => {
-                    self.kind = ::std::option::Option::Some(value::Kind::ListValue(is.read_message()?));
+                    match self.kind {
+                        ::std::option::Option::Some(value::Kind::ListValue(ref mut v)) => is.merge_message(v)?,
+                        _ => self.kind = ::std::option::Option::Some(value::Kind::ListValue(is.read_message()?)),
+                    };
                 },
                 tag => {
                     crate::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -545,6 +561,35 @@ impl crate::Message for Value {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.kind {
+            match v {
+                &value::Kind::NullValue(v) => {
+                    my_size += 11;
+                },
+                &value::Kind::NumberValue(v) => {
+                    my_size += 9;
+                },
+                &value::Kind::StringValue(ref v) => {
+                    my_size += 1 + 5 + v.len();
+                },
+                &value::Kind::BoolValue(v) => {
+                    my_size += 2;
+                },
+                &value::Kind::StructValue(ref v) => {
+                    my_size += 1 + 5 + v.estimated_size();
+                },
+                &value::Kind::ListValue(ref v) => {
+                    my_size += 1 + 5 + v.estimated_size();
+                },
+            };
+        }
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         if let ::std::option::Option::Some(ref v) = self.kind {
             match v {
@@ -734,6 +779,16 @@ impl crate::Message for ListValue {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        for value in &self.values {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         for v in &self.values {
             crate::rt::write_message_field_with_cached_size(1, v, os)?;
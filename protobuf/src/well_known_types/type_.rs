@@ -167,6 +167,31 @@ impl crate::Message for Type {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += 1 + 5 + self.name.len();
+        }
+        for value in &self.fields {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        for value in &self.oneofs {
+            my_size += 1 + 5 + value.len();
+        };
+        for value in &self.options {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        if let Some(v) = self.source_context.as_ref() {
+            my_size += 1 + 5 + v.estimated_size();
+        }
+        if self.syntax != crate::EnumOrUnknown::new(Syntax::SYNTAX_PROTO2) {
+            my_size += 11;
+        }
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         if !self.name.is_empty() {
             os.write_string(1, &self.name)?;
@@ -445,6 +470,43 @@ impl crate::Message for Field {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        if self.kind != crate::EnumOrUnknown::new(field::Kind::TYPE_UNKNOWN) {
+            my_size += 11;
+        }
+        if self.cardinality != crate::EnumOrUnknown::new(field::Cardinality::CARDINALITY_UNKNOWN) {
+            my_size += 11;
+        }
+        if self.number != 0 {
+            my_size += 11;
+        }
+        if !self.name.is_empty() {
+            my_size += 1 + 5 + self.name.len();
+        }
+        if !self.type_url.is_empty() {
+            my_size += 1 + 5 + self.type_url.len();
+        }
+        if self.oneof_index != 0 {
+            my_size += 11;
+        }
+        if self.packed != false {
+            my_size += 2;
+        }
+        for value in &self.options {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        if !self.json_name.is_empty() {
+            my_size += 1 + 5 + self.json_name.len();
+        }
+        if !self.default_value.is_empty() {
+            my_size += 1 + 5 + self.default_value.len();
+        }
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         if self.kind != crate::EnumOrUnknown::new(field::Kind::TYPE_UNKNOWN) {
             os.write_enum(1, crate::EnumOrUnknown::value(&self.kind))?;
@@ -897,6 +959,28 @@ impl crate::Message for Enum {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += 1 + 5 + self.name.len();
+        }
+        for value in &self.enumvalue {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        for value in &self.options {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        if let Some(v) = self.source_context.as_ref() {
+            my_size += 1 + 5 + v.estimated_size();
+        }
+        if self.syntax != crate::EnumOrUnknown::new(Syntax::SYNTAX_PROTO2) {
+            my_size += 11;
+        }
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         if !self.name.is_empty() {
             os.write_string(1, &self.name)?;
@@ -1070,6 +1154,22 @@ impl crate::Message for EnumValue {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += 1 + 5 + self.name.len();
+        }
+        if self.number != 0 {
+            my_size += 11;
+        }
+        for value in &self.options {
+            my_size += 1 + 5 + value.estimated_size();
+        };
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         if !self.name.is_empty() {
             os.write_string(1, &self.name)?;
@@ -1226,6 +1326,19 @@ impl crate::Message for Option {
         my_size
     }
 
+    #[allow(unused_variables)]
+    fn estimated_size(&self) -> usize {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += 1 + 5 + self.name.len();
+        }
+        if let Some(v) = self.value.as_ref() {
+            my_size += 1 + 5 + v.estimated_size();
+        }
+        my_size += crate::rt::unknown_fields_size(self.special_fields.unknown_fields()) as usize;
+        my_size
+    }
+
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::Result<()> {
         if !self.name.is_empty() {
             os.write_string(1, &self.name)?;
@@ -1,4 +1,8 @@
+use std::collections::HashSet;
+
+use crate::error::ProtobufError;
 use crate::message_dyn::MessageDyn;
+use crate::reflect::FileDescriptor;
 use crate::reflect::MessageDescriptor;
 use crate::well_known_types::any::Any;
 use crate::MessageFull;
@@ -108,4 +112,62 @@ impl Any {
         message.check_initialized_dyn()?;
         Ok(Some(message))
     }
+
+    /// Find a message descriptor for the type URL of this `Any`
+    /// in given files or their dependencies.
+    fn find_descriptor(&self, files: &[FileDescriptor]) -> Option<MessageDescriptor> {
+        let full_name = format!(".{}", Any::type_name_from_type_url(&self.type_url)?);
+        let mut visited = HashSet::new();
+        let mut stack: Vec<&FileDescriptor> = files.iter().rev().collect();
+        while let Some(file) = stack.pop() {
+            if !visited.insert(file.name()) {
+                continue;
+            }
+            if let Some(descriptor) = file.message_by_full_name(&full_name) {
+                return Some(descriptor);
+            }
+            stack.extend(file.deps().iter().rev());
+        }
+        None
+    }
+
+    /// Extract a message from this `Any` looking up the message type by type URL.
+    ///
+    /// Message type is searched in given files and their dependencies,
+    /// the result is a dynamic message if files are dynamic,
+    /// or a generated message if files are generated.
+    ///
+    /// # Returns
+    ///
+    /// * `Err` when message type is not found in provided files
+    /// * `Err` when parse failed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use protobuf::well_known_types::any::Any;
+    /// use protobuf::well_known_types::duration::{self, Duration};
+    ///
+    /// # fn main() -> protobuf::Result<()> {
+    /// let mut d = Duration::new();
+    /// d.seconds = 10;
+    /// let any = Any::pack(&d)?;
+    /// let m = any.unpack_dyn_from_files(&[duration::file_descriptor().clone()])?;
+    /// assert_eq!("google.protobuf.Duration", m.descriptor_dyn().full_name());
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn unpack_dyn_from_files(
+        &self,
+        files: &[FileDescriptor],
+    ) -> crate::Result<Box<dyn MessageDyn>> {
+        let descriptor = match self.find_descriptor(files) {
+            Some(descriptor) => descriptor,
+            None => return Err(ProtobufError::AnyTypeNotFound(self.type_url.clone()).into()),
+        };
+        let mut message = descriptor.new_instance();
+        message.merge_from_bytes_dyn(&self.value)?;
+        message.check_initialized_dyn()?;
+        Ok(message)
+    }
 }
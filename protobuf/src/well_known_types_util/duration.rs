@@ -8,6 +8,22 @@ impl Duration {
         nanos: 0,
         special_fields: SpecialFields::new(),
     };
+
+    /// Convert to `std::time::Duration`.
+    ///
+    /// Return `None` if the duration is negative, or if it is malformed:
+    /// `nanos` is outside of `(-1e9, 1e9)` range or has a sign different from `seconds`.
+    ///
+    /// This conversion might be lossy if `std::time::Duration` precision is smaller than nanoseconds.
+    pub fn to_std_duration(&self) -> Option<std::time::Duration> {
+        if self.seconds < 0 || !(0..1_000_000_000).contains(&self.nanos) {
+            return None;
+        }
+        Some(std::time::Duration::new(
+            self.seconds as u64,
+            self.nanos as u32,
+        ))
+    }
 }
 
 /// Convert from `std::time::Duration`.
@@ -32,11 +48,16 @@ impl From<std::time::Duration> for Duration {
 /// # Panics
 ///
 /// If `Duration` value is outside of `std::time::Duration` supported range.
+/// See [`Duration::to_std_duration`] for a non-panicking version.
 impl Into<std::time::Duration> for Duration {
     fn into(self) -> std::time::Duration {
-        assert!(self.seconds >= 0);
-        std::time::Duration::from_secs(self.seconds as u64)
-            + std::time::Duration::from_nanos(self.nanos as u64)
+        match self.to_std_duration() {
+            Some(duration) => duration,
+            None => panic!(
+                "duration is negative or malformed: {}s {}ns",
+                self.seconds, self.nanos
+            ),
+        }
     }
 }
 
@@ -64,4 +85,42 @@ mod test {
             std::time::Duration::from_millis(4_123),
         );
     }
+
+    #[test]
+    fn to_std_duration() {
+        let positive = Duration::from(std::time::Duration::new(5, 123_456_789));
+        assert_eq!(5, positive.seconds);
+        assert_eq!(123_456_789, positive.nanos);
+        assert_eq!(
+            Some(std::time::Duration::new(5, 123_456_789)),
+            positive.to_std_duration()
+        );
+
+        for (seconds, nanos) in [
+            // Negative.
+            (-5, -123_456_789),
+            (0, -1),
+            (-1, 0),
+            // Malformed.
+            (1, -1),
+            (0, 1_000_000_000),
+        ] {
+            let duration = Duration {
+                seconds,
+                nanos,
+                ..Default::default()
+            };
+            assert_eq!(None, duration.to_std_duration(), "{}s {}ns", seconds, nanos);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn into_std_duration_negative() {
+        let duration = Duration {
+            seconds: -1,
+            ..Default::default()
+        };
+        let _: std::time::Duration = duration.into();
+    }
 }
@@ -1,3 +1,5 @@
 mod any;
 mod duration;
+#[cfg(feature = "with-serde-json")]
+mod struct_;
 mod timestamp;
@@ -0,0 +1,151 @@
+//! Conversions between `Struct`, `Value`, `ListValue` and `serde_json` types.
+
+use crate::well_known_types::struct_::value::Kind;
+use crate::well_known_types::struct_::ListValue;
+use crate::well_known_types::struct_::NullValue;
+use crate::well_known_types::struct_::Struct;
+use crate::well_known_types::struct_::Value;
+use crate::EnumOrUnknown;
+
+/// Integers of larger magnitude cannot be represented exactly in `f64`.
+const MAX_EXACT_INTEGER: f64 = (1u64 << 53) as f64;
+
+/// Convert JSON value to `Value`.
+///
+/// `Value` stores numbers as `f64`, so integers larger than 2<sup>53</sup>
+/// in magnitude lose precision.
+impl From<serde_json::Value> for Value {
+    fn from(json: serde_json::Value) -> Self {
+        let kind = match json {
+            serde_json::Value::Null => Kind::NullValue(EnumOrUnknown::new(NullValue::NULL_VALUE)),
+            serde_json::Value::Bool(b) => Kind::BoolValue(b),
+            // `as_f64` never returns `None` without `arbitrary_precision` feature.
+            serde_json::Value::Number(n) => Kind::NumberValue(n.as_f64().unwrap_or(f64::NAN)),
+            serde_json::Value::String(s) => Kind::StringValue(s),
+            serde_json::Value::Array(a) => Kind::ListValue(a.into()),
+            serde_json::Value::Object(o) => Kind::StructValue(o.into()),
+        };
+        Value {
+            kind: Some(kind),
+            ..Default::default()
+        }
+    }
+}
+
+/// Convert `Value` to JSON value.
+///
+/// Numbers without fractional part are converted to JSON integers,
+/// so integers survive the round trip through `Value`.
+/// Non-finite numbers (which are not representable in JSON)
+/// and `Value` without kind are converted to `null`.
+impl From<Value> for serde_json::Value {
+    fn from(value: Value) -> Self {
+        match value.kind {
+            None | Some(Kind::NullValue(_)) => serde_json::Value::Null,
+            Some(Kind::BoolValue(b)) => serde_json::Value::Bool(b),
+            Some(Kind::NumberValue(n)) if n.fract() == 0.0 && n.abs() <= MAX_EXACT_INTEGER => {
+                serde_json::Value::Number((n as i64).into())
+            }
+            Some(Kind::NumberValue(n)) => match serde_json::Number::from_f64(n) {
+                Some(n) => serde_json::Value::Number(n),
+                None => serde_json::Value::Null,
+            },
+            Some(Kind::StringValue(s)) => serde_json::Value::String(s),
+            Some(Kind::ListValue(l)) => serde_json::Value::Array(l.into()),
+            Some(Kind::StructValue(s)) => serde_json::Value::Object(s.into()),
+        }
+    }
+}
+
+/// Convert JSON array to `ListValue`.
+impl From<Vec<serde_json::Value>> for ListValue {
+    fn from(json: Vec<serde_json::Value>) -> Self {
+        ListValue {
+            values: json.into_iter().map(Value::from).collect(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Convert `ListValue` to JSON array.
+impl From<ListValue> for Vec<serde_json::Value> {
+    fn from(list: ListValue) -> Self {
+        list.values
+            .into_iter()
+            .map(serde_json::Value::from)
+            .collect()
+    }
+}
+
+/// Convert JSON object to `Struct`.
+impl From<serde_json::Map<String, serde_json::Value>> for Struct {
+    fn from(json: serde_json::Map<String, serde_json::Value>) -> Self {
+        Struct {
+            fields: json.into_iter().map(|(k, v)| (k, v.into())).collect(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Convert `Struct` to JSON object.
+impl From<Struct> for serde_json::Map<String, serde_json::Value> {
+    fn from(s: Struct) -> Self {
+        s.fields.into_iter().map(|(k, v)| (k, v.into())).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use crate::well_known_types::struct_::value::Kind;
+    use crate::well_known_types::struct_::Struct;
+    use crate::well_known_types::struct_::Value;
+
+    #[test]
+    fn test_round_trip_nested() {
+        let json = json!({
+            "null": null,
+            "bool": true,
+            "int": -17,
+            "float": 1.5,
+            "string": "foo",
+            "list": [1, "two", [3.5], {}],
+            "object": {
+                "nested": {"a": [false, null]},
+            },
+        });
+        let s = Struct::from(json.as_object().unwrap().clone());
+        assert_eq!(7, s.fields.len());
+        assert_eq!(
+            Some(&Kind::NumberValue(-17.0)),
+            s.fields["int"].kind.as_ref()
+        );
+        assert_eq!(json, serde_json::Value::Object(s.into()));
+    }
+
+    #[test]
+    fn test_numbers() {
+        let value = |n: f64| {
+            let mut value = Value::new();
+            value.set_number_value(n);
+            serde_json::Value::from(value)
+        };
+        assert_eq!(json!(3), value(3.0));
+        assert_eq!(json!(-0.25), value(-0.25));
+        assert_eq!(json!(9007199254740992u64), value(9007199254740992.0));
+        assert_eq!(json!(1e300), value(1e300));
+        assert_eq!(json!(null), value(f64::NAN));
+        assert_eq!(json!(null), value(f64::INFINITY));
+
+        assert_eq!(
+            Some(Kind::NumberValue(u64::MAX as f64)),
+            Value::from(json!(u64::MAX)).kind
+        );
+    }
+
+    #[test]
+    fn test_value_without_kind_is_null() {
+        assert_eq!(json!(null), serde_json::Value::from(Value::new()));
+    }
+}
@@ -1,6 +1,8 @@
 use std::time::Duration;
 use std::time::SystemTime;
 
+#[cfg(feature = "with-chrono")]
+use crate::error::ProtobufError;
 use crate::well_known_types::timestamp::Timestamp;
 use crate::SpecialFields;
 
@@ -16,6 +18,26 @@ impl Timestamp {
     pub fn now() -> Timestamp {
         Timestamp::from(SystemTime::now())
     }
+
+    /// Convert to [`SystemTime`].
+    ///
+    /// Return `None` if `nanos` is outside of `[0, 1e9)` range,
+    /// or if the value is outside of `SystemTime` range.
+    ///
+    /// The conversion could be lossy if `SystemTime` precision is smaller than nanoseconds.
+    pub fn to_system_time(&self) -> Option<SystemTime> {
+        if !(0..1_000_000_000).contains(&self.nanos) {
+            return None;
+        }
+        if self.seconds >= 0 {
+            let duration = Duration::new(self.seconds as u64, self.nanos as u32);
+            SystemTime::UNIX_EPOCH.checked_add(duration)
+        } else {
+            let duration = Duration::from_secs(self.seconds.unsigned_abs())
+                - Duration::from_nanos(self.nanos as u64);
+            SystemTime::UNIX_EPOCH.checked_sub(duration)
+        }
+    }
 }
 
 /// Convert from [`Timestamp`].
@@ -53,20 +75,54 @@ impl From<SystemTime> for Timestamp {
 /// This function panics:
 /// * if given `Timestamp` is outside of `SystemTime` range
 /// * if `Timestamp` is malformed
+///
+/// See [`Timestamp::to_system_time`] for a non-panicking version.
 impl Into<SystemTime> for Timestamp {
     fn into(self) -> SystemTime {
-        if self.seconds >= 0 {
-            let duration =
-                Duration::from_secs(self.seconds as u64) + Duration::from_nanos(self.nanos as u64);
-            SystemTime::UNIX_EPOCH + duration
-        } else {
-            let duration =
-                Duration::from_secs(-self.seconds as u64) - Duration::from_nanos(self.nanos as u64);
-            SystemTime::UNIX_EPOCH - duration
+        match self.to_system_time() {
+            Some(time) => time,
+            None => panic!(
+                "timestamp is malformed or outside of SystemTime range: {}s {}ns",
+                self.seconds, self.nanos
+            ),
+        }
+    }
+}
+
+/// Convert from [`chrono::DateTime`].
+///
+/// Leap second is converted to the last nanosecond of the previous second,
+/// because `Timestamp` does not represent leap seconds.
+#[cfg(feature = "with-chrono")]
+impl From<chrono::DateTime<chrono::Utc>> for Timestamp {
+    fn from(time: chrono::DateTime<chrono::Utc>) -> Self {
+        Timestamp {
+            seconds: time.timestamp(),
+            nanos: time.timestamp_subsec_nanos().min(999_999_999) as i32,
+            ..Default::default()
         }
     }
 }
 
+/// Convert into [`chrono::DateTime`].
+///
+/// Return error if `nanos` is outside of `[0, 1e9)` range,
+/// or if the value is outside of `chrono::DateTime` range.
+#[cfg(feature = "with-chrono")]
+impl TryFrom<Timestamp> for chrono::DateTime<chrono::Utc> {
+    type Error = crate::Error;
+
+    fn try_from(timestamp: Timestamp) -> crate::Result<Self> {
+        let out_of_range =
+            || ProtobufError::TimestampOutOfRange(timestamp.seconds, timestamp.nanos);
+        let nanos = u32::try_from(timestamp.nanos).map_err(|_| out_of_range())?;
+        if nanos >= 1_000_000_000 {
+            return Err(out_of_range().into());
+        }
+        Ok(chrono::DateTime::from_timestamp(timestamp.seconds, nanos).ok_or_else(out_of_range)?)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::time::Duration;
@@ -115,4 +171,85 @@ mod test {
             SystemTime::UNIX_EPOCH - Duration::from_millis(3_200),
         );
     }
+
+    #[test]
+    fn system_time_round_trip() {
+        for time in [
+            SystemTime::UNIX_EPOCH + Duration::new(1_600_000_000, 123_456_789),
+            SystemTime::UNIX_EPOCH - Duration::new(1_600_000_000, 123_456_789),
+            SystemTime::UNIX_EPOCH - Duration::from_nanos(1),
+        ] {
+            let timestamp = Timestamp::from(time);
+            assert!((0..1_000_000_000).contains(&timestamp.nanos));
+            assert_eq!(Some(time), timestamp.to_system_time());
+        }
+
+        let pre_epoch = Timestamp::from(SystemTime::UNIX_EPOCH - Duration::from_nanos(1));
+        assert_eq!(-1, pre_epoch.seconds);
+        assert_eq!(999_999_999, pre_epoch.nanos);
+    }
+
+    #[test]
+    fn to_system_time_malformed() {
+        for nanos in [-1, 1_000_000_000] {
+            let timestamp = Timestamp {
+                seconds: 1,
+                nanos,
+                ..Default::default()
+            };
+            assert_eq!(None, timestamp.to_system_time());
+        }
+    }
+
+    #[cfg(feature = "with-chrono")]
+    #[test]
+    fn chrono_round_trip() {
+        use chrono::DateTime;
+        use chrono::Utc;
+
+        for (seconds, nanos) in [
+            (0, 0),
+            (1_600_000_000, 123_456_789),
+            // Pre-epoch: nanos are still counted forward.
+            (-1, 999_999_999),
+            (-1_600_000_000, 123_456_789),
+        ] {
+            let timestamp = Timestamp {
+                seconds,
+                nanos,
+                ..Default::default()
+            };
+            let time = DateTime::<Utc>::try_from(timestamp.clone()).unwrap();
+            assert_eq!(seconds, time.timestamp());
+            assert_eq!(nanos as u32, time.timestamp_subsec_nanos());
+            assert_eq!(timestamp, Timestamp::from(time));
+        }
+
+        let pre_epoch = DateTime::<Utc>::try_from(Timestamp {
+            seconds: -1,
+            nanos: 999_999_999,
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(
+            DateTime::UNIX_EPOCH - chrono::Duration::nanoseconds(1),
+            pre_epoch
+        );
+    }
+
+    #[cfg(feature = "with-chrono")]
+    #[test]
+    fn chrono_malformed() {
+        use chrono::DateTime;
+        use chrono::Utc;
+
+        for (seconds, nanos) in [(1, -1), (1, 1_000_000_000), (i64::MAX, 0)] {
+            let timestamp = Timestamp {
+                seconds,
+                nanos,
+                ..Default::default()
+            };
+            assert!(DateTime::<Utc>::try_from(timestamp).is_err());
+        }
+    }
 }
@@ -0,0 +1,371 @@
+use std::collections::BTreeMap;
+use std::str;
+
+use crate::error::ProtobufError;
+use crate::error::WireError;
+use crate::wire_format::WireType;
+use crate::CodedInputStream;
+
+/// How two serialized fields differ.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldDiffKind {
+    /// Field is present only in the second message.
+    Added,
+    /// Field is present only in the first message.
+    Removed,
+    /// Field is present in both messages, but values are different.
+    Changed,
+}
+
+/// Difference found by [`diff`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDiff {
+    /// Field numbers from the outermost message to the differing field.
+    pub path: Vec<u32>,
+    /// How the field differs.
+    pub kind: FieldDiffKind,
+}
+
+/// Options for [`diff_with_options`].
+#[derive(Debug, Clone)]
+pub struct DiffOptions {
+    /// Compare length-delimited fields which look like messages field by field.
+    ///
+    /// Otherwise length-delimited fields are compared as bytes.
+    pub submessages: bool,
+    /// Do not treat length-delimited values which are printable UTF-8 strings
+    /// as messages, even if they can be parsed as messages.
+    pub strings_are_not_messages: bool,
+    /// Maximum depth of nested messages to compare field by field.
+    pub max_depth: u32,
+    /// Prevent initializing `DiffOptions` enumerating all field.
+    pub _future_options: (),
+}
+
+impl Default for DiffOptions {
+    fn default() -> Self {
+        DiffOptions {
+            submessages: true,
+            strings_are_not_messages: true,
+            max_depth: 100,
+            _future_options: (),
+        }
+    }
+}
+
+/// Serialized field value without the tag.
+#[derive(PartialEq)]
+enum Value<'a> {
+    /// Varint, fixed or group.
+    Raw(&'a [u8]),
+    /// Payload of length-delimited field.
+    LengthDelimited(&'a [u8]),
+}
+
+/// Values of fields by field number, in order of appearance.
+fn parse_fields(bytes: &[u8]) -> crate::Result<BTreeMap<u32, Vec<Value<'_>>>> {
+    let mut fields: BTreeMap<u32, Vec<Value<'_>>> = BTreeMap::new();
+    let mut is = CodedInputStream::from_bytes(bytes);
+    while let Some(tag) = is.read_raw_tag_or_eof()? {
+        let (number, wire_type) = crate::wire_format::Tag::new(tag)?.unpack();
+        let start = is.pos() as usize;
+        let value = match wire_type {
+            WireType::LengthDelimited => {
+                let len = is.read_raw_varint64()?;
+                let start = is.pos() as usize;
+                if len > (bytes.len() - start) as u64 {
+                    return Err(ProtobufError::WireError(WireError::UnexpectedEof).into());
+                }
+                let len = len as usize;
+                is.skip_raw_bytes(len as u32)?;
+                Value::LengthDelimited(&bytes[start..start + len])
+            }
+            wire_type => {
+                is.skip_field(wire_type)?;
+                Value::Raw(&bytes[start..is.pos() as usize])
+            }
+        };
+        fields.entry(number).or_default().push(value);
+    }
+    Ok(fields)
+}
+
+fn is_printable_string(bytes: &[u8]) -> bool {
+    match str::from_utf8(bytes) {
+        Ok(s) => s.chars().all(|c| !c.is_control() || c == '\n' || c == '\t'),
+        Err(_) => false,
+    }
+}
+
+struct Differ<'o> {
+    options: &'o DiffOptions,
+    path: Vec<u32>,
+    diffs: Vec<FieldDiff>,
+}
+
+impl<'o> Differ<'o> {
+    fn push(&mut self, kind: FieldDiffKind) {
+        let diff = FieldDiff {
+            path: self.path.clone(),
+            kind,
+        };
+        if self.diffs.last() != Some(&diff) {
+            self.diffs.push(diff);
+        }
+    }
+
+    fn submessage<'a>(&self, value: &Value<'a>) -> Option<BTreeMap<u32, Vec<Value<'a>>>> {
+        match *value {
+            Value::LengthDelimited(bytes)
+                if self.options.submessages
+                    && self.path.len() < self.options.max_depth as usize
+                    && !bytes.is_empty()
+                    && !(self.options.strings_are_not_messages && is_printable_string(bytes)) =>
+            {
+                parse_fields(bytes).ok()
+            }
+            _ => None,
+        }
+    }
+
+    fn diff_messages(&mut self, a: &BTreeMap<u32, Vec<Value>>, b: &BTreeMap<u32, Vec<Value>>) {
+        let mut numbers: Vec<u32> = a.keys().chain(b.keys()).copied().collect();
+        numbers.sort();
+        numbers.dedup();
+        for number in numbers {
+            self.path.push(number);
+            match (a.get(&number), b.get(&number)) {
+                (Some(a), Some(b)) => self.diff_field(a, b),
+                (Some(_), None) => self.push(FieldDiffKind::Removed),
+                (None, Some(_)) => self.push(FieldDiffKind::Added),
+                (None, None) => unreachable!(),
+            }
+            self.path.pop();
+        }
+    }
+
+    fn diff_field(&mut self, a: &[Value], b: &[Value]) {
+        if a == b {
+            return;
+        }
+        if a.len() != b.len() {
+            self.push(FieldDiffKind::Changed);
+            return;
+        }
+        for (a, b) in a.iter().zip(b) {
+            if a == b {
+                continue;
+            }
+            match (self.submessage(a), self.submessage(b)) {
+                (Some(a), Some(b)) => {
+                    let len = self.diffs.len();
+                    self.diff_messages(&a, &b);
+                    if self.diffs.len() == len {
+                        // Same fields encoded differently (e. g. in different order).
+                        self.push(FieldDiffKind::Changed);
+                    }
+                }
+                _ => self.push(FieldDiffKind::Changed),
+            }
+        }
+    }
+}
+
+/// Compare two serialized messages field by field without a schema.
+///
+/// Length-delimited fields which look like messages are compared recursively.
+/// Repeated fields are compared element by element if they have the same
+/// number of elements, and reported as changed otherwise.
+///
+/// Differences are returned ordered by field number.
+///
+/// # Examples
+///
+/// ```
+/// use protobuf::wire::FieldDiff;
+/// use protobuf::wire::FieldDiffKind;
+///
+/// // Field 1 = 10, field 2 = 20 vs field 1 = 11.
+/// let diffs = protobuf::wire::diff(&[0x08, 10, 0x10, 20], &[0x08, 11]).unwrap();
+/// assert_eq!(
+///     vec![
+///         FieldDiff { path: vec![1], kind: FieldDiffKind::Changed },
+///         FieldDiff { path: vec![2], kind: FieldDiffKind::Removed },
+///     ],
+///     diffs
+/// );
+/// ```
+pub fn diff(a: &[u8], b: &[u8]) -> crate::Result<Vec<FieldDiff>> {
+    diff_with_options(a, b, &DiffOptions::default())
+}
+
+/// Compare two serialized messages field by field with given options.
+///
+/// See [`diff`] for details.
+pub fn diff_with_options(
+    a: &[u8],
+    b: &[u8],
+    options: &DiffOptions,
+) -> crate::Result<Vec<FieldDiff>> {
+    let a = parse_fields(a)?;
+    let b = parse_fields(b)?;
+    let mut differ = Differ {
+        options,
+        path: Vec::new(),
+        diffs: Vec::new(),
+    };
+    differ.diff_messages(&a, &b);
+    Ok(differ.diffs)
+}
+
+#[cfg(test)]
+mod test {
+    use super::diff;
+    use super::diff_with_options;
+    use super::DiffOptions;
+    use super::FieldDiff;
+    use super::FieldDiffKind;
+    use crate::hex::decode_hex;
+
+    fn fd(path: &[u32], kind: FieldDiffKind) -> FieldDiff {
+        FieldDiff {
+            path: path.to_vec(),
+            kind,
+        }
+    }
+
+    #[test]
+    fn test_equal() {
+        let a = decode_hex("08 0a 12 03 61 62 63");
+        assert_eq!(Vec::<FieldDiff>::new(), diff(&a, &a).unwrap());
+        assert_eq!(Vec::<FieldDiff>::new(), diff(&[], &[]).unwrap());
+    }
+
+    #[test]
+    fn test_one_field_changed() {
+        // 1: 10, 2: "abc", 3: 1.0f
+        let a = decode_hex("08 0a 12 03 61 62 63 1d 00 00 80 3f");
+        // 1: 10, 2: "abd", 3: 1.0f
+        let b = decode_hex("08 0a 12 03 61 62 64 1d 00 00 80 3f");
+        assert_eq!(
+            vec![fd(&[2], FieldDiffKind::Changed)],
+            diff(&a, &b).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_added_removed() {
+        // 1: 10, 2: 20
+        let a = decode_hex("08 0a 10 14");
+        // 2: 20, 3: 30
+        let b = decode_hex("10 14 18 1e");
+        assert_eq!(
+            vec![
+                fd(&[1], FieldDiffKind::Removed),
+                fd(&[3], FieldDiffKind::Added),
+            ],
+            diff(&a, &b).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_nested() {
+        // 4: { 1: 10, 2: 20 }
+        let a = decode_hex("22 04 08 0a 10 14");
+        // 4: { 1: 10, 2: 21 }
+        let b = decode_hex("22 04 08 0a 10 15");
+        assert_eq!(
+            vec![fd(&[4, 2], FieldDiffKind::Changed)],
+            diff(&a, &b).unwrap()
+        );
+
+        let options = DiffOptions {
+            submessages: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            vec![fd(&[4], FieldDiffKind::Changed)],
+            diff_with_options(&a, &b, &options).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_nested_reordered() {
+        // 4: { 1: 10, 2: 20 }
+        let a = decode_hex("22 04 08 0a 10 14");
+        // 4: { 2: 20, 1: 10 }
+        let b = decode_hex("22 04 10 14 08 0a");
+        assert_eq!(
+            vec![fd(&[4], FieldDiffKind::Changed)],
+            diff(&a, &b).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_repeated() {
+        // 1: 10, 1: 20
+        let a = decode_hex("08 0a 08 14");
+        // 1: 10, 1: 21
+        let b = decode_hex("08 0a 08 15");
+        // 1: 10
+        let c = decode_hex("08 0a");
+        assert_eq!(
+            vec![fd(&[1], FieldDiffKind::Changed)],
+            diff(&a, &b).unwrap()
+        );
+        assert_eq!(
+            vec![fd(&[1], FieldDiffKind::Changed)],
+            diff(&a, &c).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_string_not_message() {
+        // 2: { 1: 10 } vs 2: { 1: 11 }, not printable strings.
+        let a = decode_hex("12 02 08 0a");
+        let b = decode_hex("12 02 08 0b");
+        assert_eq!(
+            vec![fd(&[2, 1], FieldDiffKind::Changed)],
+            diff(&a, &b).unwrap()
+        );
+
+        // 2: "(a" vs 2: "(b", parseable as `{ 5: 97 }` and `{ 5: 98 }`.
+        let a = decode_hex("12 02 28 61");
+        let b = decode_hex("12 02 28 62");
+        assert_eq!(
+            vec![fd(&[2], FieldDiffKind::Changed)],
+            diff(&a, &b).unwrap()
+        );
+        let options = DiffOptions {
+            strings_are_not_messages: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            vec![fd(&[2, 5], FieldDiffKind::Changed)],
+            diff_with_options(&a, &b, &options).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_malformed() {
+        assert!(diff(&decode_hex("08"), &[]).is_err());
+    }
+
+    #[test]
+    fn test_length_overflow() {
+        // Length 2^32 + 1 is truncated to 1 by `as u32`.
+        let bytes = decode_hex("0a 81 80 80 80 10 00");
+        assert!(diff(&bytes, &[]).is_err());
+        // Same bytes in a bytes field are not parsed as a message.
+        let mut outer = decode_hex("0a 07");
+        outer.extend_from_slice(&bytes);
+        assert_eq!(
+            vec![fd(&[1], FieldDiffKind::Removed)],
+            diff(&outer, &[]).unwrap()
+        );
+        assert_eq!(
+            vec![fd(&[1], FieldDiffKind::Changed)],
+            diff(&outer, &decode_hex("0a 00")).unwrap()
+        );
+    }
+}
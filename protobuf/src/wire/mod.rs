@@ -0,0 +1,9 @@
+//! Utilities working with serialized messages without a schema.
+
+mod diff;
+
+pub use self::diff::diff;
+pub use self::diff::diff_with_options;
+pub use self::diff::DiffOptions;
+pub use self::diff::FieldDiff;
+pub use self::diff::FieldDiffKind;
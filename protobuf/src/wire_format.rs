@@ -100,7 +100,7 @@ impl Tag {
     pub(crate) fn new(value: u32) -> crate::Result<Tag> {
         let wire_type = WireType::new(value & TAG_TYPE_MASK);
         if wire_type.is_none() {
-            return Err(WireError::IncorrectTag(value).into());
+            return Err(WireError::IncorrectWireType(value & TAG_TYPE_MASK).into());
         }
         let field_number = value >> TAG_TYPE_BITS;
         if field_number == 0 {
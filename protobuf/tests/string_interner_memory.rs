@@ -0,0 +1,106 @@
+//! Check that interned strings are neither allocated nor retained per read.
+//!
+//! This is a separate test binary, because it installs a global allocator.
+
+#![cfg(feature = "with-bytes")]
+
+use std::alloc::GlobalAlloc;
+use std::alloc::Layout;
+use std::alloc::System;
+use std::sync::atomic::AtomicIsize;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use protobuf::Chars;
+use protobuf::CodedInputStream;
+use protobuf::CodedOutputStream;
+use protobuf::HashSetStringInterner;
+
+struct CountingAllocator;
+
+static ALLOCATED: AtomicIsize = AtomicIsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATED.fetch_add(layout.size() as isize, Ordering::SeqCst);
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        ALLOCATED.fetch_sub(layout.size() as isize, Ordering::SeqCst);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const COUNT: usize = 1000;
+const DISTINCT: usize = 10;
+const LEN: usize = 100;
+
+fn make_repeated_strings() -> Vec<u8> {
+    let mut v = Vec::new();
+    {
+        let mut os = CodedOutputStream::vec(&mut v);
+        for i in 0..COUNT {
+            let s = format!("{:0>width$}", i % DISTINCT, width = LEN);
+            os.write_string_no_tag(&s).unwrap();
+        }
+        os.flush().unwrap();
+    }
+    v
+}
+
+/// Read all strings from `Read` (not from bytes, so data is copied),
+/// and return number of allocations and number of bytes retained by the result.
+fn read_strings(input: &[u8], interner: Option<&mut HashSetStringInterner>) -> (usize, isize) {
+    let mut read = input;
+    let mut r: Vec<Chars> = Vec::with_capacity(COUNT);
+    let allocations_before = ALLOCATIONS.load(Ordering::SeqCst);
+    let before = ALLOCATED.load(Ordering::SeqCst);
+    {
+        let mut is = CodedInputStream::new(&mut read);
+        if let Some(interner) = interner {
+            is.set_string_interner(interner);
+        }
+        while !is.eof().unwrap() {
+            r.push(is.read_tokio_chars().unwrap());
+        }
+    }
+    let after = ALLOCATED.load(Ordering::SeqCst);
+    let allocations = ALLOCATIONS.load(Ordering::SeqCst) - allocations_before;
+    assert_eq!(COUNT, r.len());
+    drop(r);
+    (allocations, after - before)
+}
+
+// Single test, so other tests do not allocate concurrently.
+#[test]
+fn interned_strings_allocate_less() {
+    let input = make_repeated_strings();
+
+    let (plain_allocations, plain) = read_strings(&input, None);
+    assert!(plain_allocations >= COUNT, "{}", plain_allocations);
+    assert!(plain >= (COUNT * LEN) as isize, "{}", plain);
+
+    let mut interner = HashSetStringInterner::new();
+    let (interned_allocations, interned) = read_strings(&input, Some(&mut interner));
+    assert_eq!(DISTINCT, interner.len());
+    // Strings are read into a reused buffer, not allocated one by one.
+    assert!(
+        interned_allocations < COUNT / 4,
+        "interned: {}, plain: {}",
+        interned_allocations,
+        plain_allocations
+    );
+    // Interner itself holds DISTINCT strings, and stream buffers are freed.
+    assert!(
+        interned < plain / 4,
+        "interned: {}, plain: {}",
+        interned,
+        plain
+    );
+}
@@ -0,0 +1,37 @@
+// `cargo test --benches` and `#[feature(test)]` work only in nightly
+#![cfg(rustc_nightly)]
+#![feature(test)]
+
+extern crate test;
+
+use protobuf_perftest_misc::accessors::Inline;
+use protobuf_perftest_misc::accessors::Regular;
+
+const COUNT: usize = 1000;
+
+// Same code for both messages, to compare generated accessors
+// with and without `inline_accessors` option.
+macro_rules! bench_accessors {
+    ($name:ident, $message:ty) => {
+        #[bench]
+        fn $name(b: &mut test::Bencher) {
+            let mut messages: Vec<$message> = (0..COUNT).map(|_| <$message>::new()).collect();
+            b.iter(|| {
+                let mut sum = 0;
+                for (i, m) in messages.iter_mut().enumerate() {
+                    m.set_a(i as i32);
+                    m.set_b(m.a() as i64 * 2);
+                    if m.has_s() {
+                        sum += m.s().len() as i64;
+                    }
+                    sum += m.b();
+                    m.clear_s();
+                }
+                test::black_box(sum)
+            })
+        }
+    };
+}
+
+bench_accessors!(accessors_regular, Regular);
+bench_accessors!(accessors_inline, Inline);
@@ -0,0 +1,32 @@
+// `cargo test --benches` and `#[feature(test)]` work only in nightly
+#![cfg(rustc_nightly)]
+#![feature(test)]
+
+extern crate test;
+
+use protobuf_perftest_misc::json::Generated;
+use protobuf_perftest_misc::json::Reflective;
+
+const JSON: &str = "{\"id\": 17, \"timestamp\": \"1600000000000\", \"score\": 0.75, \
+    \"active\": true, \"name\": \"some name\", \"payload\": \"AQIDBAUGBwg=\", \
+    \"values\": [1, 2, 3, 4, 5, 6, 7, 8], \"tags\": [\"a\", \"bb\", \"ccc\"]}";
+
+// Same JSON and same fields for both messages, to compare
+// reflective parsing with code generated with `gen_json_methods` option.
+#[bench]
+fn json_parse_reflective(b: &mut test::Bencher) {
+    b.iter(|| {
+        let mut m = Reflective::new();
+        protobuf_json_mapping::merge_from_str(&mut m, JSON).unwrap();
+        test::black_box(m)
+    })
+}
+
+#[bench]
+fn json_parse_generated(b: &mut test::Bencher) {
+    b.iter(|| {
+        let mut m = Generated::new();
+        m.merge_from_json_str(JSON).unwrap();
+        test::black_box(m)
+    })
+}
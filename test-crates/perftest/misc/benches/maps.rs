@@ -0,0 +1,41 @@
+// `cargo test --benches` and `#[feature(test)]` work only in nightly
+#![cfg(rustc_nightly)]
+#![feature(test)]
+
+extern crate test;
+
+use protobuf::Message;
+use protobuf_perftest_misc::maps::WithBTreeMap;
+use protobuf_perftest_misc::maps::WithHashMap;
+
+const ENTRIES: i64 = 1000;
+
+fn map_bytes() -> Vec<u8> {
+    let mut m = WithHashMap::new();
+    for i in 0..ENTRIES {
+        m.m.insert(format!("key{}", i), i);
+    }
+    m.write_to_bytes().unwrap()
+}
+
+// Entries are needed in key order, e. g. to serialize them deterministically.
+#[bench]
+fn map_parse_hash_map_and_sort(b: &mut test::Bencher) {
+    let bytes = map_bytes();
+    b.iter(|| {
+        let m = WithHashMap::parse_from_bytes(&bytes).unwrap();
+        let mut entries: Vec<(&String, &i64)> = m.m.iter().collect();
+        entries.sort();
+        test::black_box(entries.len())
+    })
+}
+
+#[bench]
+fn map_parse_btree_map(b: &mut test::Bencher) {
+    let bytes = map_bytes();
+    b.iter(|| {
+        let m = WithBTreeMap::parse_from_bytes(&bytes).unwrap();
+        let entries: Vec<(&String, &i64)> = m.m.iter().collect();
+        test::black_box(entries.len())
+    })
+}
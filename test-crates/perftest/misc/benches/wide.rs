@@ -0,0 +1,35 @@
+// `cargo test --benches` and `#[feature(test)]` work only in nightly
+#![cfg(rustc_nightly)]
+#![feature(test)]
+
+extern crate test;
+
+use protobuf::CodedOutputStream;
+use protobuf::Message;
+use protobuf_perftest_misc::wide::Wide;
+
+const FIELDS: u32 = 64;
+
+fn wide_bytes(field_numbers: impl Iterator<Item = u32>) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    let mut os = CodedOutputStream::vec(&mut bytes);
+    for n in field_numbers {
+        os.write_uint32(n, n + 1000).unwrap();
+    }
+    os.flush().unwrap();
+    drop(os);
+    bytes
+}
+
+// Generated `merge_from` matches on tag, so field order should not matter.
+#[bench]
+fn wide_parse_in_order(b: &mut test::Bencher) {
+    let bytes = wide_bytes(1..=FIELDS);
+    b.iter(|| test::black_box(Wide::parse_from_bytes(&bytes).unwrap()))
+}
+
+#[bench]
+fn wide_parse_reverse_order(b: &mut test::Bencher) {
+    let bytes = wide_bytes((1..=FIELDS).rev());
+    b.iter(|| test::black_box(Wide::parse_from_bytes(&bytes).unwrap()))
+}
@@ -1 +1,4 @@
-
+pub mod accessors;
+pub mod json;
+pub mod maps;
+pub mod wide;
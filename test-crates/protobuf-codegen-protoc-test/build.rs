@@ -3,7 +3,17 @@ use std::fs;
 use std::path::Path;
 use std::path::PathBuf;
 
+use protobuf::descriptor::field_descriptor_proto::Type;
+use protobuf::descriptor::FieldOptions;
+use protobuf::ext::ExtFieldOptional;
+use protobuf::reflect::FieldDescriptor;
+use protobuf::reflect::MessageDescriptor;
+use protobuf::reflect::OneofDescriptor;
 use protobuf_codegen::Codegen;
+use protobuf_codegen::ConvertCodegen;
+use protobuf_codegen::Customize;
+use protobuf_codegen::CustomizeCallback;
+use protobuf_parse::Parser;
 use protobuf_test_common::build::*;
 
 fn test_protoc_bin_path() -> PathBuf {
@@ -74,11 +84,179 @@ fn generate_include_generated() {
         .run_from_script();
 }
 
+fn generate_convert() {
+    let dir = format!("{}/convert", env::var("OUT_DIR").unwrap());
+    if Path::new(&dir).exists() {
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    fs::create_dir(&dir).unwrap();
+    codegen()
+        .out_dir(&dir)
+        .input("src/convert/v1.proto")
+        .input("src/convert/v2.proto")
+        .include("src/convert")
+        .run_from_script();
+
+    let file_descriptor_set = |input: &str| {
+        Parser::new()
+            .protoc()
+            .protoc_path(&test_protoc_bin_path())
+            .input(input)
+            .include("src/convert")
+            .file_descriptor_set()
+            .unwrap()
+    };
+    ConvertCodegen::new()
+        .source(file_descriptor_set("src/convert/v1.proto"), "self")
+        .target(file_descriptor_set("src/convert/v2.proto"), "self")
+        .rename_field("convert.v1.Person.name", "full_name")
+        .out_file(format!("{}/convert.rs", dir))
+        .run_from_script();
+}
+
+fn generate_custom_option() {
+    /// `rust_type` option from `custom_option.proto`.
+    const RUST_TYPE: ExtFieldOptional<FieldOptions, String> =
+        ExtFieldOptional::new(50001, Type::TYPE_STRING);
+
+    struct RustTypeFromOption;
+
+    impl CustomizeCallback for RustTypeFromOption {
+        fn field(&self, field: &FieldDescriptor) -> Customize {
+            match RUST_TYPE.get(field.proto().options.get_or_default()) {
+                Some(t) if t == "bytes::Bytes" => Customize::default().tokio_bytes(true),
+                _ => Customize::default(),
+            }
+        }
+    }
+
+    let dir = format!("{}/custom_option", env::var("OUT_DIR").unwrap());
+    if Path::new(&dir).exists() {
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    fs::create_dir(&dir).unwrap();
+    codegen()
+        .out_dir(&dir)
+        .input("src/custom_option/custom_option.proto")
+        .includes(&["../../proto", "src/custom_option"])
+        .customize_callback(RustTypeFromOption)
+        .run_from_script();
+}
+
+struct GenSerde;
+
+impl CustomizeCallback for GenSerde {
+    fn message(&self, _message: &MessageDescriptor) -> Customize {
+        Customize::default().before("#[derive(::serde::Serialize, ::serde::Deserialize)]")
+    }
+
+    fn oneof(&self, _oneof: &OneofDescriptor) -> Customize {
+        Customize::default().before("#[derive(::serde::Serialize, ::serde::Deserialize)]")
+    }
+
+    fn special_field(&self, _message: &MessageDescriptor, _field: &str) -> Customize {
+        Customize::default().before("#[serde(skip)]")
+    }
+}
+
+fn generate_serde(name: &str, customize: Customize) {
+    let dir = format!("{}/{}", env::var("OUT_DIR").unwrap(), name);
+    if Path::new(&dir).exists() {
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    fs::create_dir(&dir).unwrap();
+    codegen()
+        .out_dir(&dir)
+        .input(format!("src/{}/{}.proto", name, name))
+        .include(format!("src/{}", name))
+        .customize(customize)
+        .customize_callback(GenSerde)
+        .run_from_script();
+}
+
+fn generate_serde_omit_defaults() {
+    generate_serde(
+        "serde_omit_defaults",
+        Customize::default().serde_omit_defaults(true),
+    );
+}
+
+fn generate_serde_flatten_oneofs() {
+    generate_serde(
+        "serde_flatten_oneofs",
+        Customize::default().serde_flatten_oneofs(true),
+    );
+}
+
+fn generate_module_path() {
+    let dir = format!("{}/module_path", env::var("OUT_DIR").unwrap());
+    if Path::new(&dir).exists() {
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    fs::create_dir(&dir).unwrap();
+    codegen()
+        .out_dir(&dir)
+        .input("src/module_path/pkg_a.proto")
+        .input("src/module_path/pkg_b.proto")
+        .input("src/module_path/module_path_c.proto")
+        .include("src/module_path")
+        .customize(
+            Customize::default()
+                .module_path_for_package("module_path.a", "crate::module_path_gen::a")
+                .module_path_for_package("module_path.b", "crate::module_path_gen::b"),
+        )
+        .run_from_script();
+}
+
+fn generate_single_module() {
+    let dir = format!("{}/single_module", env::var("OUT_DIR").unwrap());
+    if Path::new(&dir).exists() {
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    fs::create_dir(&dir).unwrap();
+    codegen()
+        .out_dir(&dir)
+        .input("src/single_module/first.proto")
+        .input("src/single_module/second.proto")
+        .include("src/single_module")
+        .customize(Customize::default().single_module(true))
+        .run_from_script();
+}
+
+fn generate_module_cfg() {
+    // Never set: not a feature, so `--all-features` does not enable it.
+    println!("cargo:rustc-check-cfg=cfg(module_cfg_disabled)");
+    let dir = format!("{}/module_cfg", env::var("OUT_DIR").unwrap());
+    if Path::new(&dir).exists() {
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    fs::create_dir(&dir).unwrap();
+    codegen()
+        .out_dir(&dir)
+        .input("src/module_cfg/enabled.proto")
+        .input("src/module_cfg/disabled.proto")
+        .include("src/module_cfg")
+        .customize(
+            Customize::default()
+                .module_cfg("disabled.proto", "module_cfg_disabled")
+                // Not generated, so `disabled` module does not compile if included.
+                .module_path_for_package("module_cfg.missing", "crate::module_cfg::missing"),
+        )
+        .run_from_script();
+}
+
 fn generate_pb_rs() {
     generate_in_common();
     generate_in_v2_v3();
     generate_interop();
     generate_include_generated();
+    generate_convert();
+    generate_custom_option();
+    generate_module_path();
+    generate_serde_omit_defaults();
+    generate_serde_flatten_oneofs();
+    generate_single_module();
+    generate_module_cfg();
 }
 
 fn main() {
@@ -1,6 +1,10 @@
+use protobuf::reflect::FileDescriptor;
 use protobuf::well_known_types::any::Any;
+use protobuf::well_known_types::duration;
+use protobuf::Message;
 use protobuf::MessageFull;
 
+use super::test_any_pb::file_descriptor;
 use super::test_any_pb::MessageOne;
 use super::test_any_pb::MessageTwo;
 
@@ -34,3 +38,43 @@ fn test_dynamic() {
     );
     assert!(any.unpack_dyn(&MessageTwo::descriptor()).unwrap().is_none());
 }
+
+#[test]
+fn test_unpack_dyn_from_files() {
+    let mut m1 = MessageOne::new();
+    m1.set_i(10);
+    let any = Any::pack(&m1).unwrap();
+
+    let generated = any
+        .unpack_dyn_from_files(&[file_descriptor().clone()])
+        .unwrap();
+    assert_eq!(m1, *generated.downcast_box::<MessageOne>().unwrap());
+
+    let dynamic_file =
+        FileDescriptor::new_dynamic(file_descriptor().proto().clone(), file_descriptor().deps())
+            .unwrap();
+    let dynamic = any.unpack_dyn_from_files(&[dynamic_file]).unwrap();
+    assert_eq!("test_any.MessageOne", dynamic.descriptor_dyn().full_name());
+    assert!(dynamic.downcast_ref::<MessageOne>().is_none());
+    assert_eq!(
+        m1.write_to_bytes().unwrap(),
+        dynamic.write_to_bytes_dyn().unwrap()
+    );
+}
+
+#[test]
+fn test_unpack_dyn_from_files_not_found() {
+    let mut m1 = MessageOne::new();
+    m1.set_i(10);
+    let any = Any::pack(&m1).unwrap();
+
+    let e = any
+        .unpack_dyn_from_files(&[duration::file_descriptor().clone()])
+        .unwrap_err();
+    assert!(
+        e.to_string()
+            .contains("type.googleapis.com/test_any.MessageOne"),
+        "{}",
+        e
+    );
+}
@@ -1,5 +1,6 @@
 use protobuf::descriptor;
 use protobuf::reflect;
+use protobuf::rt::WireType;
 use protobuf::CodedInputStream;
 use protobuf::CodedOutputStream;
 use protobuf::EnumFull;
@@ -228,6 +229,123 @@ fn test_parse_length_delimited_from_network_smoke() {
     is.check_eof().expect("check_eof");
 }
 
+#[test]
+fn test_write_to_vec_appends() {
+    let mut test1 = Test1::new();
+    test1.set_a(150);
+    let mut test2 = Test1::new();
+    test2.set_a(3);
+
+    let mut v = vec![0xaa];
+    test1.write_to_vec(&mut v).unwrap();
+    assert_eq!(decode_hex("aa 08 96 01"), v);
+
+    let mut v = Vec::new();
+    test1.write_length_delimited_to_vec(&mut v).unwrap();
+    test2.write_length_delimited_to_vec(&mut v).unwrap();
+    assert_eq!(decode_hex("03 08 96 01 02 08 03"), v);
+
+    let mut is = CodedInputStream::from_bytes(&v);
+    assert_eq!(test1, is.read_message::<Test1>().unwrap());
+    assert_eq!(test2, is.read_message::<Test1>().unwrap());
+    assert!(is.eof().unwrap());
+}
+
+#[test]
+fn test_parse_from_reader_file() {
+    use std::fs;
+    use std::io::Write;
+
+    let mut test1 = Test1::new();
+    test1.set_a(150);
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("test1.bin");
+    fs::write(&path, test1.write_to_bytes().unwrap()).unwrap();
+    let mut file = fs::File::open(&path).unwrap();
+    assert_eq!(test1, Test1::parse_from_reader(&mut file).unwrap());
+
+    // Truncated field at the end of file is an error.
+    let mut file = fs::OpenOptions::new().append(true).open(&path).unwrap();
+    file.write_all(&[0x08]).unwrap();
+    drop(file);
+    let mut file = fs::File::open(&path).unwrap();
+    assert!(Test1::parse_from_reader(&mut file).is_err());
+}
+
+#[test]
+fn test_parse_from_bytes_partial() {
+    let mut test1 = Test1::new();
+    test1.set_a(150);
+
+    let mut bytes = test1.write_length_delimited_to_bytes().unwrap();
+    bytes.extend_from_slice(&[0xde, 0xad]);
+    assert_eq!(decode_hex("03 08 96 01 de ad"), bytes);
+
+    let (parsed, tail) = Test1::parse_from_bytes_partial(&bytes).unwrap();
+    assert_eq!(test1, parsed);
+    assert_eq!(&[0xde, 0xad], tail);
+
+    let (_, tail) = Test1::parse_from_bytes_partial(&bytes[..4]).unwrap();
+    assert!(tail.is_empty());
+
+    // Length prefix is larger than the buffer.
+    assert!(Test1::parse_from_bytes_partial(&bytes[..3]).is_err());
+}
+
+#[test]
+fn test_parse_trailing_bytes() {
+    let mut test1 = Test1::new();
+    test1.set_a(150);
+
+    let mut bytes = test1.write_length_delimited_to_bytes().unwrap();
+    assert_eq!(
+        test1,
+        Test1::parse_length_delimited_from_bytes(&bytes).unwrap()
+    );
+
+    // Strict: trailing bytes after length-delimited message are an error.
+    bytes.extend_from_slice(&decode_hex("10 01"));
+    let e = Test1::parse_length_delimited_from_bytes(&bytes).unwrap_err();
+    assert_eq!(
+        "2 trailing bytes after length-delimited message",
+        e.to_string()
+    );
+
+    // Whole buffer is the message: bytes after the message are more fields.
+    let mut bytes = test1.write_to_bytes().unwrap();
+    bytes.extend_from_slice(&decode_hex("10 01"));
+    let parsed = Test1::parse_from_bytes(&bytes).unwrap();
+    assert_eq!(150, parsed.a());
+    assert_eq!(1, parsed.special_fields.unknown_fields().iter().count());
+    // Garbage which is not a field is an error.
+    bytes.extend_from_slice(&[0xff]);
+    assert!(Test1::parse_from_bytes(&bytes).is_err());
+}
+
+#[test]
+fn test_write_by_hand() {
+    let mut bytes = Vec::new();
+    {
+        let mut os = CodedOutputStream::vec(&mut bytes);
+        os.write_tag(1, WireType::Varint).unwrap();
+        os.write_raw_varint64(150).unwrap();
+        os.flush().unwrap();
+    }
+    assert_eq!(decode_hex("08 96 01"), bytes);
+    assert_eq!(150, Test1::parse_from_bytes(&bytes).unwrap().a());
+
+    let mut bytes = Vec::new();
+    {
+        let mut os = CodedOutputStream::vec(&mut bytes);
+        os.write_tag(2, WireType::LengthDelimited).unwrap();
+        os.write_raw_varint64(7).unwrap();
+        os.write_raw_bytes(b"testing").unwrap();
+        os.flush().unwrap();
+    }
+    assert_eq!("testing", Test2::parse_from_bytes(&bytes).unwrap().b());
+}
+
 /// Test if providing a smaller buffer, protobuf can detect and report error.
 #[test]
 fn test_serialize_too_large_message() {
@@ -238,3 +356,35 @@ fn test_serialize_too_large_message() {
     let mut s = CodedOutputStream::bytes(&mut bytes);
     test1.write_to_with_cached_sizes(&mut s).unwrap_err();
 }
+
+#[test]
+fn test_repeated_index() {
+    // Repeated fields are `Vec`, so `Index` and `IndexMut` come from it.
+    let mut test4 = Test4::new();
+    test4.d = vec![10, 20, 30];
+    assert_eq!(20, test4.d[1]);
+    test4.d[1] = 21;
+    test4.d[2] += 1;
+    assert_eq!(&[10, 21, 31], &test4.d[..]);
+    test_serialize_deserialize_with_dynamic("22 03 0a 15 1f", &test4);
+}
+
+#[test]
+#[should_panic]
+fn test_repeated_index_out_of_bounds() {
+    let test4 = Test4::new();
+    let _ = test4.d[0];
+}
+
+#[test]
+fn test_repeated_sort_dedup() {
+    // Repeated fields are `Vec`, so they can be normalized in place.
+    let mut test4 = Test4::new();
+    test4.d = vec![30, 10, 20, 10, 30];
+    let capacity = test4.d.capacity();
+    test4.d.sort();
+    test4.d.dedup();
+    assert_eq!(&[10, 20, 30], &test4.d[..]);
+    assert_eq!(capacity, test4.d.capacity());
+    test_serialize_deserialize_with_dynamic("22 03 0a 14 1e", &test4);
+}
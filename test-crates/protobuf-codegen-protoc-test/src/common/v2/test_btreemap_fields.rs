@@ -0,0 +1,67 @@
+use std::collections::BTreeMap;
+
+use protobuf::reflect::ReflectValueBox;
+use protobuf::Message;
+use protobuf::MessageFull;
+use protobuf_test_common::*;
+
+use super::test_btreemap_fields_pb::*;
+
+#[test]
+fn test_btreemap_type() {
+    let m = WithBTreeMaps::new();
+    let _: &BTreeMap<String, i32> = &m.m;
+    let _: &BTreeMap<i32, Entry> = &m.mm;
+}
+
+#[test]
+fn test_serialized_in_key_order() {
+    let mut m = WithBTreeMaps::new();
+    m.m.insert("b".to_owned(), 2);
+    m.m.insert("a".to_owned(), 1);
+    // Dynamic messages store maps in `HashMap`, so only generated output order is checked.
+    test_serialize_deserialize("0a 05 0a 01 61 10 01 0a 05 0a 01 62 10 02", &m);
+    test_serialize_deserialize_no_hex_with_dynamic(&m);
+}
+
+#[test]
+fn test_deterministic_after_parse() {
+    // Entries are in reverse order on the wire.
+    let bytes = hex::decode_hex("12 06 08 02 12 02 08 14 12 06 08 01 12 02 08 0a");
+    let m = WithBTreeMaps::parse_from_bytes(&bytes).unwrap();
+    assert_eq!(vec![1, 2], m.mm.keys().cloned().collect::<Vec<_>>());
+    assert_eq!(
+        hex::decode_hex("12 06 08 01 12 02 08 0a 12 06 08 02 12 02 08 14"),
+        m.write_to_bytes().unwrap()
+    );
+}
+
+#[test]
+fn test_reflect() {
+    let mut m = WithBTreeMaps::new();
+    let field = WithBTreeMaps::descriptor().field_by_name("m").unwrap();
+    field.mut_map(&mut m).insert(
+        ReflectValueBox::String("b".to_owned()),
+        ReflectValueBox::I32(2),
+    );
+    field.mut_map(&mut m).insert(
+        ReflectValueBox::String("a".to_owned()),
+        ReflectValueBox::I32(1),
+    );
+    assert_eq!(Some(&1), m.m.get("a"));
+
+    let map = field.get_map(&m);
+    assert_eq!(
+        Some(ReflectValueBox::I32(2)),
+        map.get(ReflectValueBox::String("b".to_owned()).as_value_ref())
+            .map(|v| v.to_box())
+    );
+    let keys: Vec<_> = (&map).into_iter().map(|(k, _)| k.to_box()).collect();
+    assert_eq!(
+        vec![
+            ReflectValueBox::String("a".to_owned()),
+            ReflectValueBox::String("b".to_owned()),
+        ],
+        keys
+    );
+}
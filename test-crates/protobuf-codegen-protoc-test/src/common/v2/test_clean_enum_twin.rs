@@ -0,0 +1,50 @@
+use std::convert::TryFrom;
+
+use protobuf::EnumOrUnknown;
+use protobuf::Message;
+
+use super::test_clean_enum_twin_pb::*;
+
+#[test]
+fn test_from_i32() {
+    assert_eq!(Ok(Color::GREEN), Color::try_from(1));
+    assert_eq!(Err(17), Color::try_from(17));
+}
+
+#[test]
+fn test_to_i32() {
+    assert_eq!(2, i32::from(Color::BLUE));
+    for c in [Color::RED, Color::GREEN, Color::BLUE] {
+        assert_eq!(Ok(c), Color::try_from(i32::from(c)));
+    }
+}
+
+#[test]
+fn test_from_enum_or_unknown() {
+    assert_eq!(
+        Ok(Color::BLUE),
+        Color::try_from(EnumOrUnknown::new(Color::BLUE))
+    );
+    assert_eq!(
+        Err(17),
+        Color::try_from(EnumOrUnknown::<Color>::from_i32(17))
+    );
+}
+
+#[test]
+fn test_round_trip_through_message() {
+    let mut shape = Shape::new();
+    shape.colors.push(Color::GREEN.into());
+    let shape = Shape::parse_from_bytes(&shape.write_to_bytes().unwrap()).unwrap();
+    let color = match Color::try_from(shape.colors[0]) {
+        Ok(Color::RED) => "red",
+        Ok(Color::GREEN) => "green",
+        Ok(Color::BLUE) => "blue",
+        Err(_) => "unknown",
+    };
+    assert_eq!("green", color);
+
+    // Unknown value is preserved on the wire, and reported at the boundary.
+    let shape = Shape::parse_from_bytes(&[0x08, 0x11]).unwrap();
+    assert_eq!(Err(17), Color::try_from(shape.colors[0]));
+}
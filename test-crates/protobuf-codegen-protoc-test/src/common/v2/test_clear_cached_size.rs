@@ -0,0 +1,59 @@
+use protobuf::Message;
+
+use super::test_clear_cached_size_pb::*;
+
+fn populate(m: &mut Outer, s: &str) {
+    m.inner.mut_or_insert_default().s.push(s.to_owned());
+    let mut inner = Inner::new();
+    inner.s.push(s.to_owned());
+    m.inners.push(inner);
+}
+
+#[test]
+fn test_clear_resets_cached_size() {
+    let mut m = Outer::new();
+    populate(&mut m, "long enough string");
+    let size = m.compute_size();
+    assert_eq!(size as u32, m.cached_size());
+
+    m.clear();
+    assert_eq!(0, m.cached_size());
+}
+
+#[test]
+fn test_reuse_after_clear() {
+    let mut m = Outer::new();
+    populate(&mut m, "long enough string");
+    m.write_to_bytes().unwrap();
+
+    m.clear();
+    populate(&mut m, "x");
+
+    let mut expected = Outer::new();
+    populate(&mut expected, "x");
+
+    let bytes = m.write_to_bytes().unwrap();
+    assert_eq!(expected.write_to_bytes().unwrap(), bytes);
+    assert_eq!(expected, Outer::parse_from_bytes(&bytes).unwrap());
+}
+
+#[test]
+fn test_nested_clear_resets_cached_size() {
+    let mut m = Outer::new();
+    populate(&mut m, "long enough string");
+    m.compute_size();
+
+    // Nested message taken from the parent, for example, to put it to a pool.
+    let mut inner = m.inners.pop().unwrap();
+    assert_ne!(0, inner.cached_size());
+    inner.clear();
+    assert_eq!(0, inner.cached_size());
+
+    inner.s.push("x".to_owned());
+    let mut expected = Inner::new();
+    expected.s.push("x".to_owned());
+    assert_eq!(
+        expected.write_to_bytes().unwrap(),
+        inner.write_to_bytes().unwrap()
+    );
+}
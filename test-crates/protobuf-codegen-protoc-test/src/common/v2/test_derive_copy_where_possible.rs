@@ -0,0 +1,44 @@
+use protobuf::text_format;
+use protobuf::Message;
+
+use super::test_derive_copy_where_possible_pb::*;
+
+fn assert_copy<T: Copy>(t: T) -> (T, T) {
+    (t, t)
+}
+
+#[test]
+fn test_copy_twin() {
+    let p: Point = text_format::parse_from_str("x: 10 y: 2.5 color: GREEN").unwrap();
+    let c = PointCopy::from(&p);
+    let (a, b) = assert_copy(c);
+    assert_eq!(a, b);
+
+    let m = Point::from(a);
+    assert_eq!(p, m);
+    assert_eq!(p.write_to_bytes().unwrap(), m.write_to_bytes().unwrap());
+
+    assert_copy(EmptyCopy::default());
+}
+
+#[test]
+fn test_ineligible() {
+    // `Named`, `Polygon` and `Shape` have no `Copy` twins, but still work.
+    let n: Named = text_format::parse_from_str("id: 1 name: \"n\"").unwrap();
+    assert_eq!(n.clone(), n);
+    let mut p = Polygon::new();
+    p.points.push(Point::new());
+    assert_eq!(p.clone(), p);
+    let mut s = Shape::new();
+    s.set_circle(1);
+    assert_eq!(s.clone(), s);
+}
+
+#[test]
+fn test_twin_name_collision() {
+    // `SizeCopy` is the message declared in `.proto`, not a twin of `Size`.
+    let c: SizeCopy = text_format::parse_from_str("note: \"n\"").unwrap();
+    assert_eq!(c, c.clone());
+    let s: Size = text_format::parse_from_str("width: 3").unwrap();
+    assert_eq!(s, s.clone());
+}
@@ -0,0 +1,41 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+use super::test_derive_eq_pb::*;
+
+fn assert_eq_impl<T: Eq>() {}
+
+#[test]
+fn test_eq_derived() {
+    assert_eq_impl::<Point>();
+    assert_eq_impl::<Eligible>();
+    assert_eq_impl::<eligible::Id>();
+}
+
+// `Eq` is required by `HashSet`, generated messages do not implement `Hash`.
+impl Hash for Eligible {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name().hash(state);
+    }
+}
+
+#[test]
+fn test_hash_set() {
+    let mut a = Eligible::new();
+    a.set_name("a".to_owned());
+    a.set_email("a@example.com".to_owned());
+    let mut b = Eligible::new();
+    b.set_name("b".to_owned());
+    b.set_phone(17);
+
+    let mut set = HashSet::new();
+    assert!(set.insert(a.clone()));
+    assert!(set.insert(b.clone()));
+    assert!(!set.insert(a.clone()));
+
+    b.set_phone(18);
+    assert!(set.insert(b));
+    assert_eq!(3, set.len());
+    assert!(set.contains(&a));
+}
@@ -0,0 +1,73 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+use super::test_derive_hash_pb::*;
+
+fn hash<T: Hash>(v: &T) -> u64 {
+    let mut state = DefaultHasher::new();
+    v.hash(&mut state);
+    state.finish()
+}
+
+fn point(x: i32, y: i32) -> Point {
+    let mut p = Point::new();
+    p.set_x(x);
+    p.set_y(y);
+    p
+}
+
+fn hashed() -> Hashed {
+    let mut m = Hashed::new();
+    m.set_name("foo".to_owned());
+    m.set_weight(1.5);
+    m.set_color(Color::GREEN);
+    m.point = Some(point(1, 2)).into();
+    m.values = vec![0.5, 2.0];
+    m.set_email("foo@example.com".to_owned());
+    m
+}
+
+#[test]
+fn test_equal_messages_have_equal_hashes() {
+    assert_eq!(hashed(), hashed());
+    assert_eq!(hash(&hashed()), hash(&hashed()));
+    assert_eq!(hash(&Hashed::new()), hash(&Hashed::new()));
+}
+
+#[test]
+fn test_different_messages_have_different_hashes() {
+    let a = hashed();
+    let mut b = hashed();
+    b.set_phone(17);
+    assert_ne!(a, b);
+    assert_ne!(hash(&a), hash(&b));
+
+    let mut c = hashed();
+    c.mut_point().set_y(3);
+    assert_ne!(hash(&a), hash(&c));
+
+    let mut d = hashed();
+    d.clear_name();
+    assert_ne!(hash(&a), hash(&d));
+}
+
+#[test]
+fn test_unknown_fields_not_hashed() {
+    let a = hashed();
+    let mut b = hashed();
+    b.special_fields.mut_unknown_fields().add_varint(100, 17);
+    assert_eq!(hash(&a), hash(&b));
+}
+
+#[test]
+fn test_map_insertion_order() {
+    let mut a = hashed();
+    let mut b = hashed();
+    for i in 0..20 {
+        a.points.insert(i.to_string(), point(i, i));
+        b.points.insert((19 - i).to_string(), point(19 - i, 19 - i));
+    }
+    assert_eq!(a, b);
+    assert_eq!(hash(&a), hash(&b));
+}
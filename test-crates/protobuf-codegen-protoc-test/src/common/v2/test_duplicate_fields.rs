@@ -0,0 +1,62 @@
+use protobuf::Message;
+use protobuf::MessageFull;
+use protobuf_test_common::hex::decode_hex;
+
+use super::test_duplicate_fields_pb::*;
+
+fn parse_generated_and_dynamic(hex: &str) -> Outer {
+    let bytes = decode_hex(hex);
+    let generated = Outer::parse_from_bytes(&bytes).unwrap();
+    let dynamic = Outer::descriptor().parse_from_bytes(&bytes).unwrap();
+    assert_eq!(
+        generated,
+        Outer::parse_from_bytes(&dynamic.write_to_bytes_dyn().unwrap()).unwrap()
+    );
+    generated
+}
+
+#[test]
+fn test_singular_message_merged() {
+    // `inner { a: 1 r: 3 }` followed by `inner { b: 2 r: 4 }`
+    let m = parse_generated_and_dynamic("0a 04 08 01 18 03 0a 04 10 02 18 04");
+    assert_eq!(1, m.inner.a());
+    assert_eq!(2, m.inner.b());
+    assert_eq!(vec![3, 4], m.inner.r);
+}
+
+#[test]
+fn test_singular_message_last_wins_for_fields() {
+    // `inner { a: 1 }` followed by `inner { a: 5 }`
+    let m = parse_generated_and_dynamic("0a 02 08 01 0a 02 08 05");
+    assert_eq!(5, m.inner.a());
+}
+
+#[test]
+fn test_scalar_last_wins() {
+    let m = parse_generated_and_dynamic("10 01 10 05");
+    assert_eq!(5, m.scalar());
+}
+
+#[test]
+fn test_oneof_message_merged() {
+    // `oneof_inner { a: 1 }` followed by `oneof_inner { b: 2 }`
+    let m = parse_generated_and_dynamic("1a 02 08 01 1a 02 10 02");
+    assert_eq!(1, m.oneof_inner().a());
+    assert_eq!(2, m.oneof_inner().b());
+}
+
+#[test]
+fn test_oneof_boxed_message_merged() {
+    // `oneof_outer { scalar: 1 }` followed by `oneof_outer { inner { a: 2 } }`
+    let m = parse_generated_and_dynamic("2a 02 10 01 2a 04 0a 02 08 02");
+    assert_eq!(1, m.oneof_outer().scalar());
+    assert_eq!(2, m.oneof_outer().inner.a());
+}
+
+#[test]
+fn test_oneof_other_case_replaces() {
+    // `oneof_int: 7` followed by `oneof_inner { a: 1 }`
+    let m = parse_generated_and_dynamic("20 07 1a 02 08 01");
+    assert!(!m.has_oneof_int());
+    assert_eq!(1, m.oneof_inner().a());
+}
@@ -1,6 +1,7 @@
 use protobuf::reflect::FileDescriptor;
 use protobuf::reflect::ReflectValueBox;
 use protobuf::reflect::ReflectValueRef;
+use protobuf_test_common::hex::encode_hex;
 
 use super::test_dynamic_singular_get_set_pb;
 
@@ -59,3 +60,31 @@ fn generated_set_panic_on_wrong_field_type() {
 fn dynamic_set_panic_on_wrong_field_type() {
     do_test_set_panic_on_wrong_field_type(&dynamic_file_descriptor());
 }
+
+fn do_test_zigzag(file_descriptor: &FileDescriptor) {
+    let d = file_descriptor
+        .message_by_package_relative_name("ForDynamicZigzagTest")
+        .unwrap();
+    let s64 = d.field_by_name("s64").unwrap();
+    let s32 = d.field_by_name("s32").unwrap();
+
+    let mut m = d.new_instance();
+    s64.set_singular_field(&mut *m, ReflectValueBox::from(-300i64));
+    s32.set_singular_field(&mut *m, ReflectValueBox::from(-1i32));
+    let bytes = m.write_to_bytes_dyn().unwrap();
+    assert_eq!("08 d7 04 10 01", encode_hex(&bytes));
+
+    let m = d.parse_from_bytes(&bytes).unwrap();
+    assert_eq!(Some(ReflectValueRef::from(-300i64)), s64.get_singular(&*m));
+    assert_eq!(Some(ReflectValueRef::from(-1i32)), s32.get_singular(&*m));
+}
+
+#[test]
+fn generated_zigzag() {
+    do_test_zigzag(&test_dynamic_singular_get_set_pb::file_descriptor());
+}
+
+#[test]
+fn dynamic_zigzag() {
+    do_test_zigzag(&dynamic_file_descriptor());
+}
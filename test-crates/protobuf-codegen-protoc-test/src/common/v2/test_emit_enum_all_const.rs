@@ -0,0 +1,34 @@
+use protobuf::Enum;
+
+use super::test_emit_enum_all_const_pb::*;
+
+// Usable in const context.
+const COLOR_COUNT: usize = Color::ALL.len();
+
+#[test]
+fn test_all() {
+    assert_eq!(3, COLOR_COUNT);
+    assert_eq!(&[Color::RED, Color::GREEN, Color::BLUE], Color::ALL);
+    assert_eq!(
+        &[shape::Kind::CIRCLE, shape::Kind::SQUARE],
+        shape::Kind::ALL
+    );
+}
+
+#[test]
+fn test_iterate() {
+    let names: Vec<&str> = Color::ALL
+        .iter()
+        .map(|c| match c {
+            Color::RED => "red",
+            Color::GREEN => "green",
+            Color::BLUE => "blue",
+        })
+        .collect();
+    assert_eq!(vec!["red", "green", "blue"], names);
+}
+
+#[test]
+fn test_same_as_values() {
+    assert_eq!(Color::VALUES, Color::ALL);
+}
@@ -0,0 +1,24 @@
+use protobuf::MessageFull;
+
+use super::test_emit_full_name_const_pb::*;
+
+// Usable in const context.
+const OUTER_NAME: &str = Outer::FULL_NAME;
+
+#[test]
+fn test_full_name() {
+    assert_eq!("test_emit_full_name_const.sub.Outer", OUTER_NAME);
+    assert_eq!(
+        "test_emit_full_name_const.sub.Outer.Inner",
+        outer::Inner::FULL_NAME
+    );
+}
+
+#[test]
+fn test_same_as_descriptor() {
+    assert_eq!(Outer::descriptor().full_name(), Outer::FULL_NAME);
+    assert_eq!(
+        outer::Inner::descriptor().full_name(),
+        outer::Inner::FULL_NAME
+    );
+}
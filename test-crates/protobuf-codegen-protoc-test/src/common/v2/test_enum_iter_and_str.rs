@@ -0,0 +1,26 @@
+use super::test_enum_iter_and_str_pb::*;
+
+#[test]
+fn test_iter() {
+    assert_eq!(
+        vec![Color::RED, Color::GREEN, Color::BLUE],
+        Color::iter().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![shape::Kind::CIRCLE, shape::Kind::SQUARE],
+        shape::Kind::iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_as_str() {
+    let names: Vec<&str> = Color::iter().map(|c| c.as_str()).collect();
+    assert_eq!(vec!["RED", "GREEN", "BLUE"], names);
+    assert_eq!("SQUARE", shape::Kind::SQUARE.as_str());
+}
+
+#[test]
+fn test_alias() {
+    let names: Vec<&str> = Aliased::iter().map(|c| c.as_str()).collect();
+    assert_eq!(vec!["FIRST", "ALSO_FIRST", "SECOND"], names);
+}
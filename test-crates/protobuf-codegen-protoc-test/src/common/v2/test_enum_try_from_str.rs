@@ -0,0 +1,38 @@
+use std::convert::TryFrom;
+
+use super::test_enum_try_from_str_pb::*;
+
+#[test]
+fn test_short_name() {
+    assert_eq!(Ok(Color::RED), Color::try_from("RED"));
+    assert_eq!(Ok(Color::BLUE), Color::try_from("BLUE"));
+    assert_eq!(Ok(shape::Kind::SQUARE), shape::Kind::try_from("SQUARE"));
+}
+
+#[test]
+fn test_qualified_name() {
+    assert_eq!(
+        Ok(Color::GREEN),
+        Color::try_from("enum_try_from_str.Color.GREEN")
+    );
+    assert_eq!(
+        Ok(shape::Kind::CIRCLE),
+        shape::Kind::try_from("enum_try_from_str.Shape.Kind.CIRCLE")
+    );
+}
+
+#[test]
+fn test_invalid() {
+    let e = Color::try_from("YELLOW").unwrap_err();
+    assert_eq!("enum_try_from_str.Color", e.enum_name());
+    assert_eq!("YELLOW", e.value());
+    assert_eq!(
+        "unknown value name \"YELLOW\" of enum `enum_try_from_str.Color`",
+        e.to_string()
+    );
+
+    assert!(Color::try_from("red").is_err());
+    assert!(Color::try_from("").is_err());
+    assert!(Color::try_from("Color.RED").is_err());
+    assert!(shape::Kind::try_from("enum_try_from_str.Kind.CIRCLE").is_err());
+}
@@ -0,0 +1,78 @@
+use protobuf::Message;
+
+use super::test_estimated_size_pb::*;
+
+fn nested(value: i32) -> Nested {
+    let mut m = Nested::new();
+    m.set_value(value);
+    m.names = vec!["a".to_owned(), "bbbbbbbbbbbbbbbbbbbb".to_owned()];
+    m
+}
+
+fn assert_upper_bound(m: &Estimated) {
+    let computed = m.compute_size() as usize;
+    assert_eq!(computed, m.write_to_bytes().unwrap().len());
+    let estimated = m.estimated_size();
+    assert!(
+        estimated >= computed,
+        "estimated {} < computed {} for {:?}",
+        estimated,
+        computed,
+        m
+    );
+}
+
+#[test]
+fn test_empty() {
+    assert_upper_bound(&Estimated::new());
+}
+
+#[test]
+fn test_scalars() {
+    let mut m = Estimated::new();
+    m.set_int32_field(-1);
+    m.set_uint32_field(u32::MAX);
+    m.set_sint64_field(i64::MIN);
+    m.set_fixed32_field(17);
+    m.set_double_field(1.5);
+    m.set_bool_field(true);
+    m.set_string_field("string".repeat(100));
+    m.set_bytes_field(vec![0xff; 200]);
+    m.set_color(Color::GREEN);
+    assert_upper_bound(&m);
+}
+
+#[test]
+fn test_repeated_and_nested() {
+    let mut m = Estimated::new();
+    m.nested = Some(nested(-3)).into();
+    m.packed_field = vec![-1, 0, i64::MAX];
+    m.unpacked_field = vec![u64::MAX, 1];
+    m.floats = vec![1.0; 10];
+    m.nested_list = vec![nested(1), nested(-1), Nested::new()];
+    m.nested_map.insert("x".to_owned(), nested(10));
+    m.nested_map.insert(String::new(), Nested::new());
+    m.int_map.insert(-1, -1);
+    m.int_map.insert(0, 0);
+    assert_upper_bound(&m);
+}
+
+#[test]
+fn test_oneof() {
+    let mut m = Estimated::new();
+    m.set_one_string("one".to_owned());
+    assert_upper_bound(&m);
+    m.set_one_nested(nested(-100));
+    assert_upper_bound(&m);
+    m.set_one_int32(-100);
+    assert_upper_bound(&m);
+}
+
+#[test]
+fn test_unknown_fields() {
+    let mut m = Estimated::new();
+    m.mut_unknown_fields().add_varint(100, u64::MAX);
+    m.mut_unknown_fields()
+        .add_length_delimited(101, vec![1; 300]);
+    assert_upper_bound(&m);
+}
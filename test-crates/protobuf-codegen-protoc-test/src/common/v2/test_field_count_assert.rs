@@ -0,0 +1,22 @@
+use protobuf::MessageFull;
+
+use super::test_field_count_assert_pb::*;
+
+// Code depending on the exact schema pins it like this
+const _: () = assert!(Person::FIELD_COUNT == 2);
+const _: () = assert!(PersonWithEmail::FIELD_COUNT == 3);
+const _: () = assert!(Empty::FIELD_COUNT == 0);
+
+#[test]
+fn test_added_field_changes_count() {
+    assert_eq!(Person::FIELD_COUNT + 1, PersonWithEmail::FIELD_COUNT);
+}
+
+#[test]
+fn test_field_count_matches_descriptor() {
+    assert_eq!(Person::descriptor().fields().count(), Person::FIELD_COUNT);
+    assert_eq!(
+        PersonWithEmail::descriptor().fields().count(),
+        PersonWithEmail::FIELD_COUNT
+    );
+}
@@ -0,0 +1,34 @@
+use protobuf::descriptor::field_descriptor_proto::Type;
+use protobuf::MessageFull;
+
+use super::test_field_meta_pb::*;
+
+#[test]
+fn test_fields() {
+    let fields: Vec<_> = Person::FIELDS
+        .iter()
+        .map(|f| (f.name, f.number, f.type_, f.repeated, f.in_oneof))
+        .collect();
+    assert_eq!(
+        vec![
+            ("name", 1, Type::TYPE_STRING, false, false),
+            ("ids", 3, Type::TYPE_INT64, true, false),
+            ("tags", 4, Type::TYPE_MESSAGE, true, false),
+            ("email", 7, Type::TYPE_STRING, false, true),
+            ("friend", 8, Type::TYPE_MESSAGE, false, true),
+        ],
+        fields
+    );
+    assert!(Empty::FIELDS.is_empty());
+}
+
+#[test]
+fn test_fields_match_descriptor() {
+    let descriptor = Person::descriptor();
+    assert_eq!(descriptor.fields().count(), Person::FIELDS.len());
+    for (meta, field) in Person::FIELDS.iter().zip(descriptor.fields()) {
+        assert_eq!(field.name(), meta.name);
+        assert_eq!(field.number() as u32, meta.number);
+        assert_eq!(field.proto().type_(), meta.type_);
+    }
+}
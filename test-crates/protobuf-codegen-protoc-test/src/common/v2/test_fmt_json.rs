@@ -1,3 +1,5 @@
+use protobuf::reflect::FileDescriptor;
+use protobuf::Message;
 use protobuf::MessageFull;
 use protobuf_test_common::*;
 
@@ -236,6 +238,34 @@ fn test_enum_int() {
     assert_eq!("{\"testEnumSingular\": 10}", json);
 }
 
+#[test]
+fn test_enum_int_and_name_round_trip() {
+    let mut m = TestTypes::new();
+    m.set_test_enum_singular(TestEnum::DARK);
+    m.set_test_enum_repeated(vec![TestEnum::LIGHT.into(), TestEnum::DARK.into()]);
+
+    let json_names = protobuf_json_mapping::print_to_string(&m).unwrap();
+    assert_eq!(
+        "{\"testEnumSingular\": \"DARK\", \"testEnumRepeated\": [\"LIGHT\", \"DARK\"]}",
+        json_names
+    );
+
+    let print_options = protobuf_json_mapping::PrintOptions {
+        enum_values_int: true,
+        ..Default::default()
+    };
+    let json_ints =
+        protobuf_json_mapping::print_to_string_with_options(&m, &print_options).unwrap();
+    assert_eq!(
+        "{\"testEnumSingular\": 10, \"testEnumRepeated\": [20, 10]}",
+        json_ints
+    );
+
+    // Parser accepts both representations regardless of options.
+    test_json_parse_message(&json_names, &m);
+    test_json_parse_message(&json_ints, &m);
+}
+
 #[test]
 fn test_map_field_int_key() {
     let mut m = TestTypes::new();
@@ -348,6 +378,15 @@ fn test_use_json_name() {
     m.set_field_with_json_name(true);
     let json = protobuf_json_mapping::print_to_string(&m).unwrap();
     assert_eq!("{\"Field With json_name\": true}", json);
+
+    let field = TestJsonName::descriptor()
+        .field_by_name("field_with_json_name")
+        .unwrap();
+    assert_eq!("Field With json_name", field.json_name());
+
+    test_json_parse_message("{\"Field With json_name\": true}", &m);
+    // Original field name is accepted too.
+    test_json_parse_message("{\"field_with_json_name\": true}", &m);
 }
 
 #[test]
@@ -362,3 +401,51 @@ fn test_more_than_one() {
         &m,
     );
 }
+
+#[test]
+fn test_message_field_presence() {
+    let field = TestTypes::descriptor()
+        .field_by_name("test_message_singular")
+        .unwrap();
+    let always_output_default_values = protobuf_json_mapping::PrintOptions {
+        always_output_default_values: true,
+        ..Default::default()
+    };
+
+    // Unset message field is omitted, even when default values are printed.
+    let m = TestTypes::new();
+    assert!(!field.has_field(&m));
+    test_json_print_parse_message("{}", &m);
+    let json =
+        protobuf_json_mapping::print_to_string_with_options(&m, &always_output_default_values)
+            .unwrap();
+    assert!(!json.contains("testMessageSingular"), "{}", json);
+
+    // Explicitly set empty message is emitted.
+    let mut m = TestTypes::new();
+    m.test_message_singular = protobuf::MessageField::some(TestMessage::new());
+    assert!(field.has_field(&m));
+    test_json_print_parse_message("{\"testMessageSingular\": {}}", &m);
+
+    // Same for dynamic messages.
+    let file =
+        FileDescriptor::new_dynamic(file_descriptor().proto().clone(), file_descriptor().deps())
+            .unwrap();
+    let descriptor = file.message_by_package_relative_name("TestTypes").unwrap();
+    let field = descriptor.field_by_name("test_message_singular").unwrap();
+    let dynamic = descriptor
+        .parse_from_bytes(&m.write_to_bytes().unwrap())
+        .unwrap();
+    assert!(dynamic.downcast_ref::<TestTypes>().is_none());
+    assert!(field.has_field(&*dynamic));
+    assert_eq!(
+        "{\"testMessageSingular\": {}}",
+        protobuf_json_mapping::print_to_string(&*dynamic).unwrap()
+    );
+    let dynamic = descriptor.new_instance();
+    assert!(!field.has_field(&*dynamic));
+    assert_eq!(
+        "{}",
+        protobuf_json_mapping::print_to_string(&*dynamic).unwrap()
+    );
+}
@@ -0,0 +1,29 @@
+use protobuf::EnumOrUnknown;
+use protobuf::Message;
+use protobuf_test_common::*;
+
+use super::test_force_unpacked_repeated_pb::*;
+
+#[test]
+fn test_unpacked() {
+    let mut m = ForceUnpacked::new();
+    m.ints = vec![1, 150];
+    m.fixeds = vec![3];
+    m.enums = vec![EnumOrUnknown::new(TestEnum::ONE)];
+    test_serialize_deserialize("08 01 08 96 01 15 03 00 00 00 18 01", &m);
+}
+
+#[test]
+fn test_parse_packed() {
+    let mut packed = Packed::new();
+    packed.ints = vec![1, 150];
+    packed.fixeds = vec![3];
+    packed.enums = vec![EnumOrUnknown::new(TestEnum::ONE)];
+    test_serialize_deserialize("0a 03 01 96 01 12 04 03 00 00 00 1a 01 01", &packed);
+
+    // Packed data is accepted by message with the option.
+    let m = ForceUnpacked::parse_from_bytes(&packed.write_to_bytes().unwrap()).unwrap();
+    assert_eq!(packed.ints, m.ints);
+    assert_eq!(packed.fixeds, m.fixeds);
+    assert_eq!(packed.enums, m.enums);
+}
@@ -0,0 +1,28 @@
+use protobuf::text_format;
+
+use super::test_fromstr_text_format_pb::*;
+
+#[test]
+fn test_parse() {
+    let text = r#"
+        name: "prod"
+        endpoints { host: "a" port: 80 }
+        endpoints { host: "b" port: 8080 }
+    "#;
+    let config: Config = text.parse().unwrap();
+    assert_eq!(2, config.endpoints.len());
+    assert_eq!(text_format::parse_from_str::<Config>(text).unwrap(), config);
+    // Round trip through `Display`.
+    assert_eq!(config, config.to_string().parse().unwrap());
+}
+
+#[test]
+fn test_parse_error() {
+    let e = "name: ".parse::<Config>().unwrap_err();
+    assert!(
+        e.to_string().starts_with("Text format parse error: "),
+        "{}",
+        e
+    );
+    assert!("unknown_field: 1".parse::<Endpoint>().is_err());
+}
@@ -0,0 +1,113 @@
+use protobuf_json_mapping::ParseOptions;
+
+use super::test_gen_json_methods_pb::*;
+
+fn test_same_as_reflective(json: &str) {
+    let expected: TestGenJsonMethods = protobuf_json_mapping::parse_from_str(json).unwrap();
+    let mut m = TestGenJsonMethods::new();
+    m.merge_from_json_str(json).unwrap();
+    assert_eq!(expected, m, "{}", json);
+}
+
+#[test]
+fn test_scalar() {
+    test_same_as_reflective("{}");
+    test_same_as_reflective(
+        "{\"doubleSingular\": 1.5, \"floatSingular\": \"-Infinity\", \"int32Singular\": -3, \
+         \"int64Singular\": \"40000000000\", \"uint32Singular\": 5, \"uint64Singular\": 6, \
+         \"sint32Singular\": -7, \"fixed64Singular\": \"8\", \"boolSingular\": true}",
+    );
+}
+
+#[test]
+fn test_string_bytes() {
+    test_same_as_reflective(
+        "{\"stringSingular\": \"a\\u0062\\n\", \"bytesSingular\": \"AQI=\", \
+         \"customName\": \"c\"}",
+    );
+}
+
+#[test]
+fn test_proto_field_name() {
+    test_same_as_reflective("{\"int32_singular\": 3, \"custom_json_name\": \"c\"}");
+}
+
+#[test]
+fn test_repeated() {
+    test_same_as_reflective(
+        "{\"int32Repeated\": [1, 2, 3], \"stringRepeated\": [\"a\", \"b\"], \
+         \"bytesRepeated\": [\"AQI=\"]}",
+    );
+    test_same_as_reflective("{\"int32Repeated\": null}");
+    // Repeated field is replaced, not appended.
+    test_same_as_reflective("{\"int32Repeated\": [1, 2], \"int32Repeated\": [3]}");
+}
+
+#[test]
+fn test_reflective_fields() {
+    test_same_as_reflective(
+        "{\"enumSingular\": \"DARK\", \"nestedSingular\": {\"value\": 1}, \
+         \"nestedRepeated\": [{}, {\"value\": 2}], \"mapField\": {\"a\": 1}, \
+         \"oneofNested\": {\"value\": 3}, \"int32Singular\": 4}",
+    );
+}
+
+#[test]
+fn test_merge() {
+    let mut m = TestGenJsonMethods::new();
+    m.merge_from_json_str("{\"int32Singular\": 1, \"stringSingular\": \"a\"}")
+        .unwrap();
+    m.merge_from_json_str("{\"int32Singular\": 2}").unwrap();
+
+    let mut expected = TestGenJsonMethods::new();
+    protobuf_json_mapping::merge_from_str(
+        &mut expected,
+        "{\"int32Singular\": 2, \"stringSingular\": \"a\"}",
+    )
+    .unwrap();
+    assert_eq!(expected, m);
+}
+
+#[test]
+fn test_unknown_field() {
+    let mut m = TestGenJsonMethods::new();
+    assert!(m.merge_from_json_str("{\"unknown\": 1}").is_err());
+
+    let parse_options = ParseOptions {
+        ignore_unknown_fields: true,
+        ..Default::default()
+    };
+    let mut m = TestGenJsonMethods::new();
+    m.merge_from_json_str_with_options(
+        "{\"unknown\": {\"a\": [1]}, \"int32Singular\": 1}",
+        &parse_options,
+    )
+    .unwrap();
+    let mut expected = TestGenJsonMethods::new();
+    protobuf_json_mapping::merge_from_str(&mut expected, "{\"int32Singular\": 1}").unwrap();
+    assert_eq!(expected, m);
+}
+
+#[test]
+fn test_errors() {
+    for json in [
+        "{\"int32Singular\": true}",
+        "{\"stringSingular\": 1}",
+        "{\"bytesSingular\": \"!\"}",
+        "{\"int32Repeated\": [1,]}",
+        "[]",
+    ] {
+        let mut m = TestGenJsonMethods::new();
+        let e = m.merge_from_json_str(json).unwrap_err();
+        let mut r = TestGenJsonMethods::new();
+        let expected = protobuf_json_mapping::merge_from_str(&mut r, json).unwrap_err();
+        assert_eq!(expected.to_string(), e.to_string(), "{}", json);
+    }
+}
+
+#[test]
+fn test_empty_message() {
+    let mut m = Empty::new();
+    m.merge_from_json_str("{}").unwrap();
+    assert!(m.merge_from_json_str("{\"a\": 1}").is_err());
+}
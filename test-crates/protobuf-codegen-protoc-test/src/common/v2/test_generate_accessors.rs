@@ -10,3 +10,18 @@ fn test() {
     // even if it's not requested explicitly
     WithoutAccessors::new().f;
 }
+
+#[test]
+fn test_message_getter_absent_returns_shared_default() {
+    let m = WithAccessors::new();
+    let default = <FooBar as protobuf::Message>::default_instance();
+    assert!(std::ptr::eq(default, m.f()));
+    assert!(std::ptr::eq(default, m.f.get_or_default()));
+    assert!(std::ptr::eq(default, &*m.f));
+    // Also shared between different messages.
+    assert!(std::ptr::eq(m.f(), WithAccessors::new().fs()));
+
+    let mut m = WithAccessors::new();
+    m.mut_f();
+    assert!(!std::ptr::eq(default, m.f()));
+}
@@ -0,0 +1,41 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+use super::test_identity_fields_pb::*;
+
+fn hash<T: Hash>(v: &T) -> u64 {
+    let mut state = DefaultHasher::new();
+    v.hash(&mut state);
+    state.finish()
+}
+
+fn item(id: &str, version: i32, payload: &str) -> Item {
+    let mut item = Item::new();
+    item.set_id(id.to_owned());
+    item.set_version(version);
+    item.set_payload(payload.to_owned());
+    item
+}
+
+#[test]
+fn test_equal_on_identity_fields() {
+    let a = item("x", 1, "foo");
+    let mut b = item("x", 1, "bar");
+    b.tags.push(10);
+    assert_eq!(a, b);
+    assert_eq!(hash(&a), hash(&b));
+
+    let mut set = HashSet::new();
+    set.insert(a);
+    assert!(!set.insert(b));
+}
+
+#[test]
+fn test_differ_on_identity_fields() {
+    let a = item("x", 1, "foo");
+    assert_ne!(a, item("x", 2, "foo"));
+    assert_ne!(a, item("y", 1, "foo"));
+    assert_ne!(hash(&a), hash(&item("x", 2, "foo")));
+}
@@ -0,0 +1,22 @@
+use super::test_inline_accessors_pb::*;
+
+#[test]
+fn test_accessors() {
+    let mut m = WithInline::new();
+    m.set_a(10);
+    assert_eq!(10, m.a());
+    m.mut_r().push("x".to_owned());
+    assert_eq!(vec!["x".to_owned()], m.take_r());
+    m.clear_a();
+    assert_eq!(0, m.a());
+}
+
+#[test]
+fn test_generated_inline() {
+    let generated = include_str!("test_inline_accessors_pb.rs");
+    assert!(generated.contains("#[inline]\n    pub fn set_a(&mut self, v: i32)"));
+    // Option is set only for `WithInline`.
+    let without = &generated[generated.find("impl WithoutInline").unwrap()..];
+    let without = &without[..without.find("impl ::protobuf::Message").unwrap()];
+    assert!(!without.contains("#[inline]"));
+}
@@ -0,0 +1,32 @@
+use protobuf_test_common::capture_log;
+
+use super::test_instrument_getters_pb::*;
+
+#[test]
+fn test_getters_logged() {
+    let mut m = Instrumented::new();
+    m.set_a(10);
+    let ((), records) = capture_log(|| {
+        assert_eq!(10, m.a());
+        assert!(m.r().is_empty());
+        assert_eq!("", m.s());
+    });
+    let messages: Vec<&str> = records.iter().map(|r| r.message.as_str()).collect();
+    assert_eq!(
+        vec![
+            "get `test_instrument_getters.Instrumented.a`",
+            "get `test_instrument_getters.Instrumented.r`",
+            "get `test_instrument_getters.Instrumented.s`",
+        ],
+        messages
+    );
+    assert!(records.iter().all(|r| r.level == log::Level::Debug));
+}
+
+#[test]
+fn test_not_instrumented() {
+    let m = NotInstrumented::new();
+    let (a, records) = capture_log(|| m.a());
+    assert_eq!(0, a);
+    assert!(records.is_empty(), "{:?}", records);
+}
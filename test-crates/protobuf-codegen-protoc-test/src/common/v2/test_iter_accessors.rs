@@ -0,0 +1,25 @@
+use super::test_iter_accessors_pb::*;
+
+#[test]
+fn test_iter() {
+    let mut m = WithRepeated::new();
+    m.ints = vec![1, 2, 3];
+    m.strings = vec!["a".to_owned(), "b".to_owned()];
+    m.messages.push(Inner::new());
+    m.type_.push(vec![1]);
+
+    assert_eq!(6, m.ints_iter().sum::<i32>());
+    assert_eq!(
+        vec!["a", "b"],
+        m.strings_iter().map(|s| s.as_str()).collect::<Vec<_>>()
+    );
+    assert_eq!(1, m.messages_iter().count());
+    assert_eq!(1, m.type_iter().count());
+}
+
+#[test]
+fn test_iter_empty() {
+    let m = WithRepeated::new();
+    assert_eq!(None, m.ints_iter().next());
+    assert_eq!(0, m.messages_iter().count());
+}
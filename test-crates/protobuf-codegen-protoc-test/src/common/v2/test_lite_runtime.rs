@@ -11,3 +11,13 @@ fn test_lite_runtime() {
     // test it doesn't crash
     format!("{:?}", m);
 }
+
+#[test]
+fn test_no_descriptor_data() {
+    // `option optimize_for = LITE_RUNTIME` alone enables lite runtime,
+    // so neither reflection nor descriptor data is generated.
+    let generated = include_str!("test_lite_runtime_pb.rs");
+    assert!(!generated.contains("file_descriptor"));
+    assert!(!generated.contains("MessageFull"));
+    assert!(!generated.contains("EnumFull"));
+}
@@ -0,0 +1,47 @@
+use protobuf::Message;
+use protobuf_test_common::capture_log;
+use protobuf_test_common::hex::decode_hex;
+
+use super::test_log_parse_errors_pb::*;
+
+// Field `inner` (3) contains field `s` (5) with length 5, but only 1 byte.
+const TRUNCATED: &str = "1a 03 2a 05 61";
+
+#[test]
+fn test_parse_error_logged_with_field_path() {
+    let (r, records) = capture_log(|| Outer::parse_from_bytes(&decode_hex(TRUNCATED)));
+    let e = r.unwrap_err();
+    assert_eq!(1, records.len(), "{:?}", records);
+    assert_eq!(log::Level::Warn, records[0].level);
+    assert!(
+        records[0]
+            .message
+            .contains("test_log_parse_errors.Outer#3/test_log_parse_errors.Inner#5"),
+        "{}",
+        records[0].message
+    );
+    assert!(records[0].message.contains(&e.to_string()));
+    // Returned error is the original one, without the path.
+    assert!(!e.to_string().contains("Inner"), "{}", e);
+}
+
+#[test]
+fn test_outer_without_log() {
+    let (r, records) = capture_log(|| OuterWithoutLog::parse_from_bytes(&decode_hex(TRUNCATED)));
+    assert!(records.is_empty(), "{:?}", records);
+    // Nested message still adds the path.
+    let e = r.unwrap_err();
+    assert!(
+        e.to_string().starts_with("test_log_parse_errors.Inner#5: "),
+        "{}",
+        e
+    );
+}
+
+#[test]
+fn test_no_log_on_success() {
+    let bytes = decode_hex("1a 05 2a 03 61 62 63");
+    let (r, records) = capture_log(|| Outer::parse_from_bytes(&bytes));
+    assert_eq!(bytes, r.unwrap().write_to_bytes().unwrap());
+    assert!(records.is_empty(), "{:?}", records);
+}
@@ -77,3 +77,16 @@ fn text_format() {
 
     assert_eq!(&*print_to_string(&*map), "m {key: \"two\" value: 2}");
 }
+
+#[test]
+fn test_map_default_message_value() {
+    // Entry with empty message value is written and survives round-trip.
+    let mut map = TestMap::new();
+    map.mut_mm().insert("a".to_owned(), TestMapEntry::new());
+    test_serialize_deserialize_with_dynamic("12 05 0a 01 61 12 00", &map);
+
+    // Both key and value are default.
+    let mut map = TestMap::new();
+    map.mut_mm().insert("".to_owned(), TestMapEntry::new());
+    test_serialize_deserialize_with_dynamic("12 04 0a 00 12 00", &map);
+}
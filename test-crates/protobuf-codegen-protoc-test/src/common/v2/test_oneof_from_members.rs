@@ -0,0 +1,52 @@
+use std::marker::PhantomData;
+
+use protobuf::EnumOrUnknown;
+
+use super::test_oneof_from_members_pb::*;
+
+#[test]
+fn test_from_member() {
+    let mut m = WithOneof::new();
+
+    m.one = Some(10u32.into());
+    assert_eq!(10, m.u());
+
+    let mut member = Member::new();
+    member.a.push(20);
+    m.one = Some(member.clone().into());
+    assert_eq!(&member, m.member());
+
+    m.one = Some(EnumOrUnknown::new(Color::GREEN).into());
+    assert_eq!(Color::GREEN, m.color());
+
+    let mut nested = WithOneof::new();
+    nested.set_u(30);
+    m.one = Some(nested.clone().into());
+    assert_eq!(&nested, m.recursive());
+
+    m.two = Some(b"ab".to_vec().into());
+    assert_eq!(b"ab", m.b());
+}
+
+/// `Impls::<T, U>::FROM` is true if `T: From<U>`.
+///
+/// Inherent associated const is preferred over trait one when the bound holds.
+struct Impls<T, U>(PhantomData<(T, U)>);
+
+trait NotFrom {
+    const FROM: bool = false;
+}
+
+impl<T, U> NotFrom for Impls<T, U> {}
+
+impl<T: From<U>, U> Impls<T, U> {
+    const FROM: bool = true;
+}
+
+#[test]
+fn test_shared_type_not_generated() {
+    assert!(Impls::<with_oneof::One, u32>::FROM);
+    assert!(Impls::<with_oneof::Two, Vec<u8>>::FROM);
+    // Two members of `two` are strings, so the conversion would be ambiguous.
+    assert!(!Impls::<with_oneof::Two, String>::FROM);
+}
@@ -0,0 +1,39 @@
+use protobuf::MessageField;
+
+use super::test_prost_compat_pb::*;
+
+fn item() -> Item {
+    let mut item = Item {
+        sizes: vec![1, 2],
+        ..Default::default()
+    };
+    item.set_name("bolt".to_owned());
+    item.set_kind(Kind::SMALL);
+    item
+}
+
+#[test]
+fn test_struct_literal() {
+    let order = Order {
+        item: MessageField::some(item()),
+        extra: vec![Item::default()],
+        payment: Some(order::Payment::Card("1234".to_owned())),
+        ..Default::default()
+    };
+    assert_eq!("bolt", order.item.name());
+    assert_eq!(Kind::SMALL, order.item.kind());
+    assert_eq!("1234", order.card());
+    assert_eq!(0, order.account());
+}
+
+#[test]
+fn test_encode_decode() {
+    let mut order = Order::default();
+    order.item = MessageField::some(item());
+    order.set_account(17);
+
+    let bytes = order.encode_to_vec();
+    assert_eq!(order.encoded_len(), bytes.len());
+    assert_eq!(order, Order::decode(&bytes).unwrap());
+    assert!(Order::decode(&[0xff]).is_err());
+}
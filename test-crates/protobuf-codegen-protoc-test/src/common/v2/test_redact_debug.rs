@@ -0,0 +1,30 @@
+use super::test_redact_debug_pb::*;
+
+#[test]
+fn test_redacted_field_value_not_printed() {
+    let mut credentials = Credentials::new();
+    credentials.set_user("root".to_owned());
+    credentials.set_password("hunter2".to_owned());
+    credentials.tokens = vec!["token-value".to_owned()];
+    credentials.set_api_key("api-key-value".to_owned());
+    credentials.set_comment("comment-value".to_owned());
+
+    let debug = format!("{:?}", credentials);
+    assert!(debug.contains("root"), "{}", debug);
+    assert!(debug.contains("comment-value"), "{}", debug);
+    assert!(debug.contains("<redacted>"), "{}", debug);
+    assert!(!debug.contains("hunter2"), "{}", debug);
+    assert!(!debug.contains("token-value"), "{}", debug);
+    assert!(!debug.contains("api-key-value"), "{}", debug);
+
+    // Pretty printing works too
+    let debug = format!("{:#?}", credentials);
+    assert!(!debug.contains("hunter2"), "{}", debug);
+}
+
+#[test]
+fn test_not_redacted() {
+    let mut no_redacted = NoRedacted::new();
+    no_redacted.set_user("root".to_owned());
+    assert!(format!("{:?}", no_redacted).contains("root"));
+}
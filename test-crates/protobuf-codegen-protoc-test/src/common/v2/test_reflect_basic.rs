@@ -267,3 +267,56 @@ fn test_json_name() {
     // the descriptor itself.
     assert_eq!("subM", field_descriptor.proto().json_name());
 }
+
+#[test]
+fn test_set_from_str() {
+    let mut m = TestTypesSingular::new();
+    let descriptor = TestTypesSingular::descriptor();
+    let field = |name| descriptor.field_by_name(name).unwrap();
+
+    field("int32_field").set_from_str(&mut m, "-17").unwrap();
+    field("bool_field").set_from_str(&mut m, "true").unwrap();
+    field("enum_field").set_from_str(&mut m, "BLUE").unwrap();
+    field("string_field").set_from_str(&mut m, "foo").unwrap();
+    assert_eq!(-17, m.int32_field());
+    assert_eq!(true, m.bool_field());
+    assert_eq!(TestReflectEnum::BLUE, m.enum_field());
+    assert_eq!("foo", m.string_field());
+
+    field("enum_field").set_from_str(&mut m, "3").unwrap();
+    assert_eq!(TestReflectEnum::GREEN, m.enum_field());
+
+    assert!(field("int32_field").set_from_str(&mut m, "foo").is_err());
+    assert!(field("bool_field").set_from_str(&mut m, "1").is_err());
+    assert!(field("enum_field").set_from_str(&mut m, "PURPLE").is_err());
+    assert!(field("message_field").set_from_str(&mut m, "").is_err());
+    assert_eq!(-17, m.int32_field());
+
+    let mut m = TestTypesRepeated::new();
+    let descriptor = TestTypesRepeated::descriptor();
+    let field = descriptor.field_by_name("uint64_field").unwrap();
+    field.set_from_str(&mut m, "1").unwrap();
+    field.set_from_str(&mut m, "2").unwrap();
+    assert_eq!(&[1, 2], &m.uint64_field[..]);
+}
+
+#[test]
+fn test_bytes_base64() {
+    let mut m = TestTypesSingular::new();
+    let field = TestTypesSingular::descriptor()
+        .field_by_name("bytes_field")
+        .unwrap();
+
+    assert_eq!("", field.get_bytes_as_base64(&m));
+
+    field.set_bytes_from_base64(&mut m, "AAEC/w==").unwrap();
+    assert_eq!(&[0, 1, 2, 255], m.bytes_field());
+    assert_eq!("AAEC/w==", field.get_bytes_as_base64(&m));
+
+    // Same encoding as JSON.
+    let json = protobuf_json_mapping::print_to_string(&m).unwrap();
+    assert!(json.contains("\"AAEC/w==\""), "{}", json);
+
+    assert!(field.set_bytes_from_base64(&mut m, "AA!C").is_err());
+    assert_eq!(&[0, 1, 2, 255], m.bytes_field());
+}
@@ -83,3 +83,14 @@ fn test_issue_281() {
     test.values = (0..100).collect();
     test_serialize_deserialize_no_hex(&test);
 }
+
+#[test]
+fn test_sort_in_place() {
+    // Repeated fields are `Vec`, so all slice methods are available directly
+    let mut test = TestPacked::new();
+    test.varints = vec![1000, 17];
+    test.varints.as_mut_slice().sort();
+    assert_eq!(&[17, 1000], test.varints.as_slice());
+    assert_eq!(Ok(1), test.varints.binary_search(&1000));
+    test_serialize_deserialize("22 03 11 e8 07", &test);
+}
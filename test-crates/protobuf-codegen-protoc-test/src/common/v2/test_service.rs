@@ -1,3 +1,5 @@
+use protobuf::MessageFull;
+
 use super::test_service_pb::*;
 
 #[test]
@@ -14,3 +16,36 @@ fn reflect() {
     let services = file_descriptor().services().collect::<Vec<_>>();
     assert_eq!(1, services.len());
 }
+
+#[test]
+fn reflect_methods() {
+    let service = file_descriptor().services().next().unwrap();
+    assert_eq!("Service", service.name());
+    assert_eq!("service.Service", service.full_name());
+    assert_eq!(7, service.methods().count());
+
+    let short_form = service.method_by_name("ShortForm").unwrap();
+    assert_eq!("ShortForm", short_form.name());
+    assert_eq!(Request::descriptor(), short_form.input_type());
+    assert_eq!(Response::descriptor(), short_form.output_type());
+    assert!(!short_form.client_streaming());
+    assert!(!short_form.server_streaming());
+
+    let dotty = service.method_by_name("DottyShortForm").unwrap();
+    assert_eq!("basic.Test1", dotty.input_type().full_name());
+    assert_eq!("basic.Test2", dotty.output_type().full_name());
+
+    let client_streaming = service.method_by_name("ClientStreaming").unwrap();
+    assert!(client_streaming.client_streaming());
+    assert!(!client_streaming.server_streaming());
+
+    let server_streaming = service.method_by_name("ServerStreaming").unwrap();
+    assert!(!server_streaming.client_streaming());
+    assert!(server_streaming.server_streaming());
+
+    let bidi_streaming = service.method_by_name("BidiStreaming").unwrap();
+    assert!(bidi_streaming.client_streaming());
+    assert!(bidi_streaming.server_streaming());
+
+    assert!(service.method_by_name("Missing").is_none());
+}
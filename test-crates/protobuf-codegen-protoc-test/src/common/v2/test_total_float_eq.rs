@@ -0,0 +1,63 @@
+use super::test_total_float_eq_pb::*;
+
+fn nan_point() -> Point {
+    let mut p = Point::new();
+    p.coords = vec![1.0, f64::NAN];
+    p.weights = vec![f32::NAN];
+    p.attrs.insert("x".to_owned(), f64::NAN);
+    p.parent.mut_or_insert_default().coords.push(f64::NAN);
+    p.set_d(f64::NAN);
+    p
+}
+
+#[test]
+fn test_nan_equal() {
+    let p = nan_point();
+    assert_eq!(p, p.clone());
+    assert_eq!(p, nan_point());
+}
+
+#[test]
+fn test_bits_differ() {
+    let other_nan = f64::from_bits(f64::NAN.to_bits() ^ 1);
+    assert!(other_nan.is_nan());
+
+    let mut p = nan_point();
+    p.coords[1] = other_nan;
+    assert_ne!(nan_point(), p);
+
+    let mut p = nan_point();
+    p.attrs.insert("x".to_owned(), other_nan);
+    assert_ne!(nan_point(), p);
+
+    let mut p = nan_point();
+    p.parent.mut_or_insert_default().coords[0] = other_nan;
+    assert_ne!(nan_point(), p);
+
+    let mut p = nan_point();
+    p.set_d(other_nan);
+    assert_ne!(nan_point(), p);
+
+    let mut p = nan_point();
+    p.weights[0] = -p.weights[0];
+    assert_ne!(nan_point(), p);
+}
+
+#[test]
+fn test_zero_and_oneof() {
+    let mut a = Point::new();
+    a.coords.push(0.0);
+    let mut b = Point::new();
+    b.coords.push(-0.0);
+    assert_ne!(a, b);
+
+    let mut a = Point::new();
+    let mut b = Point::new();
+    assert_eq!(a, b);
+    a.set_s("a".to_owned());
+    assert_ne!(a, b);
+    b.set_d(1.0);
+    assert_ne!(a, b);
+    b.set_s("a".to_owned());
+    assert_eq!(a, b);
+}
@@ -0,0 +1,14 @@
+use super::test_track_caller_pb::*;
+
+#[test]
+fn test_accessors() {
+    let mut m = WithTrackCaller::new();
+    // Getter of unset required field returns default value, does not panic.
+    assert_eq!(0, m.required_nested().a());
+    m.mut_required_nested().set_a(10);
+    assert_eq!(10, m.required_nested().a());
+
+    m.mut_s().push_str("x");
+    assert_eq!("x", m.take_s());
+    assert_eq!("", m.take_s());
+}
@@ -0,0 +1,36 @@
+use super::test_tuple_conversion_pb::*;
+
+#[test]
+fn test_record_to_tuple_and_back() {
+    let mut m = Record::new();
+    m.set_id(17);
+    m.set_name("seventeen".to_owned());
+    m.inner.mut_or_insert_default().set_a(3);
+
+    let (id, name, inner) = m.clone().into();
+    assert_eq!(m.id, id);
+    assert_eq!(m.name, name);
+    assert_eq!(3, inner.a());
+
+    assert_eq!(m, Record::from((id, name, inner)));
+}
+
+#[test]
+fn test_single_field_tuple() {
+    let mut m = Inner::new();
+    m.set_a(10);
+    let t: (_,) = m.clone().into();
+    assert_eq!(m.a, t.0);
+    assert_eq!(m, Inner::from(t));
+}
+
+#[test]
+fn test_oneof_is_tuple_member() {
+    let mut m = WithOneof::new();
+    m.values = vec![1, 2];
+    m.set_number(5);
+    let (values, kind) = m.clone().into();
+    assert_eq!(vec![1, 2], values);
+    assert_eq!(Some(with_oneof::Kind::Number(5)), kind);
+    assert_eq!(m, WithOneof::from((values, kind)));
+}
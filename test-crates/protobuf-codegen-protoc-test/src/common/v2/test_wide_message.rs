@@ -0,0 +1,47 @@
+use protobuf::rt::WireType;
+use protobuf::text_format;
+use protobuf::CodedInputStream;
+use protobuf::Message;
+
+use super::test_wide_message_pb::*;
+
+fn wide() -> Wide {
+    let text = "f1: 'a' f2: -2 f3: 3.5 f4: true f5: 5 f6: 6 f7: 'abc' f8: -8000000000 \
+        f9: 9.25 f10: true f11: 11 f12: 12 f13: 'd' f14: -14 f15: 15.0 f16: true f17: 17 \
+        f18: 18 f19: 'e' f20: -20 f21: 21.0 f22: true f23: 23 f24: 24 f25: 'f' f26: -26 \
+        f27: 27.0 f28: true f29: 29 f30: 30 f31: 'g' f32: -32 f33: 33.0 f34: true f35: 35 \
+        f36: 36 f37: 'h' f38: -38 f39: 39.0 f40: true \
+        r41: 1 r41: 2 r41: 3 m42 { a: 42 } rm43 { a: 1 } rm43 { a: 2 } f100: 100 f5000: 5000";
+    text_format::parse_from_str(text).unwrap()
+}
+
+/// Split serialized message into fields, and concatenate them
+/// in order of decreasing field number (repeated elements keep their order).
+fn reverse_fields(bytes: &[u8]) -> Vec<u8> {
+    let mut fields = Vec::new();
+    let mut is = CodedInputStream::from_bytes(bytes);
+    loop {
+        let start = is.pos() as usize;
+        let tag = match is.read_raw_tag_or_eof().unwrap() {
+            Some(tag) => tag,
+            None => break,
+        };
+        is.skip_field(WireType::new(tag & 7).unwrap()).unwrap();
+        fields.push((tag >> 3, &bytes[start..is.pos() as usize]));
+    }
+    fields.sort_by_key(|(number, _)| std::cmp::Reverse(*number));
+    fields
+        .into_iter()
+        .flat_map(|(_, b)| b.iter().copied())
+        .collect()
+}
+
+#[test]
+fn test_reverse_order() {
+    let m = wide();
+    let bytes = m.write_to_bytes().unwrap();
+    let reversed = reverse_fields(&bytes);
+    assert_eq!(bytes.len(), reversed.len());
+    assert_ne!(bytes, reversed);
+    assert_eq!(m, Wide::parse_from_bytes(&reversed).unwrap());
+}
@@ -0,0 +1,35 @@
+// Include mod.rs which references two mods: `v1` and `v2`
+include!(concat!(env!("OUT_DIR"), "/convert/mod.rs"));
+// `From<v1::*>` for `v2::*`
+include!(concat!(env!("OUT_DIR"), "/convert/convert.rs"));
+
+impl ConvertRest<v1::Person> for v2::Person {
+    fn convert_rest(&mut self, source: &v1::Person) {
+        self.id = source.legacy_id.to_string();
+    }
+}
+
+#[test]
+fn test_renamed_field() {
+    let mut address = v1::Address::new();
+    address.city = "Paris".to_owned();
+
+    let mut person = v1::Person::new();
+    person.name = "Marie".to_owned();
+    person.age = 30;
+    person.emails = vec!["marie@example.com".to_owned()];
+    person.address = Some(address.clone()).into();
+    person.kind = v1::Kind::KIND_FRIEND.into();
+    person.old_addresses = vec![address];
+    person.legacy_id = 17;
+
+    let person = v2::Person::from(person);
+    assert_eq!("Marie", person.full_name);
+    assert_eq!(30, person.age);
+    assert_eq!(vec!["marie@example.com".to_owned()], person.emails);
+    assert_eq!("Paris", person.address.city);
+    assert_eq!(v2::Kind::KIND_FRIEND, person.kind.unwrap());
+    assert_eq!(1, person.old_addresses.len());
+    assert_eq!("Paris", person.old_addresses[0].city);
+    assert_eq!("17", person.id);
+}
@@ -0,0 +1,14 @@
+// Generated with `CustomizeCallback` which switches fields
+// with `(rust_type) = "bytes::Bytes"` option to `tokio_bytes`.
+include!(concat!(env!("OUT_DIR"), "/custom_option/mod.rs"));
+
+use custom_option::WithCustomOption;
+
+#[test]
+fn test_custom_option_changes_type() {
+    let mut m = WithCustomOption::new();
+    m.data = bytes::Bytes::from_static(b"data");
+    m.plain = b"plain".to_vec();
+    let _: &bytes::Bytes = &m.data;
+    let _: &Vec<u8> = &m.plain;
+}
@@ -17,3 +17,18 @@ mod google;
 mod interop;
 
 mod include_generated;
+
+mod convert;
+
+mod module_path;
+
+mod serde_flatten_oneofs;
+
+mod serde_omit_defaults;
+
+mod single_module;
+
+mod module_cfg;
+
+#[cfg(feature = "with-bytes")]
+mod custom_option;
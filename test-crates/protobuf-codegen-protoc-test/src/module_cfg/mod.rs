@@ -0,0 +1,25 @@
+// `disabled` module is gated by `module_cfg_disabled` cfg which is never set,
+// and it references a module which does not exist,
+// so this crate compiles only if the module is compiled out.
+include!(concat!(env!("OUT_DIR"), "/module_cfg/mod.rs"));
+
+use protobuf::Message;
+
+#[test]
+fn test_mod_rs_cfg() {
+    let mod_rs = include_str!(concat!(env!("OUT_DIR"), "/module_cfg/mod.rs"));
+    assert!(
+        mod_rs.contains("#[cfg(module_cfg_disabled)]\npub mod disabled;\n"),
+        "{}",
+        mod_rs
+    );
+    assert!(mod_rs.contains("\npub mod enabled;\n"), "{}", mod_rs);
+}
+
+#[test]
+fn test_enabled_module() {
+    let mut m = enabled::Enabled::new();
+    m.value = 17;
+    let bytes = m.write_to_bytes().unwrap();
+    assert_eq!(m, enabled::Enabled::parse_from_bytes(&bytes).unwrap());
+}
@@ -0,0 +1,35 @@
+// Packages `module_path.a` and `module_path.b` are generated into modules
+// `gen::module_path_gen::a` and `gen::module_path_gen::b`,
+// not configured package `module_path.c` is generated into the top module.
+// Paths starting with `crate` are relative to the module which includes
+// generated `mod.rs`, and it is included below the crate root here.
+use protobuf::Message;
+
+use self::gen::module_path_c::C;
+use self::gen::module_path_gen::a::pkg_a::A;
+use self::gen::module_path_gen::b::pkg_b::B;
+
+mod gen {
+    include!(concat!(env!("OUT_DIR"), "/module_path/mod.rs"));
+}
+
+#[test]
+fn test_cross_package_reference() {
+    let mut m = B::new();
+    let a: &mut A = m.a.mut_or_insert_default();
+    a.value = 17;
+    let c: &mut C = m.c.mut_or_insert_default();
+    c.value = 19;
+
+    let bytes = m.write_to_bytes().unwrap();
+    assert_eq!(m, B::parse_from_bytes(&bytes).unwrap());
+
+    let file_descriptor = gen::module_path_gen::b::pkg_b::file_descriptor();
+    assert_eq!(
+        vec![
+            gen::module_path_gen::a::pkg_a::file_descriptor().clone(),
+            gen::module_path_c::file_descriptor().clone(),
+        ],
+        file_descriptor.deps()
+    );
+}
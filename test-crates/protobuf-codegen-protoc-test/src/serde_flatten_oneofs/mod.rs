@@ -0,0 +1,33 @@
+// Generated with `serde_flatten_oneofs` option and `CustomizeCallback`
+// which derives serde traits.
+include!(concat!(env!("OUT_DIR"), "/serde_flatten_oneofs/mod.rs"));
+
+use serde_flatten_oneofs::shape;
+use serde_flatten_oneofs::Inner;
+use serde_flatten_oneofs::Shape;
+
+#[test]
+fn test_member_at_top_level() {
+    let mut s = Shape::new();
+    s.name = "c".to_owned();
+    s.kind = Some(shape::Kind::Radius(10));
+    let json = serde_json::to_string(&s).unwrap();
+    assert_eq!(r#"{"name":"c","radius":10}"#, json);
+    assert_eq!(s, serde_json::from_str(&json).unwrap());
+
+    let mut inner = Inner::new();
+    inner.a = 1;
+    s.kind = Some(shape::Kind::Inner(inner));
+    let json = serde_json::to_string(&s).unwrap();
+    assert_eq!(r#"{"name":"c","inner":{"a":1}}"#, json);
+    assert_eq!(s, serde_json::from_str(&json).unwrap());
+}
+
+#[test]
+fn test_unset() {
+    let mut s = Shape::new();
+    s.name = "c".to_owned();
+    let json = serde_json::to_string(&s).unwrap();
+    assert_eq!(r#"{"name":"c"}"#, json);
+    assert_eq!(s, serde_json::from_str(&json).unwrap());
+}
@@ -0,0 +1,40 @@
+// Generated with `serde_omit_defaults` option and `CustomizeCallback`
+// which derives serde traits.
+include!(concat!(env!("OUT_DIR"), "/serde_omit_defaults/mod.rs"));
+
+use serde_omit_defaults::point;
+use serde_omit_defaults::Point;
+
+#[test]
+fn test_default_fields_omitted() {
+    assert_eq!("{}", serde_json::to_string(&Point::new()).unwrap());
+
+    let mut p = Point::new();
+    p.x = 0;
+    p.y = 17;
+    p.label = String::new();
+    p.weight = Some(0.0);
+    p.tags = vec![0];
+    assert_eq!(
+        r#"{"y":17,"weight":0.0,"tags":[0]}"#,
+        serde_json::to_string(&p).unwrap()
+    );
+}
+
+#[test]
+fn test_oneof() {
+    let mut p = Point::new();
+    p.shape = Some(point::Shape::Radius(0));
+    assert_eq!(
+        r#"{"shape":{"Radius":0}}"#,
+        serde_json::to_string(&p).unwrap()
+    );
+}
+
+#[test]
+fn test_missing_fields_are_default() {
+    let p: Point = serde_json::from_str(r#"{"label":"a"}"#).unwrap();
+    let mut expected = Point::new();
+    expected.label = "a".to_owned();
+    assert_eq!(expected, p);
+}
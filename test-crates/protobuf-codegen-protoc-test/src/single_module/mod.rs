@@ -0,0 +1,33 @@
+// Both protos are generated into single `mod.rs` with nested modules.
+include!(concat!(env!("OUT_DIR"), "/single_module/mod.rs"));
+
+use std::path::Path;
+
+use protobuf::Message;
+
+#[test]
+fn test_single_file() {
+    let dir = Path::new(concat!(env!("OUT_DIR"), "/single_module"));
+    let files: Vec<_> = dir
+        .read_dir()
+        .unwrap()
+        .map(|e| e.unwrap().file_name().into_string().unwrap())
+        .collect();
+    assert_eq!(vec!["mod.rs".to_owned()], files);
+}
+
+#[test]
+fn test_reference_across_modules() {
+    let mut m = second::Second::new();
+    let f: &mut first::First = m.first.mut_or_insert_default();
+    f.value = 17;
+    m.color = first::Color::GREEN.into();
+
+    let bytes = m.write_to_bytes().unwrap();
+    assert_eq!(m, second::Second::parse_from_bytes(&bytes).unwrap());
+
+    assert_eq!(
+        vec![first::file_descriptor().clone()],
+        second::file_descriptor().deps()
+    );
+}
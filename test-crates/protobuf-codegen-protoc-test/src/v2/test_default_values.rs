@@ -1,3 +1,5 @@
+use protobuf::MessageFull;
+
 use super::test_default_values_pb::*;
 
 #[test]
@@ -38,3 +40,25 @@ fn test_default_value_extreme() {
     assert_eq!(b"'", d.bquote1());
     assert_eq!(b"\"", d.bquote2());
 }
+
+#[test]
+fn test_has_field_reflect() {
+    let descriptor = TestDefaultValues::descriptor();
+    let int32_field = descriptor.field_by_name("int32_field").unwrap();
+    let bool_field = descriptor
+        .field_by_name("bool_default_false_field")
+        .unwrap();
+
+    let mut d = TestDefaultValues::new();
+    assert!(!int32_field.has_field(&d));
+    assert!(!bool_field.has_field(&d));
+
+    // Explicitly set default value is present in proto2.
+    d.set_int32_field(3);
+    d.set_bool_default_false_field(false);
+    assert!(int32_field.has_field(&d));
+    assert!(bool_field.has_field(&d));
+
+    d.clear_int32_field();
+    assert!(!int32_field.has_field(&d));
+}
@@ -0,0 +1,23 @@
+use protobuf::Message;
+
+use super::test_prost_compat_required_pb::*;
+
+#[test]
+fn test_encode_uninitialized() {
+    let mut m = WithRequired::new();
+    m.set_name("n".to_owned());
+    assert!(!m.is_initialized());
+    assert!(m.write_to_bytes().is_err());
+
+    // Like `prost`, does not check required fields.
+    let bytes = m.encode_to_vec();
+    assert_eq!(m.encoded_len(), bytes.len());
+    // But parsing checks them.
+    assert!(WithRequired::decode(&bytes).is_err());
+    let mut parsed = WithRequired::new();
+    parsed.merge_from_bytes(&bytes).unwrap();
+    assert_eq!(m, parsed);
+
+    m.set_id(1);
+    assert_eq!(m.write_to_bytes().unwrap(), m.encode_to_vec());
+}
@@ -0,0 +1,39 @@
+use super::test_result_required_getters_pb::*;
+
+#[test]
+fn test_try_get_set() {
+    let mut m = WithRequired::new();
+    m.set_n(10);
+    m.set_s("x".to_owned());
+    m.inner.mut_or_insert_default().set_a(20);
+    assert_eq!(10, m.try_get_n().unwrap());
+    assert_eq!("x", m.try_get_s().unwrap());
+    assert_eq!(20, m.try_get_inner().unwrap().a());
+}
+
+#[test]
+fn test_try_get_unset() {
+    let m = WithRequired::new();
+    let e = m.try_get_n().unwrap_err();
+    assert_eq!(
+        "Required field `test_result_required_getters.WithRequired.n` is not set",
+        e.to_string()
+    );
+    assert!(m.try_get_s().is_err());
+    assert!(m.try_get_inner().is_err());
+}
+
+#[test]
+fn test_not_generated_for_optional() {
+    // Resolved to the trait method only if there is no generated `try_get_o`.
+    trait NoTryGetO {
+        fn try_get_o(&self) -> &'static str {
+            "not generated"
+        }
+    }
+    impl NoTryGetO for WithRequired {}
+
+    let mut m = WithRequired::new();
+    m.set_o(10);
+    assert_eq!("not generated", m.try_get_o());
+}
@@ -0,0 +1,59 @@
+use std::cell::RefCell;
+use std::panic;
+use std::sync::Once;
+
+use super::test_track_caller_required_pb::*;
+
+/// Run a closure which panics and return the location of the panic.
+fn panic_location(f: impl FnOnce() + panic::UnwindSafe) -> (String, u32) {
+    thread_local! {
+        static LOCATION: RefCell<Option<(String, u32)>> = const { RefCell::new(None) };
+    }
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let prev = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let location = info.location().unwrap();
+            LOCATION
+                .with(|l| *l.borrow_mut() = Some((location.file().to_owned(), location.line())));
+            prev(info);
+        }));
+    });
+    assert!(panic::catch_unwind(f).is_err());
+    LOCATION.with(|l| l.borrow_mut().take().unwrap())
+}
+
+#[test]
+fn test_expect_set() {
+    let mut m = WithRequired::new();
+    m.nested.mut_or_insert_default().set_a(10);
+    m.set_n(20);
+    assert_eq!(10, m.expect_nested().a());
+    assert_eq!(20, m.expect_n());
+}
+
+#[test]
+fn test_expect_unset_reports_caller() {
+    let m = WithRequired::new();
+
+    let line = line!() + 1;
+    let location = panic_location(|| drop(m.expect_nested()));
+    assert_eq!((file!().to_owned(), line), location);
+
+    let line = line!() + 1;
+    let location = panic_location(|| drop(m.expect_n()));
+    assert_eq!((file!().to_owned(), line), location);
+}
+
+#[test]
+fn test_expect_not_generated_for_optional() {
+    // Resolved to the trait method only if there is no generated `expect_o`.
+    trait NoExpectO {
+        fn expect_o(&self) -> &'static str {
+            "not generated"
+        }
+    }
+    impl NoExpectO for WithRequired {}
+
+    assert_eq!("not generated", WithRequired::new().expect_o());
+}
@@ -0,0 +1,41 @@
+use protobuf::Message;
+
+use super::test_typestate_builder_pb::*;
+
+/// Fallback `build` which is resolved only when
+/// generated `build` is not available for the builder state
+/// (inherent methods take precedence over trait methods).
+trait BuildUnavailable: Sized {
+    fn build(self) -> NotBuilt {
+        NotBuilt
+    }
+}
+
+impl<N, I> BuildUnavailable for PersonBuilder<N, I> {}
+
+struct NotBuilt;
+
+#[test]
+fn test_build_with_all_required() {
+    let person: Person = Person::builder()
+        .email("a@example.com".to_owned())
+        .id(17)
+        .name("Alice".to_owned())
+        .build();
+    assert!(person.is_initialized());
+    assert_eq!("Alice", person.name());
+    assert_eq!(17, person.id());
+    assert_eq!("a@example.com", person.email());
+    assert!(person.phones.is_empty());
+}
+
+#[test]
+fn test_build_without_required_does_not_compile() {
+    // These would not compile if `build` returned `Person`.
+    let _: NotBuilt = Person::builder().build();
+    let _: NotBuilt = Person::builder().name("Alice".to_owned()).build();
+    let _: NotBuilt = Person::builder()
+        .id(17)
+        .email("a@example.com".to_owned())
+        .build();
+}
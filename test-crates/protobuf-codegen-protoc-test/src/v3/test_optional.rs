@@ -57,3 +57,28 @@ fn reflect_oneofs() {
         iii.get_singular(&mut message)
     );
 }
+
+#[test]
+fn reflect_has_field() {
+    let descriptor = TestOptionalProto3::descriptor();
+    let non_optional = descriptor.field_by_name("non_optional").unwrap();
+    let iii = descriptor.field_by_name("iii").unwrap();
+    let sss = descriptor.field_by_name("sss").unwrap();
+
+    let mut message = TestOptionalProto3::new();
+    assert!(!non_optional.has_field(&message));
+    assert!(!iii.has_field(&message));
+    assert!(!sss.has_field(&message));
+
+    // Implicit presence: zero is not distinguishable from unset.
+    message.non_optional = 0;
+    assert!(!non_optional.has_field(&message));
+    message.non_optional = 1;
+    assert!(non_optional.has_field(&message));
+
+    // Explicit presence: zero is present.
+    message.iii = Some(0);
+    message.sss = Some(String::new());
+    assert!(iii.has_field(&message));
+    assert!(sss.has_field(&message));
+}
@@ -0,0 +1,77 @@
+//! Check that default instances of generated messages do not allocate.
+//!
+//! Shared by `tests/default_no_alloc.rs` of protoc and pure codegen test crates,
+//! which include generated code for `src/v2/test_default_no_alloc_pb.proto`.
+
+use std::alloc::GlobalAlloc;
+use std::alloc::Layout;
+use std::alloc::System;
+use std::cell::Cell;
+
+use protobuf::Message;
+
+use super::test_default_no_alloc_pb::*;
+
+/// Counts allocations made by the current thread,
+/// so tests running concurrently do not interfere.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|c| c.set(c.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn count_allocations<R>(f: impl FnOnce() -> R) -> (R, usize) {
+    let before = ALLOCATIONS.with(|c| c.get());
+    let r = f();
+    let after = ALLOCATIONS.with(|c| c.get());
+    (r, after - before)
+}
+
+#[test]
+fn test_default_does_not_allocate() {
+    let (m, count) = count_allocations(DefaultNoAlloc::default);
+    assert_eq!(0, count);
+    let (_, count) = count_allocations(DefaultNoAlloc::new);
+    assert_eq!(0, count);
+    let (_, count) = count_allocations(DefaultNoAllocNested::new);
+    assert_eq!(0, count);
+
+    // Getters return defaults without allocation too.
+    let (_, count) = count_allocations(|| {
+        assert_eq!(17, m.i());
+        assert_eq!("default string", m.s());
+        assert_eq!(b"default bytes", m.b());
+        assert_eq!(DefaultNoAllocEnum::TWO, m.e());
+        assert_eq!("", m.nested.s());
+        assert_eq!("", m.oneof_s());
+        assert!(m.map.is_empty());
+    });
+    assert_eq!(0, count);
+
+    let (_, count) = count_allocations(|| drop(m));
+    assert_eq!(0, count);
+}
+
+#[test]
+fn test_counting_allocator_counts() {
+    let (_, count) = count_allocations(|| {
+        let mut m = DefaultNoAlloc::new();
+        m.repeated_i.push(1);
+        m.write_to_bytes().unwrap()
+    });
+    assert!(count >= 2, "{}", count);
+}
@@ -0,0 +1,7 @@
+//! Separate test binary, because the test installs a global allocator.
+
+#[path = "../src/v2/test_default_no_alloc_pb.rs"]
+mod test_default_no_alloc_pb;
+
+#[path = "common/default_no_alloc.rs"]
+mod default_no_alloc;
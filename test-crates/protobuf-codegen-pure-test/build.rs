@@ -4,7 +4,17 @@ use std::io::Read;
 use std::io::Write;
 use std::path::Path;
 
+use protobuf::descriptor::field_descriptor_proto::Type;
+use protobuf::descriptor::FieldOptions;
+use protobuf::ext::ExtFieldOptional;
+use protobuf::reflect::FieldDescriptor;
+use protobuf::reflect::MessageDescriptor;
+use protobuf::reflect::OneofDescriptor;
 use protobuf_codegen::Codegen;
+use protobuf_codegen::ConvertCodegen;
+use protobuf_codegen::Customize;
+use protobuf_codegen::CustomizeCallback;
+use protobuf_parse::Parser;
 use protobuf_test_common::build::*;
 use protobuf_test_common::print_rerun_if_changed_recursively;
 
@@ -125,6 +135,177 @@ fn generate_include_generated() {
         .run_from_script();
 }
 
+fn generate_convert() {
+    copy_from_protobuf_test("src/convert/mod.rs");
+
+    let dir = format!("{}/convert", env::var("OUT_DIR").unwrap());
+    if Path::new(&dir).exists() {
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    fs::create_dir(&dir).unwrap();
+    Codegen::new()
+        .pure()
+        .out_dir(&dir)
+        .input("../../test-crates/protobuf-codegen-protoc-test/src/convert/v1.proto")
+        .input("../../test-crates/protobuf-codegen-protoc-test/src/convert/v2.proto")
+        .include("../../test-crates/protobuf-codegen-protoc-test/src/convert")
+        .run_from_script();
+
+    let file_descriptor_set = |input: &str| {
+        Parser::new()
+            .pure()
+            .input(input)
+            .include("../../test-crates/protobuf-codegen-protoc-test/src/convert")
+            .file_descriptor_set()
+            .unwrap()
+    };
+    ConvertCodegen::new()
+        .source(
+            file_descriptor_set(
+                "../../test-crates/protobuf-codegen-protoc-test/src/convert/v1.proto",
+            ),
+            "self",
+        )
+        .target(
+            file_descriptor_set(
+                "../../test-crates/protobuf-codegen-protoc-test/src/convert/v2.proto",
+            ),
+            "self",
+        )
+        .rename_field("convert.v1.Person.name", "full_name")
+        .out_file(format!("{}/convert.rs", dir))
+        .run_from_script();
+}
+
+fn generate_custom_option() {
+    /// `rust_type` option from `custom_option.proto`.
+    const RUST_TYPE: ExtFieldOptional<FieldOptions, String> =
+        ExtFieldOptional::new(50001, Type::TYPE_STRING);
+
+    struct RustTypeFromOption;
+
+    impl CustomizeCallback for RustTypeFromOption {
+        fn field(&self, field: &FieldDescriptor) -> Customize {
+            match RUST_TYPE.get(field.proto().options.get_or_default()) {
+                Some(t) if t == "bytes::Bytes" => Customize::default().tokio_bytes(true),
+                _ => Customize::default(),
+            }
+        }
+    }
+
+    copy_from_protobuf_test("src/custom_option/mod.rs");
+
+    let dir = format!("{}/custom_option", env::var("OUT_DIR").unwrap());
+    if Path::new(&dir).exists() {
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    fs::create_dir(&dir).unwrap();
+    Codegen::new()
+        .pure()
+        .out_dir(&dir)
+        .input(
+            "../../test-crates/protobuf-codegen-protoc-test/src/custom_option/custom_option.proto",
+        )
+        .include("../../test-crates/protobuf-codegen-protoc-test/src/custom_option")
+        .customize_callback(RustTypeFromOption)
+        .run_from_script();
+}
+
+struct GenSerde;
+
+impl CustomizeCallback for GenSerde {
+    fn message(&self, _message: &MessageDescriptor) -> Customize {
+        Customize::default().before("#[derive(::serde::Serialize, ::serde::Deserialize)]")
+    }
+
+    fn oneof(&self, _oneof: &OneofDescriptor) -> Customize {
+        Customize::default().before("#[derive(::serde::Serialize, ::serde::Deserialize)]")
+    }
+
+    fn special_field(&self, _message: &MessageDescriptor, _field: &str) -> Customize {
+        Customize::default().before("#[serde(skip)]")
+    }
+}
+
+fn generate_serde(name: &str, customize: Customize) {
+    copy_from_protobuf_test(&format!("src/{}/mod.rs", name));
+
+    let dir = format!("{}/{}", env::var("OUT_DIR").unwrap(), name);
+    if Path::new(&dir).exists() {
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    fs::create_dir(&dir).unwrap();
+    Codegen::new()
+        .pure()
+        .out_dir(&dir)
+        .input(format!(
+            "../../test-crates/protobuf-codegen-protoc-test/src/{}/{}.proto",
+            name, name
+        ))
+        .include(format!(
+            "../../test-crates/protobuf-codegen-protoc-test/src/{}",
+            name
+        ))
+        .customize(customize)
+        .customize_callback(GenSerde)
+        .run_from_script();
+}
+
+fn generate_serde_omit_defaults() {
+    generate_serde(
+        "serde_omit_defaults",
+        Customize::default().serde_omit_defaults(true),
+    );
+}
+
+fn generate_serde_flatten_oneofs() {
+    generate_serde(
+        "serde_flatten_oneofs",
+        Customize::default().serde_flatten_oneofs(true),
+    );
+}
+
+fn generate_module_path() {
+    copy_from_protobuf_test("src/module_path/mod.rs");
+
+    let dir = format!("{}/module_path", env::var("OUT_DIR").unwrap());
+    if Path::new(&dir).exists() {
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    fs::create_dir(&dir).unwrap();
+    Codegen::new()
+        .pure()
+        .out_dir(&dir)
+        .input("../../test-crates/protobuf-codegen-protoc-test/src/module_path/pkg_a.proto")
+        .input("../../test-crates/protobuf-codegen-protoc-test/src/module_path/pkg_b.proto")
+        .input("../../test-crates/protobuf-codegen-protoc-test/src/module_path/module_path_c.proto")
+        .include("../../test-crates/protobuf-codegen-protoc-test/src/module_path")
+        .customize(
+            Customize::default()
+                .module_path_for_package("module_path.a", "crate::module_path_gen::a")
+                .module_path_for_package("module_path.b", "crate::module_path_gen::b"),
+        )
+        .run_from_script();
+}
+
+fn generate_single_module() {
+    copy_from_protobuf_test("src/single_module/mod.rs");
+
+    let dir = format!("{}/single_module", env::var("OUT_DIR").unwrap());
+    if Path::new(&dir).exists() {
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    fs::create_dir(&dir).unwrap();
+    Codegen::new()
+        .pure()
+        .out_dir(&dir)
+        .input("../../test-crates/protobuf-codegen-protoc-test/src/single_module/first.proto")
+        .input("../../test-crates/protobuf-codegen-protoc-test/src/single_module/second.proto")
+        .include("../../test-crates/protobuf-codegen-protoc-test/src/single_module")
+        .customize(Customize::default().single_module(true))
+        .run_from_script();
+}
+
 fn generate_pb_rs() {
     print_rerun_if_changed_recursively("../../test-crates/protobuf-codegen-protoc-test");
 
@@ -140,12 +321,21 @@ fn generate_pb_rs() {
     copy_tests_v2_v3("src/common/v2", "src/common/v3");
     gen_in_dir("src/common/v3", "src/common/v3");
 
+    // Only pure parser supports editions, so these tests are not copied.
+    gen_in_dir("src/editions", "src/editions");
+
     copy_tests("src/google/protobuf");
     gen_in_dir("src/google/protobuf", "src");
 
     generate_interop();
 
     generate_include_generated();
+    generate_convert();
+    generate_custom_option();
+    generate_module_path();
+    generate_serde_omit_defaults();
+    generate_serde_flatten_oneofs();
+    generate_single_module();
 }
 
 fn main() {
@@ -0,0 +1,47 @@
+use protobuf_test_common::*;
+
+use super::test_editions_presence_pb::*;
+
+#[test]
+fn test_explicit_presence() {
+    let mut m = WithPresence::new();
+    // Explicit presence fields are stored in `Option`.
+    let _: &Option<i32> = &m.explicit;
+    let _: &Option<String> = &m.explicit_string;
+    assert!(!m.has_explicit());
+    assert!(!m.has_explicit_string());
+
+    m.set_explicit(0);
+    m.set_explicit_string(String::new());
+    assert!(m.has_explicit());
+    assert!(m.has_explicit_string());
+    // Set to default value, but still serialized.
+    test_serialize_deserialize("08 00 1a 00", &m);
+}
+
+#[test]
+fn test_implicit_presence() {
+    let mut m = WithPresence::new();
+    // Implicit presence field is stored as is.
+    let _: &i32 = &m.implicit;
+    m.implicit = 0;
+    test_serialize_deserialize("", &m);
+    m.implicit = 3;
+    test_serialize_deserialize("10 03", &m);
+}
+
+#[test]
+fn test_message_presence() {
+    let mut m = WithPresence::new();
+    assert!(m.nested.is_none());
+    m.nested.mut_or_insert_default();
+    test_serialize_deserialize("22 00", &m);
+}
+
+#[test]
+fn test_repeated_field_encoding() {
+    let mut m = WithPresence::new();
+    m.packed = vec![1, 2];
+    m.expanded = vec![3, 4];
+    test_serialize_deserialize("2a 02 01 02 30 03 30 04", &m);
+}
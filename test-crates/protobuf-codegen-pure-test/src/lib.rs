@@ -5,6 +5,21 @@ mod v3;
 
 mod common;
 
+mod editions;
+
 mod interop;
 
 mod include_generated;
+
+mod convert;
+
+mod module_path;
+
+mod serde_flatten_oneofs;
+
+mod serde_omit_defaults;
+
+mod single_module;
+
+#[cfg(feature = "with-bytes")]
+mod custom_option;
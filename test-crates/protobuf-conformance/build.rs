@@ -0,0 +1,21 @@
+use protobuf_codegen::Codegen;
+
+const PROTOBUF_GIT: &str = "../../google-protobuf-all-protos/protobuf/protobuf-git";
+
+fn main() {
+    Codegen::new()
+        .pure()
+        .cargo_out_dir("protos")
+        .include(format!("{}/conformance", PROTOBUF_GIT))
+        .include(format!("{}/src", PROTOBUF_GIT))
+        .input(format!("{}/conformance/conformance.proto", PROTOBUF_GIT))
+        .input(format!(
+            "{}/src/google/protobuf/test_messages_proto2.proto",
+            PROTOBUF_GIT
+        ))
+        .input(format!(
+            "{}/src/google/protobuf/test_messages_proto3.proto",
+            PROTOBUF_GIT
+        ))
+        .run_from_script();
+}
@@ -0,0 +1,220 @@
+//! # Testee for protobuf conformance test suite
+//!
+//! Conformance test runner (`conformance_test_runner` from protobuf repository)
+//! starts the testee process and sends `ConformanceRequest` messages to its stdin,
+//! and reads `ConformanceResponse` messages from its stdout.
+//! Each message is prefixed with its length as 32-bit little-endian integer.
+//!
+//! ```sh
+//! conformance_test_runner target/debug/protobuf-conformance
+//! ```
+//!
+//! JSPB format is not supported, such tests are reported as skipped.
+
+use std::io;
+use std::io::Read;
+use std::io::Write;
+
+use protobuf::reflect::MessageDescriptor;
+use protobuf::Message;
+use protobuf::MessageDyn;
+use protobuf_json_mapping::ParseOptions;
+
+use crate::conformance::conformance_request;
+use crate::conformance::conformance_response;
+use crate::conformance::ConformanceRequest;
+use crate::conformance::ConformanceResponse;
+use crate::conformance::TestCategory;
+use crate::conformance::WireFormat;
+
+include!(concat!(env!("OUT_DIR"), "/protos/mod.rs"));
+
+fn message_descriptor(message_type: &str) -> Option<MessageDescriptor> {
+    [
+        test_messages_proto2::file_descriptor(),
+        test_messages_proto3::file_descriptor(),
+        conformance::file_descriptor(),
+    ]
+    .iter()
+    .find_map(|f| f.message_by_full_name(&format!(".{}", message_type)))
+}
+
+fn parse(
+    request: &ConformanceRequest,
+    descriptor: &MessageDescriptor,
+) -> Result<Box<dyn MessageDyn>, conformance_response::Result> {
+    use conformance_response::Result;
+
+    let parse_error = |e: &dyn std::error::Error| Result::ParseError(e.to_string());
+
+    match &request.payload {
+        Some(conformance_request::Payload::ProtobufPayload(bytes)) => descriptor
+            .parse_from_bytes(bytes)
+            .map_err(|e| parse_error(&e)),
+        Some(conformance_request::Payload::JsonPayload(json)) => {
+            let parse_options = ParseOptions {
+                ignore_unknown_fields: request.test_category.enum_value()
+                    == Ok(TestCategory::JSON_IGNORE_UNKNOWN_PARSING_TEST),
+                ..Default::default()
+            };
+            protobuf_json_mapping::parse_dyn_from_str_with_options(descriptor, json, &parse_options)
+                .map_err(|e| parse_error(&e))
+        }
+        Some(conformance_request::Payload::TextPayload(text)) => {
+            let mut message = descriptor.new_instance();
+            protobuf::text_format::merge_from_str(&mut *message, text)
+                .map_err(|e| parse_error(&e))?;
+            Ok(message)
+        }
+        Some(conformance_request::Payload::JspbPayload(_)) => {
+            Err(Result::Skipped("JSPB is not supported".to_owned()))
+        }
+        None => Err(Result::RuntimeError("request has no payload".to_owned())),
+    }
+}
+
+fn serialize(
+    request: &ConformanceRequest,
+    message: &dyn MessageDyn,
+) -> conformance_response::Result {
+    use conformance_response::Result;
+
+    match request.requested_output_format.enum_value() {
+        Ok(WireFormat::PROTOBUF) => match message.write_to_bytes_dyn() {
+            Ok(bytes) => Result::ProtobufPayload(bytes),
+            Err(e) => Result::SerializeError(e.to_string()),
+        },
+        Ok(WireFormat::JSON) => match protobuf_json_mapping::print_to_string(message) {
+            Ok(json) => Result::JsonPayload(json),
+            Err(e) => Result::SerializeError(e.to_string()),
+        },
+        Ok(WireFormat::TEXT_FORMAT) => {
+            Result::TextPayload(protobuf::text_format::print_to_string(message))
+        }
+        Ok(WireFormat::JSPB) => Result::Skipped("JSPB is not supported".to_owned()),
+        Ok(WireFormat::UNSPECIFIED) | Err(_) => {
+            Result::RuntimeError("unspecified output format".to_owned())
+        }
+    }
+}
+
+/// Execute single conformance test.
+pub fn run_test(request: &ConformanceRequest) -> ConformanceResponse {
+    let result = match message_descriptor(&request.message_type) {
+        Some(descriptor) => match parse(request, &descriptor) {
+            Ok(message) => serialize(request, &*message),
+            Err(result) => result,
+        },
+        None => conformance_response::Result::RuntimeError(format!(
+            "unknown message type: {}",
+            request.message_type
+        )),
+    };
+    let mut response = ConformanceResponse::new();
+    response.result = Some(result);
+    response
+}
+
+/// Read length-prefixed request from `input`, execute it
+/// and write length-prefixed response to `output`.
+///
+/// Return `false` on clean EOF in `input`.
+pub fn serve_one(input: &mut dyn Read, output: &mut dyn Write) -> protobuf::Result<bool> {
+    let mut len = [0; 4];
+    match input.read_exact(&mut len) {
+        Ok(()) => {}
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(false),
+        Err(e) => return Err(e.into()),
+    }
+    let mut bytes = vec![0; u32::from_le_bytes(len) as usize];
+    input.read_exact(&mut bytes)?;
+    let request = ConformanceRequest::parse_from_bytes(&bytes)?;
+
+    let response = run_test(&request).write_to_bytes()?;
+    output.write_all(&(response.len() as u32).to_le_bytes())?;
+    output.write_all(&response)?;
+    output.flush()?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_messages_proto3::TestAllTypesProto3;
+
+    fn request_bytes(request: &ConformanceRequest) -> Vec<u8> {
+        let bytes = request.write_to_bytes().unwrap();
+        let mut r = (bytes.len() as u32).to_le_bytes().to_vec();
+        r.extend_from_slice(&bytes);
+        r
+    }
+
+    fn serve(request: &ConformanceRequest) -> ConformanceResponse {
+        let input = request_bytes(request);
+        let mut input = &input[..];
+        let mut output = Vec::new();
+        assert!(serve_one(&mut input, &mut output).unwrap());
+        assert!(!serve_one(&mut input, &mut output).unwrap());
+
+        let len = u32::from_le_bytes(output[..4].try_into().unwrap()) as usize;
+        assert_eq!(len + 4, output.len());
+        ConformanceResponse::parse_from_bytes(&output[4..]).unwrap()
+    }
+
+    fn test_message() -> TestAllTypesProto3 {
+        let mut m = TestAllTypesProto3::new();
+        m.optional_int32 = 17;
+        m.optional_string = "foo".to_owned();
+        m.repeated_int64 = vec![1, 2];
+        m
+    }
+
+    #[test]
+    fn test_protobuf_to_protobuf() {
+        let mut request = ConformanceRequest::new();
+        request.set_protobuf_payload(test_message().write_to_bytes().unwrap());
+        request.message_type = "protobuf_test_messages.proto3.TestAllTypesProto3".to_owned();
+        request.requested_output_format = WireFormat::PROTOBUF.into();
+        request.test_category = TestCategory::BINARY_TEST.into();
+
+        let response = serve(&request);
+        let m = TestAllTypesProto3::parse_from_bytes(response.protobuf_payload()).unwrap();
+        assert_eq!(test_message(), m);
+    }
+
+    #[test]
+    fn test_json_to_protobuf() {
+        let mut request = ConformanceRequest::new();
+        request.set_json_payload(
+            r#"{"optionalInt32": 17, "optionalString": "foo", "repeatedInt64": ["1", "2"]}"#
+                .to_owned(),
+        );
+        request.message_type = "protobuf_test_messages.proto3.TestAllTypesProto3".to_owned();
+        request.requested_output_format = WireFormat::PROTOBUF.into();
+        request.test_category = TestCategory::JSON_TEST.into();
+
+        let response = serve(&request);
+        let m = TestAllTypesProto3::parse_from_bytes(response.protobuf_payload()).unwrap();
+        assert_eq!(test_message(), m);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let mut request = ConformanceRequest::new();
+        request.set_protobuf_payload(vec![0xff]);
+        request.message_type = "protobuf_test_messages.proto3.TestAllTypesProto3".to_owned();
+        request.requested_output_format = WireFormat::PROTOBUF.into();
+
+        assert!(serve(&request).has_parse_error());
+    }
+
+    #[test]
+    fn test_unknown_message_type() {
+        let mut request = ConformanceRequest::new();
+        request.set_protobuf_payload(Vec::new());
+        request.message_type = "foo.Bar".to_owned();
+        request.requested_output_format = WireFormat::PROTOBUF.into();
+
+        assert!(serve(&request).has_runtime_error());
+    }
+}
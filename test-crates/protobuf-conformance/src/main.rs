@@ -0,0 +1,19 @@
+use std::io;
+use std::process;
+
+fn main() {
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    let mut stdin = stdin.lock();
+    let mut stdout = stdout.lock();
+    loop {
+        match protobuf_conformance::serve_one(&mut stdin, &mut stdout) {
+            Ok(true) => {}
+            Ok(false) => break,
+            Err(e) => {
+                eprintln!("protobuf-conformance: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+}
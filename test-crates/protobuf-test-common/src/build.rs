@@ -97,6 +97,10 @@ pub struct GenInDirArgs<'a> {
     pub customize: Customize,
 }
 
+/// `cfg` predicates for test modules which need optional dependencies,
+/// by substring of module name.
+const MOD_CFGS: &[(&str, &str)] = &[("tokio", r#"feature = "with-bytes""#)];
+
 /// Generate mod.rs from all files in a directory
 pub fn gen_mod_rs_in_dir(dir: &str) {
     assert!(Path::new(dir).is_dir());
@@ -120,8 +124,10 @@ pub fn gen_mod_rs_in_dir(dir: &str) {
         assert!(file_name.ends_with(".rs"));
         let mod_name = &file_name[..file_name.len() - ".rs".len()];
 
-        if mod_name.contains("tokio") {
-            writeln!(mod_rs, r#"#[cfg(feature = "with-bytes")]"#).expect("write tokio");
+        for (pattern, cfg) in MOD_CFGS {
+            if mod_name.contains(pattern) {
+                writeln!(mod_rs, "#[cfg({})]", cfg).expect("write cfg");
+            }
         }
         writeln!(mod_rs, "mod {};", mod_name).expect("write");
     }
@@ -132,6 +138,7 @@ pub fn gen_mod_rs_in_dir(dir: &str) {
 enum TestProtobufVersions {
     V2,
     V3,
+    Editions,
     Google,
 }
 
@@ -139,6 +146,7 @@ enum TestProtobufVersions {
 enum ProtobufSyntax {
     V2,
     V3,
+    Editions,
 }
 
 fn test_version_from_file_path(mut file_path: &Path) -> TestProtobufVersions {
@@ -152,6 +160,8 @@ fn test_version_from_file_path(mut file_path: &Path) -> TestProtobufVersions {
             return TestProtobufVersions::V2;
         } else if name == "v3" {
             return TestProtobufVersions::V3;
+        } else if name == "editions" {
+            return TestProtobufVersions::Editions;
         } else if name == "google" {
             return TestProtobufVersions::Google;
         }
@@ -166,6 +176,8 @@ fn test_version_from_file_content(file_path: &Path) -> ProtobufSyntax {
         return ProtobufSyntax::V2;
     } else if content.contains("syntax = \"proto3\"") {
         return ProtobufSyntax::V3;
+    } else if content.contains("edition = \"") {
+        return ProtobufSyntax::Editions;
     } else {
         panic!(
             "cannot detect protobuf version from file content: {:?}",
@@ -178,6 +190,7 @@ fn check_test_version(file_path: &Path) {
     let expected_version_from_file_name = match test_version_from_file_path(file_path) {
         TestProtobufVersions::V2 => ProtobufSyntax::V2,
         TestProtobufVersions::V3 => ProtobufSyntax::V3,
+        TestProtobufVersions::Editions => ProtobufSyntax::Editions,
         TestProtobufVersions::Google => return,
     };
 
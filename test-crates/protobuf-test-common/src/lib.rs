@@ -29,3 +29,6 @@ pub use interop::*;
 
 mod dynamic;
 pub use dynamic::*;
+
+mod log_capture;
+pub use log_capture::*;
@@ -0,0 +1,59 @@
+//! Logger capturing log records for tests.
+
+use std::cell::RefCell;
+use std::sync::Once;
+
+use log::Log;
+use log::Metadata;
+use log::Record;
+
+/// Captured log record.
+#[derive(Debug, Clone)]
+pub struct CapturedRecord {
+    pub level: log::Level,
+    pub message: String,
+}
+
+thread_local! {
+    static CAPTURED: RefCell<Option<Vec<CapturedRecord>>> = RefCell::new(None);
+}
+
+struct CapturingLogger;
+
+impl Log for CapturingLogger {
+    fn enabled(&self, _metadata: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        CAPTURED.with(|captured| {
+            if let Some(captured) = &mut *captured.borrow_mut() {
+                captured.push(CapturedRecord {
+                    level: record.level(),
+                    message: record.args().to_string(),
+                });
+            }
+        });
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: CapturingLogger = CapturingLogger;
+
+/// Run function, and return log records emitted in the current thread.
+///
+/// Installs the global logger on first call, so it must not be used
+/// in test binaries which install a different logger.
+pub fn capture_log<R>(f: impl FnOnce() -> R) -> (R, Vec<CapturedRecord>) {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| {
+        log::set_logger(&LOGGER).expect("logger is already installed");
+        log::set_max_level(log::LevelFilter::Trace);
+    });
+
+    CAPTURED.with(|captured| *captured.borrow_mut() = Some(Vec::new()));
+    let r = f();
+    let records = CAPTURED.with(|captured| captured.borrow_mut().take().unwrap());
+    (r, records)
+}